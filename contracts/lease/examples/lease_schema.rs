@@ -1,11 +1,16 @@
-use lease::api::{ExecuteMsg, NewLeaseForm, StateQuery};
+use lease::api::{
+    ExecuteMsg, HistoryResponse, NewLeaseForm, QueryMsg, SimulateResponse, StateResponse,
+};
 use sdk::cosmwasm_schema::{export_schema, schema_for};
 
 fn main() {
     let out_dir = schema::prep_out_dir().expect("The output directory should be valid");
     export_schema(&schema_for!(NewLeaseForm), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
-    export_schema(&schema_for!(StateQuery), &out_dir);
+    export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(StateResponse), &out_dir);
+    export_schema(&schema_for!(SimulateResponse), &out_dir);
+    export_schema(&schema_for!(HistoryResponse), &out_dir);
 }
 
 #[cfg(test)]
@@ -28,3 +33,26 @@ fn test_repay_representation() {
         r#"{"repay":[]}"#
     );
 }
+
+#[cfg(test)]
+#[test]
+fn test_query_representation() {
+    use finance::percent::Percent;
+    use lease::api::{SimulateQuery, StateQuery};
+    use sdk::cosmwasm_std::from_slice;
+
+    assert_eq!(
+        from_slice::<QueryMsg>(br#"{}"#).expect("deserialization failed"),
+        QueryMsg::State(StateQuery {})
+    );
+    assert_eq!(
+        from_slice::<QueryMsg>(br#"{"simulate":{"price_change":[800,1100]}}"#)
+            .expect("deserialization failed"),
+        QueryMsg::Simulate {
+            simulate: SimulateQuery {
+                price_change: vec![Percent::from_percent(80), Percent::from_percent(110)],
+                at: None,
+            }
+        }
+    );
+}
@@ -1,22 +1,37 @@
+use std::slice;
+
 use serde::{Deserialize, Serialize};
 
 use currency::{lease::LeaseGroup, lpn::Lpns, payment::PaymentGroup};
 use finance::coin::CoinDTO;
-use sdk::schemars::{self, JsonSchema};
+use marketprice::SpotPrice;
+use sdk::{
+    cosmwasm_std::Addr,
+    schemars::{self, JsonSchema},
+};
 
 pub use self::{
     open::{
-        ConnectionParams, Ics20Channel, InterestPaymentSpec, LoanForm, NewLeaseContract,
-        NewLeaseForm,
+        AuctionSpec, ConnectionParams, Ics20Channel, InterestPaymentSpec, LiquidationFee,
+        LiquidationMode, LoanForm, NewLeaseContract, NewLeaseForm, RateType,
+    },
+    query::{
+        opened, opening, paid, AutoPayQuery, AutoPayResponse, HistoryEntry, HistoryQuery,
+        HistoryRecord, HistoryRecordId, HistoryResponse, LiabilityState, LiquidationCause,
+        LiquidationPrices, Payment, QueryMsg, Scenario, SimulateQuery, SimulateResponse,
+        StateQuery, StateResponse, Zone,
     },
-    query::{opened, opening, paid, StateQuery, StateResponse},
 };
 
+pub(crate) use self::open::legacy_max_slippage;
+
 // TODO consider defining the modules public instead of just selected items
 mod open;
 mod query;
 
 pub type PaymentCoin = CoinDTO<PaymentGroup>;
+/// The downpayment coins, a single one is (de-)serialized as before the multi-coin support
+pub type DownpaymentCoins = OneOrMany<DownpaymentCoin>;
 pub type DownpaymentCoin = PaymentCoin;
 pub type LeaseCoin = CoinDTO<LeaseGroup>;
 pub type LpnCoin = CoinDTO<Lpns>;
@@ -24,10 +39,111 @@ pub type LpnCoin = CoinDTO<Lpns>;
 #[derive(Serialize, Deserialize)]
 pub struct MigrateMsg {}
 
+/// One or many items where a single one is represented as is, not as a list
+///
+/// It keeps the messages and the states that used to carry a single item compatible
+/// with the ones carrying many.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            Self::One(item) => slice::from_ref(item),
+            Self::Many(items) => items,
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for OneOrMany<T> {
+    fn from(mut items: Vec<T>) -> Self {
+        if items.len() == 1 {
+            Self::One(items.remove(0))
+        } else {
+            Self::Many(items)
+        }
+    }
+}
+
+impl<T> From<OneOrMany<T>> for Vec<T> {
+    fn from(value: OneOrMany<T>) -> Self {
+        match value {
+            OneOrMany::One(item) => vec![item],
+            OneOrMany::Many(items) => items,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     Repay(),
+    /// Increase the lease amount with the sent payment
+    ///
+    /// The payment is transferred to the lease DEX account, swapped into the lease currency,
+    /// unless already in it, and then added to the lease amount. The liquidation alarms are
+    /// re-scheduled to reflect the new liability.
+    AddCollateral(),
+    /// Borrow more against the lease
+    ///
+    /// The amount is borrowed from the LPP and the interest due is paid out of it. The rest
+    /// is swapped into the lease currency on the DEX and added to the lease amount.
+    /// The resulting LTV should not exceed the initial one.
+    ///
+    /// Only the lease customer is allowed to increase the loan.
+    IncreaseLoan {
+        amount: LpnCoin,
+    },
+    /// Close the position, fully or partially, by selling lease asset on the DEX
+    ///
+    /// The proceeds repay the loan. If `amount` is provided, only that amount of
+    /// the lease asset is sold. Otherwise, the whole lease amount is sold, the loan
+    /// gets fully repaid, the surplus is sent to the customer, and the lease is closed.
+    /// A full close is accepted only if the sale is expected to repay the loan, and
+    /// the DEX rejects a sale that yields less than the total due.
+    ///
+    /// Only the lease customer is allowed to close the position.
+    ClosePosition {
+        #[serde(default)]
+        amount: Option<LeaseCoin>,
+    },
+    /// Change the prices at which the position gets closed automatically
+    ///
+    /// The provided policy replaces the current one. Only the lease customer is
+    /// allowed to change it. Neither of the prices should trigger at the current
+    /// market price.
+    ChangeClosePolicy(ClosePolicy),
+    /// Transfer the lease ownership to another address
+    ///
+    /// Only the lease customer is allowed to do it. The leaser gets notified
+    /// to move the lease under the new customer.
+    TransferOwnership {
+        new_customer: Addr,
+    },
+    /// Buy the lease asset offered at a liquidation auction
+    ///
+    /// The sent LPN amount should cover the current ask. The whole asset under liquidation
+    /// is sent to the buyer and any excess payment is refunded. Anyone is allowed to bid.
+    Bid(),
+    /// Top up the auto-pay reserve with the sent payment
+    ///
+    /// A payment in a currency other than the LPN is swapped into the LPN on the DEX.
+    /// On each time alarm, the overdue margin and loan interest are paid out of the reserve
+    /// before they may trigger a liquidation. Only the lease customer is allowed to fund it.
+    FundAutoPay(),
+    /// Send the remaining auto-pay reserve back to the customer and opt out of auto-pay
+    ///
+    /// Only the lease customer is allowed to withdraw it.
+    WithdrawAutoPay(),
+    /// Liquidate the lease if its liability or overdue interest requires it
+    ///
+    /// Open to anyone. The sender is paid the keeper bounty, a share of the liquidation
+    /// penalty. The liquidations triggered by the alarms are not paid any bounty.
+    Liquidate(),
     // it is not an enum variant to represent it as a JSON object instead of JSON string
     Close(),
     // that is a limitation of cosmjs library
@@ -44,10 +160,126 @@ pub enum ExecuteMsg {
     /// Invoked always by the same contract instance.
     DexCallbackContinue(),
 
+    /// An entry point for recording a payment or a liquidation in the lease history
+    ///
+    /// Invoked always by the same contract instance.
+    RecordHistory(HistoryEntry),
+
     /// Heal a lease past a middleware failure
     ///
     /// It cures a lease in the following cases:
     /// - on the final repay transaction, when an error, usually an out-of-gas, occurs on the Lpp's ExecuteMsg::RepayLoan sub-message
     /// - on the final repay transaction, when an error occurs on the Lease's SudoMsg::Response message
+    /// - on a DEX transaction that got stuck, by taking one of the following recovery paths:
+    ///   - re-enter the current state, for example, re-send an ICS20 transfer, or re-open the ICA
+    ///   - re-open the ICA and then re-enter the current state, for example, retry a swap
+    ///   - re-query the balances, for example, to complete a transfer-in
+    ///   - re-deliver a DEX response that has not been processed yet
+    ///
+    /// The paths that re-send a transaction are taken only if the lease has not changed its state
+    /// for at least `dex::HEAL_DELAY`, that exceeds the IBC timeouts, so the original transaction
+    /// cannot be in flight anymore.
+    ///
+    /// The path taken is reported with a 'heal' event.
     Heal(),
 }
+
+/// Customer-defined prices of the lease asset in the lease LPN at which the position gets fully closed
+///
+/// The stop-loss triggers when the market price goes below it. The take-profit
+/// triggers when the market price goes up to or above it.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ClosePolicy {
+    #[serde(default)]
+    pub stop_loss: Option<SpotPrice>,
+    #[serde(default)]
+    pub take_profit: Option<SpotPrice>,
+}
+
+/// The messages a lease sends to its leaser
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteLeaserMsg {
+    ChangeLeaseCustomer {
+        customer: Addr,
+        new_customer: Addr,
+    },
+    /// The lease has bought its asset and is open
+    LeaseOpened {
+        amount: LeaseCoin,
+        loan: LpnCoin,
+    },
+    /// The lease asset or loan has changed
+    LeaseUpdated {},
+    /// The lease has been fully repaid and closed, or fully liquidated
+    LeaseClosed {
+        #[serde(default)]
+        liquidated: bool,
+    },
+}
+
+/// The queries a lease sends to its leaser
+///
+/// The message format should match the respective variants of the leaser `QueryMsg`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryLeaserMsg {
+    ProtocolPause {},
+}
+
+/// The lease operations paused protocol-wide by the leaser
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ProtocolPause {
+    /// Opening leases
+    #[serde(default)]
+    pub openings: bool,
+    /// Increasing the loans of open leases
+    #[serde(default)]
+    pub borrows: bool,
+    /// Liquidations
+    ///
+    /// The alarms of a lease due to be liquidated are rescheduled instead.
+    #[serde(default)]
+    pub liquidations: bool,
+    /// Repayments, including the ones out of the auto-pay reserve, and position closes,
+    /// either requested by the customer or triggered by a close policy, since their proceeds
+    /// repay the loan
+    ///
+    /// The alarms of a lease with an auto-pay or a close due are rescheduled instead, unless
+    /// the lease is due to be liquidated.
+    #[serde(default)]
+    pub repayments: bool,
+}
+
+#[cfg(test)]
+mod test_one_or_many {
+    use sdk::cosmwasm_std::{from_slice, to_vec};
+
+    use super::OneOrMany;
+
+    #[test]
+    fn one() {
+        let one: OneOrMany<u8> = vec![5].into();
+        assert_eq!(OneOrMany::One(5), one);
+        assert_eq!(b"5".to_vec(), to_vec(&one).unwrap());
+        assert_eq!(one, from_slice(b"5").unwrap());
+        assert_eq!(vec![5], Vec::from(one));
+    }
+
+    #[test]
+    fn many() {
+        let many: OneOrMany<u8> = vec![5, 7].into();
+        assert_eq!(OneOrMany::Many(vec![5, 7]), many);
+        assert_eq!(b"[5,7]".to_vec(), to_vec(&many).unwrap());
+        assert_eq!(many, from_slice(b"[5,7]").unwrap());
+        assert_eq!(&[5, 7], many.as_slice());
+    }
+
+    #[test]
+    fn one_as_many() {
+        let one: OneOrMany<u8> = from_slice(b"[5]").unwrap();
+        assert_eq!(&[5], one.as_slice());
+    }
+}
@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use currency::SymbolOwned;
+use currency::{lpn::Usdc, SymbolOwned};
 pub use dex::{ConnectionParams, Ics20Channel};
-use finance::{duration::Duration, liability::Liability, percent::Percent};
+use finance::{
+    coin::Coin,
+    duration::Duration,
+    liability::Liability,
+    percent::{bound::BoundToHundredPercent, Percent},
+};
 use sdk::{
     cosmwasm_std::Addr,
     schemars::{self, JsonSchema},
@@ -10,6 +15,8 @@ use sdk::{
 
 use crate::{error::ContractError, error::ContractResult};
 
+use super::LpnCoin;
+
 mod unchecked;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -33,6 +40,32 @@ pub struct NewLeaseForm {
     pub max_ltd: Option<Percent>,
     /// Liability parameters
     pub liability: Liability,
+    /// The minimum amount, in LPN, a liquidation should be for
+    ///
+    /// Smaller liquidations are not performed.
+    #[serde(default = "legacy_min_liquidation")]
+    pub min_liquidation: LpnCoin,
+    /// The minimum lease amount, in LPN, that should remain after a partial liquidation
+    ///
+    /// A liquidation that would leave less is turned into a full one.
+    #[serde(default = "legacy_min_asset")]
+    pub min_asset: LpnCoin,
+    /// The maximum slippage of the swaps the lease performs on the DEX
+    ///
+    /// The minimum output of a swap is valued at the oracle prices less this percentage.
+    #[serde(default = "legacy_max_slippage")]
+    pub max_slippage: BoundToHundredPercent,
+    /// The fee charged on the proceeds of liquidations
+    ///
+    /// Leases opened before the fee introduction are not charged any.
+    #[serde(default)]
+    pub liquidation_fee: LiquidationFee,
+    /// How the lease asset is sold on liquidation
+    #[serde(default)]
+    pub liquidation_mode: LiquidationMode,
+    /// Whether the loan interest rate is fixed at opening or re-quoted every due period
+    #[serde(default)]
+    pub rate_type: RateType,
     /// Loan parameters
     pub loan: LoanForm,
     /// The time alarms contract the lease uses to get time notifications
@@ -41,6 +74,82 @@ pub struct NewLeaseForm {
     pub market_price_oracle: Addr,
 }
 
+// The amounts the forms stored by the leases still opening at their introduction fall back to
+fn legacy_min_liquidation() -> LpnCoin {
+    Coin::<Usdc>::new(10_000).into()
+}
+
+fn legacy_min_asset() -> LpnCoin {
+    Coin::<Usdc>::new(15_000_000).into()
+}
+
+/// Leases opened before the slippage protection accept any swap output
+pub(crate) fn legacy_max_slippage() -> BoundToHundredPercent {
+    BoundToHundredPercent::try_from_percent(Percent::HUNDRED)
+        .expect("the hundred percent should not cross its own bound")
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Default, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct LiquidationFee {
+    /// The share of the liquidation proceeds charged to the customer as a penalty
+    ///
+    /// The penalty, less the keeper bounty, goes to the Profit contract. The amount of a partial
+    /// liquidation is increased so that the rest of the proceeds still bring the lease back to
+    /// a healthy LTV. A full liquidation is charged no more than the proceeds in excess of
+    /// the amount due.
+    pub penalty: BoundToHundredPercent,
+    /// The share of the penalty paid to the keeper that triggers the liquidation
+    ///
+    /// A keeper is anyone who sends [`ExecuteMsg::Liquidate`](crate::api::ExecuteMsg::Liquidate).
+    /// No bounty is paid on the liquidations triggered by the alarms or by a customer action.
+    #[serde(default)]
+    pub keeper_bounty: BoundToHundredPercent,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Default, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LiquidationMode {
+    /// Sell the asset under liquidation on the DEX at the market price
+    #[default]
+    Market,
+    /// Offer the asset under liquidation at a declining price to anyone paying in LPN
+    ///
+    /// The asset is first transferred from the DEX account to the lease.
+    Auction(AuctionSpec),
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AuctionSpec {
+    /// The ask starts from the oracle price increased by this percentage
+    pub premium: Percent,
+    /// The ask goes down by this share of the initial one on every period
+    pub decay: BoundToHundredPercent,
+    /// The lowest ask as a share of the oracle value of the asset at the auction start
+    ///
+    /// Once the ask reaches it, the auction ends and the asset is sold on the DEX instead.
+    pub floor: BoundToHundredPercent,
+    /// How long the ask stays unchanged
+    pub period: Duration,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Default, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RateType {
+    /// The LPP loan interest rate is the one quoted at opening for the whole lease lifetime
+    ///
+    /// A premium on top of the margin interest is charged for the guarantee.
+    #[default]
+    Fixed,
+    /// The LPP loan interest rate is re-quoted at the start of each interest due period
+    ///
+    /// The re-quote takes place on the time alarm at the period start, or on the interest
+    /// payment that completes the previous period, whichever comes first. The new rate applies
+    /// to the loan interest that is not paid yet.
+    Floating,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(rename = "loan")]
@@ -165,3 +274,45 @@ mod test_invariant {
         ));
     }
 }
+
+#[cfg(test)]
+mod test_legacy_form {
+    use currency::lpn::Usdc;
+    use finance::{coin::Coin, percent::Percent};
+    use sdk::cosmwasm_std::from_slice;
+
+    use crate::api::LpnCoin;
+
+    use super::{LiquidationFee, NewLeaseForm};
+
+    // the form of a lease opened before the minimum amounts became configurable,
+    // the slippage protection, and the liquidation fee
+    const FORM: &[u8] = br#"{"customer":"customer","currency":"ATOM","max_ltd":null,
+        "liability":{"initial":600,"healthy":700,"first_liq_warn":720,"second_liq_warn":750,
+        "third_liq_warn":780,"max":800,"recalc_time":36000000000000},
+        "loan":{"annual_margin_interest":30,"lpp":"lpp","interest_payment":
+        {"due_period":86400000000000,"grace_period":3600000000000},"profit":"profit"},
+        "time_alarms":"time_alarms","market_price_oracle":"oracle"}"#;
+
+    #[test]
+    fn min_amounts() {
+        let form: NewLeaseForm = from_slice(FORM).unwrap();
+        assert_eq!(
+            LpnCoin::from(Coin::<Usdc>::new(10_000)),
+            form.min_liquidation
+        );
+        assert_eq!(LpnCoin::from(Coin::<Usdc>::new(15_000_000)), form.min_asset);
+    }
+
+    #[test]
+    fn max_slippage() {
+        let form: NewLeaseForm = from_slice(FORM).unwrap();
+        assert_eq!(Percent::HUNDRED, form.max_slippage.percent());
+    }
+
+    #[test]
+    fn liquidation_fee() {
+        let form: NewLeaseForm = from_slice(FORM).unwrap();
+        assert_eq!(LiquidationFee::default(), form.liquidation_fee);
+    }
+}
@@ -1,23 +1,200 @@
 use serde::{Deserialize, Serialize};
 
 use finance::percent::Percent;
+use marketprice::SpotPrice;
 use sdk::{
     cosmwasm_std::Timestamp,
     schemars::{self, JsonSchema},
 };
 
-use super::{DownpaymentCoin, LeaseCoin, LpnCoin};
+use super::{ClosePolicy, DownpaymentCoins, LeaseCoin, LpnCoin};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case", untagged)]
+pub enum QueryMsg {
+    /// Simulate the lease liability under hypothetical prices
+    ///
+    /// Does not alter the lease state. Supported only by opened leases with no ongoing transaction.
+    Simulate {
+        simulate: SimulateQuery,
+    },
+    /// Query the payment and liquidation history of the lease
+    ///
+    /// Supported in any lease state.
+    History {
+        history: HistoryQuery,
+    },
+    /// Query the remaining auto-pay reserve
+    ///
+    /// Supported only by opened leases with no ongoing transaction.
+    AutoPay {
+        auto_pay: AutoPayQuery,
+    },
+    State(StateQuery),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
 pub struct StateQuery {}
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SimulateQuery {
+    /// The hypothetical prices of the lease currency, each one as a percent of the current price
+    ///
+    /// For example, 800 (80%) stands for a 20% price drop, and 1100 (110%) for a 10% rise.
+    pub price_change: Vec<Percent>,
+    /// The time the liability is evaluated at, defaults to now
+    ///
+    /// Times in the past are treated as now.
+    #[serde(default)]
+    pub at: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct HistoryQuery {
+    /// Return the records with ids greater than this one, starts from the oldest kept if `None`
+    #[serde(default)]
+    pub start_after: Option<HistoryRecordId>,
+    /// The maximum number of records to return, defaults to 10 and is capped to 100
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+pub type HistoryRecordId = u64;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AutoPayQuery {}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(rename_all = "snake_case")]
+pub struct AutoPayResponse {
+    /// The remaining reserve, `None` if the customer has not opted in to auto-pay
+    pub reserve: Option<LpnCoin>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(rename_all = "snake_case")]
+pub struct HistoryResponse {
+    pub records: Vec<HistoryRecord>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(rename_all = "snake_case")]
+pub struct HistoryRecord {
+    pub id: HistoryRecordId,
+    pub at: Timestamp,
+    pub height: u64,
+    pub entry: HistoryEntry,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryEntry {
+    Repayment {
+        payment: Payment,
+    },
+    PositionClose {
+        payment: Payment,
+        amount: LeaseCoin,
+    },
+    Liquidation {
+        payment: Payment,
+        /// The penalty charged on top of the payment, including the keeper bounty
+        penalty: LpnCoin,
+        keeper_bounty: LpnCoin,
+        cause: LiquidationCause,
+        amount: LeaseCoin,
+    },
+}
+
+/// A payment and its split over the lease dues
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Payment {
+    pub total: LpnCoin,
+    pub previous_margin_interest: LpnCoin,
+    pub previous_loan_interest: LpnCoin,
+    pub current_margin_interest: LpnCoin,
+    pub current_loan_interest: LpnCoin,
+    pub principal: LpnCoin,
+    pub change: LpnCoin,
+    pub loan_close: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LiquidationCause {
+    Overdue,
+    Liability { ltv: Percent, healthy_ltv: Percent },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(rename_all = "snake_case")]
+pub struct SimulateResponse {
+    pub at: Timestamp,
+    pub scenarios: Vec<Scenario>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(rename_all = "snake_case")]
+pub struct Scenario {
+    pub price_change: Percent,
+    /// The ratio of the total due to the lease amount, may exceed 100%
+    pub ltv: Percent,
+    /// The liquidation warning zone, `None` if there is no debt or the lease gets liquidated
+    pub zone: Option<Zone>,
+    /// The lease amount that gets liquidated, `None` if there is no liquidation
+    pub liquidation: Option<LeaseCoin>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(rename_all = "snake_case")]
+pub struct LiabilityState {
+    /// The ratio of the total due to the lease amount
+    pub ltv: Percent,
+    /// The liquidation warning zone, `None` if the lease is subject to liquidation
+    pub zone: Option<Zone>,
+    pub liquidation_prices: LiquidationPrices,
+}
+
+/// The lease currency prices at which the liquidation levels are reached
+///
+/// The prices are calculated against the current total due.
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(rename_all = "snake_case")]
+pub struct LiquidationPrices {
+    pub first_liq_warn: SpotPrice,
+    pub second_liq_warn: SpotPrice,
+    pub third_liq_warn: SpotPrice,
+    pub max: SpotPrice,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+#[serde(rename_all = "snake_case")]
+pub struct Zone {
+    /// The warning level, 0 if there is no warning, 1 to 3 otherwise
+    pub level: u8,
+    pub low: Option<Percent>,
+    pub high: Percent,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
 #[serde(rename_all = "snake_case")]
 pub enum StateResponse {
     Opening {
-        downpayment: DownpaymentCoin,
+        downpayment: DownpaymentCoins,
         loan: LpnCoin,
         loan_interest_rate: Percent,
         in_progress: opening::OngoingTrx,
@@ -31,7 +208,12 @@ pub enum StateResponse {
         previous_interest_due: LpnCoin,
         current_margin_due: LpnCoin,
         current_interest_due: LpnCoin,
+        /// The liability at the current price, `None` if the oracle does not provide a price
+        #[serde(default)]
+        liability: Option<LiabilityState>,
         validity: Timestamp,
+        #[serde(default)]
+        close_policy: ClosePolicy,
         in_progress: Option<opened::OngoingTrx>,
     },
     Paid {
@@ -62,7 +244,7 @@ pub mod opened {
 
     use sdk::schemars::{self, JsonSchema};
 
-    use crate::api::{LeaseCoin, PaymentCoin};
+    use crate::api::{LeaseCoin, LpnCoin, PaymentCoin};
 
     #[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
     #[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
@@ -72,10 +254,26 @@ pub mod opened {
             payment: PaymentCoin,
             in_progress: RepayTrx,
         },
+        AutoPayFunding {
+            payment: PaymentCoin,
+            in_progress: RepayTrx,
+        },
         Liquidation {
             liquidation: LeaseCoin,
             in_progress: LiquidateTrx,
         },
+        Collateral {
+            collateral: PaymentCoin,
+            in_progress: CollateralTrx,
+        },
+        LoanIncrease {
+            proceeds: LpnCoin,
+            in_progress: LoanIncreaseTrx,
+        },
+        ClosePosition {
+            close: LeaseCoin,
+            in_progress: PositionCloseTrx,
+        },
     }
 
     #[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
@@ -95,6 +293,35 @@ pub mod opened {
         Swap,
         TransferInInit,
         TransferInFinish,
+        /// The asset is offered at an auction at the given ask
+        Auction {
+            ask: LpnCoin,
+        },
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+    #[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+    #[serde(rename_all = "snake_case")]
+    pub enum CollateralTrx {
+        TransferOut,
+        Swap,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+    #[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+    #[serde(rename_all = "snake_case")]
+    pub enum LoanIncreaseTrx {
+        TransferOut,
+        Swap,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+    #[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
+    #[serde(rename_all = "snake_case")]
+    pub enum PositionCloseTrx {
+        Swap,
+        TransferInInit,
+        TransferInFinish,
     }
 }
 
@@ -111,3 +338,83 @@ pub mod paid {
         TransferInFinish,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use currency::{lease::Atom, lpn::Usdc};
+    use finance::{coin::Coin, percent::Percent};
+    use sdk::cosmwasm_std::{from_slice, to_vec, Timestamp};
+
+    use crate::api::ClosePolicy;
+
+    use super::{AutoPayQuery, HistoryQuery, QueryMsg, SimulateQuery, StateQuery, StateResponse};
+
+    #[test]
+    fn state_query() {
+        let state = QueryMsg::State(StateQuery {});
+        assert_eq!(br#"{}"#.to_vec(), to_vec(&state).unwrap());
+        assert_eq!(state, from_slice(br#"{}"#).unwrap());
+    }
+
+    #[test]
+    fn new_queries() {
+        assert_eq!(
+            QueryMsg::Simulate {
+                simulate: SimulateQuery {
+                    price_change: vec![Percent::from_percent(80)],
+                    at: None,
+                }
+            },
+            from_slice(br#"{"simulate":{"price_change":[800]}}"#).unwrap()
+        );
+        assert_eq!(
+            QueryMsg::History {
+                history: HistoryQuery {
+                    start_after: None,
+                    limit: None,
+                }
+            },
+            from_slice(br#"{"history":{}}"#).unwrap()
+        );
+        assert_eq!(
+            QueryMsg::AutoPay {
+                auto_pay: AutoPayQuery {}
+            },
+            from_slice(br#"{"auto_pay":{}}"#).unwrap()
+        );
+    }
+
+    #[test]
+    fn unknown_query() {
+        assert!(from_slice::<QueryMsg>(br#"{"unknown":{}}"#).is_err());
+    }
+
+    #[test]
+    fn opened_before_close_policy() {
+        let lpn = |amount| Coin::<Usdc>::new(amount).into();
+        let opened = StateResponse::Opened {
+            amount: Coin::<Atom>::new(1000).into(),
+            loan_interest_rate: Percent::from_permille(70),
+            margin_interest_rate: Percent::from_permille(30),
+            principal_due: lpn(500),
+            previous_margin_due: lpn(0),
+            previous_interest_due: lpn(0),
+            current_margin_due: lpn(2),
+            current_interest_due: lpn(3),
+            liability: None,
+            validity: Timestamp::from_nanos(1000),
+            close_policy: ClosePolicy::default(),
+            in_progress: None,
+        };
+
+        let current = String::from_utf8(to_vec(&opened).unwrap()).unwrap();
+        let legacy = current.replace(r#""liability":null,"#, "").replace(
+            r#""close_policy":{"stop_loss":null,"take_profit":null},"#,
+            "",
+        );
+        assert!(!legacy.contains("close_policy"));
+        assert!(!legacy.contains("liability"));
+
+        assert_eq!(opened, from_slice(legacy.as_bytes()).unwrap());
+    }
+}
@@ -1,11 +1,14 @@
 use enum_dispatch::enum_dispatch;
 
 use sdk::cosmwasm_std::{
-    Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Timestamp,
+    Addr, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Timestamp,
 };
 
 use crate::{
-    api::StateResponse,
+    api::{
+        AutoPayResponse, ClosePolicy, LeaseCoin, LpnCoin, SimulateQuery, SimulateResponse,
+        StateResponse,
+    },
     error::{ContractError, ContractResult},
 };
 
@@ -34,7 +37,12 @@ where
         err("dex response")
     }
 
-    fn on_dex_error(self, _deps: Deps<'_>, _env: Env) -> ContractResult<Response> {
+    fn on_dex_error(
+        self,
+        _details: String,
+        _deps: Deps<'_>,
+        _env: Env,
+    ) -> ContractResult<Response> {
         err("dex error")
     }
 
@@ -74,6 +82,19 @@ where
 
     fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> ContractResult<StateResponse>;
 
+    fn simulate(
+        self,
+        _deps: Deps<'_>,
+        _env: Env,
+        _query: SimulateQuery,
+    ) -> ContractResult<SimulateResponse> {
+        err("simulate")
+    }
+
+    fn auto_pay(self, _deps: Deps<'_>) -> ContractResult<AutoPayResponse> {
+        err("auto pay")
+    }
+
     fn reply(self, _deps: &mut DepsMut<'_>, _env: Env, _msg: Reply) -> ContractResult<Response> {
         err("reply")
     }
@@ -87,6 +108,25 @@ where
         err("repay")
     }
 
+    fn add_collateral(
+        self,
+        _deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        err("add collateral")
+    }
+
+    fn increase_loan(
+        self,
+        _deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+        _amount: LpnCoin,
+    ) -> ContractResult<Response> {
+        err("increase loan")
+    }
+
     fn close(
         self,
         _deps: &mut DepsMut<'_>,
@@ -96,6 +136,67 @@ where
         err("close")
     }
 
+    fn close_position(
+        self,
+        _deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+        _amount: Option<LeaseCoin>,
+    ) -> ContractResult<Response> {
+        err("close position")
+    }
+
+    fn change_close_policy(
+        self,
+        _deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+        _policy: ClosePolicy,
+    ) -> ContractResult<Response> {
+        err("change close policy")
+    }
+
+    fn transfer_ownership(
+        self,
+        _deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+        _new_customer: Addr,
+    ) -> ContractResult<Response> {
+        err("transfer ownership")
+    }
+
+    fn bid(
+        self,
+        _deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        err("bid")
+    }
+
+    fn fund_auto_pay(
+        self,
+        _deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        err("fund auto pay")
+    }
+
+    fn withdraw_auto_pay(
+        self,
+        _deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        err("withdraw auto pay")
+    }
+
+    fn liquidate(self, _deps: Deps<'_>, _env: Env, _info: MessageInfo) -> ContractResult<Response> {
+        err("liquidate")
+    }
+
     fn on_time_alarm(
         self,
         _deps: Deps<'_>,
@@ -0,0 +1,131 @@
+use serde::Serialize;
+
+use currency::Currency;
+use finance::{
+    coin::{Coin, WithCoin, WithCoinResult},
+    percent::{Percent, Units},
+};
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::Oracle as OracleTrait;
+use platform::batch::Batch;
+use sdk::cosmwasm_std::Addr;
+
+use crate::{
+    api::{LeaseCoin, LpnCoin},
+    error::ContractError,
+    lease::{auction, with_lease::WithLease, Lease},
+};
+
+/// Determine the asks an auction of a lease asset starts from and ends at
+pub(crate) struct Asks<'a> {
+    asset: &'a LeaseCoin,
+    premium: Percent,
+    floor: Percent,
+}
+
+impl<'a> Asks<'a> {
+    pub fn new(asset: &'a LeaseCoin, premium: Percent, floor: Percent) -> Self {
+        Self {
+            asset,
+            premium,
+            floor,
+        }
+    }
+}
+
+impl<'a> WithLease for Asks<'a> {
+    type Output = (LpnCoin, LpnCoin);
+
+    type Error = ContractError;
+
+    fn exec<Lpn, Asset, Lpp, Oracle>(
+        self,
+        lease: Lease<Lpn, Asset, Lpp, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Lpn: Currency + Serialize,
+        Lpp: LppLoanTrait<Lpn>,
+        Oracle: OracleTrait<Lpn>,
+        Asset: Currency + Serialize,
+    {
+        lease
+            .auction_asks(self.asset.try_into()?, self.premium, self.floor)
+            .map(|(start, floor)| (start.into(), floor.into()))
+    }
+}
+
+/// Determine the ask of an auction after a number of decay periods
+pub(crate) struct DecayedAsk<'a> {
+    decay: Percent,
+    periods: Units,
+    floor: &'a LpnCoin,
+}
+
+impl<'a> DecayedAsk<'a> {
+    pub fn new(decay: Percent, periods: Units, floor: &'a LpnCoin) -> Self {
+        Self {
+            decay,
+            periods,
+            floor,
+        }
+    }
+}
+
+impl<'a> WithCoin for DecayedAsk<'a> {
+    type Output = LpnCoin;
+
+    type Error = ContractError;
+
+    fn on<C>(&self, start_ask: Coin<C>) -> WithCoinResult<Self>
+    where
+        C: Currency,
+    {
+        self.floor
+            .try_into()
+            .map(|floor| auction::decayed_ask(start_ask, self.decay, self.periods, floor).into())
+            .map_err(Into::into)
+    }
+}
+
+/// Settle a bid on an auction of a lease asset
+pub(crate) struct Bid<'a> {
+    asset: &'a LeaseCoin,
+    ask: &'a LpnCoin,
+    payment: LpnCoin,
+    buyer: Addr,
+}
+
+impl<'a> Bid<'a> {
+    pub fn new(asset: &'a LeaseCoin, ask: &'a LpnCoin, payment: LpnCoin, buyer: Addr) -> Self {
+        Self {
+            asset,
+            ask,
+            payment,
+            buyer,
+        }
+    }
+}
+
+impl<'a> WithLease for Bid<'a> {
+    type Output = Batch;
+
+    type Error = ContractError;
+
+    fn exec<Lpn, Asset, Lpp, Oracle>(
+        self,
+        _lease: Lease<Lpn, Asset, Lpp, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Lpn: Currency + Serialize,
+        Lpp: LppLoanTrait<Lpn>,
+        Oracle: OracleTrait<Lpn>,
+        Asset: Currency + Serialize,
+    {
+        auction::settle_bid::<Lpn, Asset>(
+            self.asset.try_into()?,
+            self.ask.try_into()?,
+            self.payment.try_into()?,
+            self.buyer,
+        )
+    }
+}
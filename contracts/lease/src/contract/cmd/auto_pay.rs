@@ -0,0 +1,63 @@
+use serde::Serialize;
+
+use currency::Currency;
+use finance::coin::Coin;
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::Oracle as OracleTrait;
+use sdk::cosmwasm_std::Timestamp;
+
+use crate::{
+    api::LpnCoin,
+    error::ContractError,
+    lease::{with_lease::WithLease, Lease},
+};
+
+/// Determine how much of the overdue margin and loan interest to pay out of an auto-pay reserve
+pub(crate) struct AutoPay<'a> {
+    reserve: &'a LpnCoin,
+    now: Timestamp,
+}
+
+impl<'a> AutoPay<'a> {
+    pub fn new(reserve: &'a LpnCoin, now: Timestamp) -> Self {
+        Self { reserve, now }
+    }
+}
+
+pub(crate) struct AutoPayResult {
+    /// The amount to pay, zero if nothing is overdue or the reserve is empty
+    pub payment: LpnCoin,
+    /// The reserve left after the payment
+    pub reserve: LpnCoin,
+    /// Whether the reserve left falls short of the overdue amount
+    pub low: bool,
+}
+
+impl<'a> WithLease for AutoPay<'a> {
+    type Output = AutoPayResult;
+
+    type Error = ContractError;
+
+    fn exec<Lpn, Asset, LppLoan, Oracle>(
+        self,
+        lease: Lease<Lpn, Asset, LppLoan, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Lpn: Currency + Serialize,
+        LppLoan: LppLoanTrait<Lpn>,
+        Oracle: OracleTrait<Lpn>,
+        Asset: Currency + Serialize,
+    {
+        let reserve: Coin<Lpn> = self.reserve.try_into()?;
+        let state = lease.state(self.now);
+        let overdue = state.previous_margin_due + state.previous_interest_due;
+        let payment = overdue.min(reserve);
+        let reserve_left = reserve - payment;
+
+        Ok(AutoPayResult {
+            payment: payment.into(),
+            reserve: reserve_left.into(),
+            low: reserve_left < overdue,
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use serde::Serialize;
+
+use currency::Currency;
+use finance::percent::Percent;
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::{Oracle as OracleTrait, OracleRef};
+use platform::batch::Batch;
+use profit::stub::ProfitRef;
+use sdk::cosmwasm_std::Timestamp;
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    api::ClosePolicy,
+    error::ContractError,
+    lease::{with_lease::WithLease, IntoDTOResult, Lease, LeaseDTO},
+};
+
+use super::{liquidation_status, LiquidationStatus};
+
+pub(crate) struct ChangeClosePolicy {
+    policy: ClosePolicy,
+    now: Timestamp,
+    liquidation_penalty: Percent,
+    profit: ProfitRef,
+    time_alarms: TimeAlarmsRef,
+    price_alarms: OracleRef,
+}
+
+impl ChangeClosePolicy {
+    pub fn new(
+        policy: ClosePolicy,
+        now: Timestamp,
+        liquidation_penalty: Percent,
+        profit: ProfitRef,
+        time_alarms: TimeAlarmsRef,
+        price_alarms: OracleRef,
+    ) -> Self {
+        Self {
+            policy,
+            now,
+            liquidation_penalty,
+            profit,
+            time_alarms,
+            price_alarms,
+        }
+    }
+}
+
+pub(crate) struct ChangeClosePolicyResult {
+    pub lease: LeaseDTO,
+    pub messages: Batch,
+    pub liquidation: LiquidationStatus,
+}
+
+impl WithLease for ChangeClosePolicy {
+    type Output = ChangeClosePolicyResult;
+
+    type Error = ContractError;
+
+    fn exec<Lpn, Asset, Lpp, Oracle>(
+        self,
+        mut lease: Lease<Lpn, Asset, Lpp, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Lpn: Currency + Serialize,
+        Lpp: LppLoanTrait<Lpn>,
+        Oracle: OracleTrait<Lpn>,
+        Asset: Currency + Serialize,
+    {
+        lease.change_close_policy(self.policy.try_into()?)?;
+
+        let liquidation = liquidation_status::status_and_schedule(
+            &lease,
+            self.now,
+            self.liquidation_penalty,
+            &self.time_alarms,
+            &self.price_alarms,
+        )?;
+
+        lease.try_into_dto(self.profit, self.time_alarms).map(
+            |IntoDTOResult {
+                 lease,
+                 batch: messages,
+             }| ChangeClosePolicyResult {
+                lease,
+                messages,
+                liquidation,
+            },
+        )
+    }
+}
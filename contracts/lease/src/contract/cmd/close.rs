@@ -2,17 +2,30 @@ use currency::Currency;
 use platform::{bank::BankAccount, batch::Batch};
 
 use crate::{
+    api::LpnCoin,
     error::ContractError,
     lease::{with_lease_paid::WithLeaseTypes, LeaseDTO, LeasePaid},
 };
 
 pub struct Close<Bank> {
     lease_account: Bank,
+    proceeds: Option<LpnCoin>,
 }
 
 impl<Bank> Close<Bank> {
     pub fn new(lease_account: Bank) -> Self {
-        Self { lease_account }
+        Self {
+            lease_account,
+            proceeds: None,
+        }
+    }
+
+    /// Close a lease whose amount has been sold for the given proceeds
+    pub fn sold(lease_account: Bank, proceeds: LpnCoin) -> Self {
+        Self {
+            lease_account,
+            proceeds: Some(proceeds),
+        }
     }
 }
 
@@ -29,6 +42,10 @@ where
         Asset: Currency,
         Lpn: Currency,
     {
-        LeasePaid::<Asset, Lpn>::from_dto(dto).close(self.lease_account)
+        let lease = LeasePaid::<Asset, Lpn>::from_dto(dto);
+        match self.proceeds {
+            None => lease.close(self.lease_account),
+            Some(proceeds) => lease.close_sold(self.lease_account, proceeds.try_into()?),
+        }
     }
 }
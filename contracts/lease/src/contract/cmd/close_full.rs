@@ -0,0 +1,93 @@
+use currency::Currency;
+use finance::percent::bound::BoundToHundredPercent;
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::Oracle as OracleTrait;
+use platform::batch::Batch;
+use profit::stub::ProfitRef;
+use sdk::cosmwasm_std::Timestamp;
+use serde::Serialize;
+
+use crate::{
+    api::LpnCoin,
+    error::ContractError,
+    lease::{with_lease::WithLease, Lease},
+};
+
+use super::ReceiptDTO;
+
+pub(crate) struct CloseResult {
+    pub receipt: ReceiptDTO,
+    pub messages: Batch,
+}
+
+pub(crate) struct Close {
+    payment: LpnCoin,
+    now: Timestamp,
+    profit: ProfitRef,
+}
+
+impl Close {
+    pub fn new(payment: LpnCoin, now: Timestamp, profit: ProfitRef) -> Self {
+        Self {
+            payment,
+            now,
+            profit,
+        }
+    }
+}
+
+impl WithLease for Close {
+    type Output = CloseResult;
+
+    type Error = ContractError;
+
+    fn exec<Lpn, Asset, Lpp, Oracle>(
+        self,
+        lease: Lease<Lpn, Asset, Lpp, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Lpn: Currency + Serialize,
+        Lpp: LppLoanTrait<Lpn>,
+        Oracle: OracleTrait<Lpn>,
+        Asset: Currency + Serialize,
+    {
+        lease
+            .close_full(self.payment.try_into()?, self.now, self.profit.as_stub())
+            .map(|(receipt, messages)| CloseResult {
+                receipt: receipt.into(),
+                messages,
+            })
+    }
+}
+
+pub(crate) struct Check {
+    max_slippage: BoundToHundredPercent,
+    now: Timestamp,
+}
+
+impl Check {
+    pub fn new(max_slippage: BoundToHundredPercent, now: Timestamp) -> Self {
+        Self { max_slippage, now }
+    }
+}
+
+impl WithLease for Check {
+    type Output = LpnCoin;
+
+    type Error = ContractError;
+
+    fn exec<Lpn, Asset, Lpp, Oracle>(
+        self,
+        lease: Lease<Lpn, Asset, Lpp, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Lpn: Currency + Serialize,
+        Lpp: LppLoanTrait<Lpn>,
+        Oracle: OracleTrait<Lpn>,
+        Asset: Currency + Serialize,
+    {
+        lease
+            .check_close_full(self.max_slippage, self.now)
+            .map(Into::into)
+    }
+}
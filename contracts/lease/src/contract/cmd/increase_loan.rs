@@ -0,0 +1,77 @@
+use serde::Serialize;
+
+use currency::Currency;
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::Oracle as OracleTrait;
+use platform::batch::Batch;
+use profit::stub::ProfitRef;
+use sdk::cosmwasm_std::Timestamp;
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    api::LpnCoin,
+    error::ContractError,
+    lease::{with_lease::WithLease, IntoDTOResult, Lease, LeaseDTO},
+};
+
+pub(crate) struct IncreaseLoan {
+    amount: LpnCoin,
+    now: Timestamp,
+    profit: ProfitRef,
+    time_alarms: TimeAlarmsRef,
+}
+
+impl IncreaseLoan {
+    pub fn new(
+        amount: LpnCoin,
+        now: Timestamp,
+        profit: ProfitRef,
+        time_alarms: TimeAlarmsRef,
+    ) -> Self {
+        Self {
+            amount,
+            now,
+            profit,
+            time_alarms,
+        }
+    }
+}
+
+pub(crate) struct IncreaseLoanResult {
+    pub lease: LeaseDTO,
+    pub proceeds: LpnCoin,
+    pub messages: Batch,
+}
+
+impl WithLease for IncreaseLoan {
+    type Output = IncreaseLoanResult;
+
+    type Error = ContractError;
+
+    fn exec<Lpn, Asset, Lpp, Oracle>(
+        self,
+        mut lease: Lease<Lpn, Asset, Lpp, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Lpn: Currency + Serialize,
+        Lpp: LppLoanTrait<Lpn>,
+        Oracle: OracleTrait<Lpn>,
+        Asset: Currency + Serialize,
+    {
+        let amount = self.amount.try_into()?;
+        let mut profit = self.profit.as_stub();
+
+        let proceeds = lease.increase_loan(amount, self.now, &mut profit)?;
+
+        lease.try_into_dto(self.profit, self.time_alarms).map(
+            |IntoDTOResult {
+                 lease,
+                 batch: messages,
+             }| IncreaseLoanResult {
+                lease,
+                proceeds: proceeds.into(),
+                messages: messages.merge(profit.into()),
+            },
+        )
+    }
+}
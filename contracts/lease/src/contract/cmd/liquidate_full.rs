@@ -3,52 +3,64 @@ use lpp::stub::loan::LppLoan as LppLoanTrait;
 use oracle::stub::Oracle as OracleTrait;
 use platform::batch::Batch;
 use profit::stub::ProfitRef;
-use sdk::cosmwasm_std::Timestamp;
+use sdk::cosmwasm_std::{Addr, Timestamp};
 use serde::Serialize;
 
 use crate::{
-    api::LpnCoin,
+    api::{LiquidationFee, LpnCoin},
     error::ContractError,
-    lease::{with_lease::WithLease, FullRepayReceipt, Lease},
+    lease::{with_lease::WithLease, FullRepayReceipt, Lease, PenaltyDTO},
 };
 
 use super::ReceiptDTO;
 
 pub(crate) struct LiquidateResult {
     pub receipt: ReceiptDTO,
+    pub penalty: PenaltyDTO,
     pub messages: Batch,
 }
 
 impl LiquidateResult {
-    fn new(receipt: ReceiptDTO, messages: Batch) -> Self {
+    fn new(receipt: ReceiptDTO, penalty: PenaltyDTO, messages: Batch) -> Self {
         debug_assert!(
             receipt.close,
             "The full-liquidation payment should have repaid the total outstanding liability!"
         );
-        Self { receipt, messages }
+        Self {
+            receipt,
+            penalty,
+            messages,
+        }
     }
-}
 
-impl<Lpn> From<FullRepayReceipt<Lpn>> for LiquidateResult
-where
-    Lpn: Currency,
-{
-    fn from(value: FullRepayReceipt<Lpn>) -> Self {
+    fn with_penalty<Lpn>(value: FullRepayReceipt<Lpn>, penalty: PenaltyDTO, bounty: Batch) -> Self
+    where
+        Lpn: Currency,
+    {
         let (receipt, messages) = value.decompose();
-        Self::new(receipt.into(), messages)
+        Self::new(receipt.into(), penalty, messages.merge(bounty))
     }
 }
 
 pub(crate) struct Liquidate {
     payment: LpnCoin,
+    fee: LiquidationFee,
+    keeper: Option<Addr>,
     now: Timestamp,
     profit: ProfitRef,
 }
 
 impl Liquidate {
-    pub fn new(payment: LpnCoin, now: Timestamp, profit: ProfitRef) -> Self {
+    pub fn new(
+        payment: LpnCoin,
+        (fee, keeper): (LiquidationFee, Option<Addr>),
+        now: Timestamp,
+        profit: ProfitRef,
+    ) -> Self {
         Self {
             payment,
+            fee,
+            keeper,
             now,
             profit,
         }
@@ -70,10 +82,20 @@ impl WithLease for Liquidate {
         Oracle: OracleTrait<Lpn>,
         Asset: Currency + Serialize,
     {
+        let mut profit = self.profit.as_stub();
+        let penalty = lease.full_liquidation_penalty(
+            self.payment.try_into()?,
+            &self.fee,
+            self.keeper,
+            self.now,
+        );
+        let payment = penalty.payment();
+        let (penalty, bounty_messages) = penalty.charge(&mut profit);
+
         // TODO [issue #92] request the needed amount from the Liquidation Fund and
         // make sure the message goes out before the liquidation messages.
         lease
-            .liquidate_full(self.payment.try_into()?, self.now, self.profit.as_stub())
-            .map(Into::into)
+            .liquidate_full(payment, self.now, profit)
+            .map(|receipt| LiquidateResult::with_penalty(receipt, penalty, bounty_messages))
     }
 }
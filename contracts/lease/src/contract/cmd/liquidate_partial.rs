@@ -1,24 +1,33 @@
 use currency::Currency;
 use lpp::stub::loan::LppLoan as LppLoanTrait;
 use oracle::stub::{Oracle as OracleTrait, OracleRef};
+use platform::batch::Batch;
 use profit::stub::ProfitRef;
-use sdk::cosmwasm_std::Timestamp;
+use sdk::cosmwasm_std::{Addr, Timestamp};
 use serde::Serialize;
 use timealarms::stub::TimeAlarmsRef;
 
 use crate::{
-    api::{LeaseCoin, LpnCoin},
+    api::{LeaseCoin, LiquidationFee, LpnCoin},
     error::ContractError,
-    lease::{with_lease::WithLease, IntoDTOResult, Lease},
+    lease::{with_lease::WithLease, IntoDTOResult, Lease, LeaseDTO, PenaltyDTO},
 };
 
-use super::{liquidation_status, RepayResult};
+use super::{liquidation_status, LiquidationStatus, ReceiptDTO};
 
-pub(crate) type LiquidateResult = RepayResult;
+pub(crate) struct LiquidateResult {
+    pub lease: LeaseDTO,
+    pub receipt: ReceiptDTO,
+    pub penalty: PenaltyDTO,
+    pub messages: Batch,
+    pub liquidation: LiquidationStatus,
+}
 
 pub(crate) struct Liquidate {
     asset: LeaseCoin,
     payment: LpnCoin,
+    fee: LiquidationFee,
+    keeper: Option<Addr>,
     now: Timestamp,
     profit: ProfitRef,
     time_alarms: TimeAlarmsRef,
@@ -29,6 +38,7 @@ impl Liquidate {
     pub fn new(
         asset: LeaseCoin,
         payment: LpnCoin,
+        (fee, keeper): (LiquidationFee, Option<Addr>),
         now: Timestamp,
         profit: ProfitRef,
         time_alarms: TimeAlarmsRef,
@@ -37,6 +47,8 @@ impl Liquidate {
         Self {
             asset,
             payment,
+            fee,
+            keeper,
             now,
             profit,
             time_alarms,
@@ -61,16 +73,20 @@ impl WithLease for Liquidate {
         Asset: Currency + Serialize,
     {
         let mut profit = self.profit.as_stub();
+        let penalty =
+            lease.partial_liquidation_penalty(self.payment.try_into()?, &self.fee, self.keeper);
         let receipt = lease.liquidate_partial(
             self.asset.try_into()?,
-            self.payment.try_into()?,
+            penalty.payment(),
             self.now,
             &mut profit,
         )?;
+        let (penalty, bounty_messages) = penalty.charge(&mut profit);
 
         let liquidation = liquidation_status::status_and_schedule(
             &lease,
             self.now,
+            self.fee.penalty.percent(),
             &self.time_alarms,
             &self.price_alarms,
         )?;
@@ -82,7 +98,8 @@ impl WithLease for Liquidate {
              }| Self::Output {
                 lease,
                 receipt: receipt.into(),
-                messages: messages.merge(profit.into()),
+                penalty,
+                messages: messages.merge(profit.into()).merge(bounty_messages),
                 liquidation,
             },
         )
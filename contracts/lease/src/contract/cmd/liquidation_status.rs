@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use currency::Currency;
-use finance::liability::{Cause, Liquidation, Status, Zone};
+use finance::{
+    liability::{Cause, Liquidation, Status, Zone},
+    percent::Percent,
+};
 use lpp::stub::loan::LppLoan as LppLoanTrait;
 use oracle::stub::{Oracle as OracleTrait, OracleRef};
 use platform::batch::Batch;
@@ -11,12 +14,13 @@ use timealarms::stub::TimeAlarmsRef;
 use crate::{
     api::LeaseCoin,
     error::{ContractError, ContractResult},
-    lease::{with_lease::WithLease, Lease, LeaseDTO},
+    lease::{with_lease::WithLease, CloseTrigger, Lease, LeaseDTO},
 };
 
 pub(crate) fn status_and_schedule<Lpn, Asset, Lpp, Oracle>(
     lease: &Lease<Lpn, Asset, Lpp, Oracle>,
     when: Timestamp,
+    liquidation_penalty: Percent,
     time_alarms: &TimeAlarmsRef,
     price_alarms: &OracleRef,
 ) -> ContractResult<CmdResult>
@@ -26,12 +30,15 @@ where
     Oracle: OracleTrait<Lpn>,
     Asset: Currency + Serialize,
 {
-    let status = lease.liquidation_status(when)?;
+    let status = lease.liquidation_status(when, liquidation_penalty)?;
     Ok(match status {
         Status::NoDebt => CmdResult::NoDebt,
-        Status::No(zone) => CmdResult::NewAlarms {
-            alarms: lease.reschedule(&when, &zone, time_alarms, price_alarms)?,
-            current_liability: zone,
+        Status::No(zone) => match lease.close_policy_trigger()? {
+            Some(trigger) => CmdResult::NeedClose(trigger),
+            None => CmdResult::NewAlarms {
+                alarms: lease.reschedule(&when, &zone, time_alarms, price_alarms)?,
+                current_liability: zone,
+            },
         },
         Status::Liquidation(liquidation) => CmdResult::NeedLiquidation(liquidation.into()),
     })
@@ -39,6 +46,7 @@ where
 
 pub(crate) struct Cmd<'a> {
     now: Timestamp,
+    liquidation_penalty: Percent,
     time_alarms: &'a TimeAlarmsRef,
     price_alarms: &'a OracleRef,
 }
@@ -50,6 +58,7 @@ pub(crate) enum CmdResult {
         alarms: Batch,
     },
     NeedLiquidation(LiquidationDTO),
+    NeedClose(CloseTrigger),
 }
 
 #[derive(Serialize, Deserialize)]
@@ -92,11 +101,13 @@ where
 impl<'a> Cmd<'a> {
     pub fn new(
         now: Timestamp,
+        liquidation_penalty: Percent,
         time_alarms: &'a TimeAlarmsRef,
         price_alarms: &'a OracleRef,
     ) -> Self {
         Self {
             now,
+            liquidation_penalty,
             time_alarms,
             price_alarms,
         }
@@ -118,6 +129,12 @@ impl<'a> WithLease for Cmd<'a> {
         Oracle: OracleTrait<Lpn>,
         Asset: Currency + Serialize,
     {
-        status_and_schedule(&lease, self.now, self.time_alarms, self.price_alarms)
+        status_and_schedule(
+            &lease,
+            self.now,
+            self.liquidation_penalty,
+            self.time_alarms,
+            self.price_alarms,
+        )
     }
 }
@@ -1,4 +1,12 @@
+pub(crate) use add_collateral::{AddCollateral, AddCollateralResult};
+pub(crate) use auction::{Asks as AuctionAsks, Bid, DecayedAsk};
+pub(crate) use auto_pay::{AutoPay, AutoPayResult};
+pub(crate) use change_close_policy::{ChangeClosePolicy, ChangeClosePolicyResult};
 pub(crate) use close::Close;
+pub(crate) use close_full::{
+    Check as FullCloseCheck, Close as FullClose, CloseResult as FullCloseResult,
+};
+pub(crate) use increase_loan::{IncreaseLoan, IncreaseLoanResult};
 pub(crate) use liquidate_full::{
     Liquidate as FullLiquidation, LiquidateResult as FullLiquidationResult,
 };
@@ -11,13 +19,23 @@ pub(crate) use liquidation_status::{
 pub(crate) use open::open_lease;
 pub(crate) use open_loan::{OpenLoanReq, OpenLoanReqResult, OpenLoanResp, OpenLoanRespResult};
 pub(crate) use repay::{ReceiptDTO, Repay, RepayResult};
+pub(crate) use requote::{Requote, RequoteResult};
+pub(crate) use simulate::Simulate;
 pub(crate) use state::LeaseState;
 
+mod add_collateral;
+mod auction;
+mod auto_pay;
+mod change_close_policy;
 mod close;
+mod close_full;
+mod increase_loan;
 mod liquidate_full;
 mod liquidate_partial;
 mod liquidation_status;
 mod open;
 mod open_loan;
 mod repay;
+mod requote;
+mod simulate;
 mod state;
@@ -80,14 +80,18 @@ impl<'a> WithLeaseDeps for LeaseFactory<'a> {
             lpp_loan,
             self.form.loan.annual_margin_interest,
             self.form.loan.interest_payment,
+            self.form.rate_type,
         );
         let amount: Coin<Asset> = self.amount.try_into()?;
+        let min_liquidation: Coin<Lpn> = self.form.min_liquidation.try_into()?;
+        let min_asset: Coin<Lpn> = self.form.min_asset.try_into()?;
 
         let lease = Lease::<_, Asset, _, _>::new(
             self.lease_addr,
             self.form.customer,
             amount,
             liability,
+            (min_liquidation, min_asset),
             loan,
             oracle,
         );
@@ -95,6 +99,7 @@ impl<'a> WithLeaseDeps for LeaseFactory<'a> {
         let alarms = match liquidation_status::status_and_schedule(
             &lease,
             self.start_at,
+            self.form.liquidation_fee.penalty.percent(),
             &self.time_alarms,
             &self.price_alarms,
         )? {
@@ -103,7 +108,9 @@ impl<'a> WithLeaseDeps for LeaseFactory<'a> {
                 current_liability: _,
                 alarms,
             } => alarms,
-            LiquidationStatus::NeedLiquidation(_) => unreachable!(),
+            LiquidationStatus::NeedLiquidation(_) | LiquidationStatus::NeedClose(_) => {
+                unreachable!()
+            }
         };
 
         lease
@@ -10,7 +10,10 @@ use finance::{
 };
 use lpp::stub::lender::{LppLender as LppLenderTrait, WithLppLender};
 use oracle::{convert, stub::OracleRef};
-use platform::{bank, batch::Batch};
+use platform::{
+    bank::{self, Aggregate},
+    batch::Batch,
+};
 use sdk::cosmwasm_std::{Coin as CwCoin, QuerierWrapper, Reply};
 
 use crate::{
@@ -54,7 +57,10 @@ impl<'a> WithLppLender for OpenLoanReq<'a> {
         Lpn: Currency + Serialize,
         LppLender: LppLenderTrait<Lpn>,
     {
-        let (downpayment, downpayment_lpn) = bank::may_received::<PaymentGroup, _>(
+        let Downpayment {
+            coins: downpayment,
+            lpn: downpayment_lpn,
+        } = bank::may_received_all::<PaymentGroup, _>(
             self.funds_in,
             DownpaymentHandler {
                 oracle: self.oracle,
@@ -65,7 +71,7 @@ impl<'a> WithLppLender for OpenLoanReq<'a> {
         .ok_or_else(Self::Error::NoPaymentError)??;
 
         if downpayment_lpn.is_zero() {
-            return Err(Self::Error::InsufficientPayment(downpayment));
+            return Err(Self::Error::InsufficientPayment(downpayment[0].clone()));
         }
 
         let borrow_lpn = self
@@ -81,6 +87,23 @@ impl<'a> WithLppLender for OpenLoanReq<'a> {
     }
 }
 
+struct Downpayment<Lpn> {
+    coins: Vec<DownpaymentCoin>,
+    lpn: Coin<Lpn>,
+}
+
+impl<Lpn> Aggregate for Downpayment<Lpn>
+where
+    Lpn: Currency,
+{
+    fn aggregate(self, other: Self) -> Self {
+        Self {
+            coins: self.coins.aggregate(other.coins),
+            lpn: self.lpn + other.lpn,
+        }
+    }
+}
+
 struct DownpaymentHandler<'a, Lpn> {
     oracle: OracleRef,
     _lpn: PhantomData<Lpn>,
@@ -90,7 +113,7 @@ impl<'a, Lpn> WithCoin for DownpaymentHandler<'a, Lpn>
 where
     Lpn: Currency,
 {
-    type Output = (DownpaymentCoin, Coin<Lpn>);
+    type Output = Downpayment<Lpn>;
 
     type Error = ContractError;
 
@@ -100,15 +123,16 @@ where
     {
         let downpayment_lpn = convert::to_base(self.oracle.clone(), in_amount, self.querier)?;
 
-        let downpayment = in_amount.into();
-
-        Ok((downpayment, downpayment_lpn))
+        Ok(Downpayment {
+            coins: vec![in_amount.into()],
+            lpn: downpayment_lpn,
+        })
     }
 }
 
 pub struct OpenLoanReqResult {
     pub(in crate::contract) batch: Batch,
-    pub(in crate::contract) downpayment: DownpaymentCoin,
+    pub(in crate::contract) downpayment: Vec<DownpaymentCoin>,
 }
 
 pub struct OpenLoanResp {
@@ -1,6 +1,7 @@
 use serde::Serialize;
 
 use currency::Currency;
+use finance::percent::Percent;
 use lpp::stub::loan::LppLoan as LppLoanTrait;
 use oracle::stub::{Oracle as OracleTrait, OracleRef};
 use platform::batch::Batch;
@@ -20,6 +21,7 @@ use super::{liquidation_status, LiquidationStatus};
 pub(crate) struct Repay {
     payment: LpnCoin,
     now: Timestamp,
+    liquidation_penalty: Percent,
     profit: ProfitRef,
     time_alarms: TimeAlarmsRef,
     price_alarms: OracleRef,
@@ -29,6 +31,7 @@ impl Repay {
     pub fn new(
         payment: LpnCoin,
         now: Timestamp,
+        liquidation_penalty: Percent,
         profit: ProfitRef,
         time_alarms: TimeAlarmsRef,
         price_alarms: OracleRef,
@@ -36,6 +39,7 @@ impl Repay {
         Self {
             payment,
             now,
+            liquidation_penalty,
             profit,
             time_alarms,
             price_alarms,
@@ -84,6 +88,7 @@ impl WithLease for Repay {
         let liquidation = liquidation_status::status_and_schedule(
             &lease,
             self.now,
+            self.liquidation_penalty,
             &self.time_alarms,
             &self.price_alarms,
         )?;
@@ -0,0 +1,63 @@
+use serde::Serialize;
+
+use currency::Currency;
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::Oracle as OracleTrait;
+use platform::batch::Batch;
+use profit::stub::ProfitRef;
+use sdk::cosmwasm_std::Timestamp;
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    error::ContractError,
+    lease::{with_lease::WithLease, IntoDTOResult, Lease, LeaseDTO},
+};
+
+pub(crate) struct Requote {
+    now: Timestamp,
+    profit: ProfitRef,
+    time_alarms: TimeAlarmsRef,
+}
+
+impl Requote {
+    pub fn new(now: Timestamp, profit: ProfitRef, time_alarms: TimeAlarmsRef) -> Self {
+        Self {
+            now,
+            profit,
+            time_alarms,
+        }
+    }
+}
+
+pub(crate) struct RequoteResult {
+    pub lease: LeaseDTO,
+    pub messages: Batch,
+}
+
+impl WithLease for Requote {
+    type Output = Option<RequoteResult>;
+
+    type Error = ContractError;
+
+    fn exec<Lpn, Asset, Lpp, Oracle>(
+        self,
+        mut lease: Lease<Lpn, Asset, Lpp, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Lpn: Currency + Serialize,
+        Lpp: LppLoanTrait<Lpn>,
+        Oracle: OracleTrait<Lpn>,
+        Asset: Currency + Serialize,
+    {
+        if !lease.requote(self.now) {
+            return Ok(None);
+        }
+
+        lease.try_into_dto(self.profit, self.time_alarms).map(
+            |IntoDTOResult {
+                 lease,
+                 batch: messages,
+             }| Some(RequoteResult { lease, messages }),
+        )
+    }
+}
@@ -0,0 +1,50 @@
+use serde::Serialize;
+
+use currency::Currency;
+use finance::percent::Percent;
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::Oracle as OracleTrait;
+use sdk::cosmwasm_std::Timestamp;
+
+use crate::{
+    api::SimulateResponse,
+    error::ContractError,
+    lease::{with_lease::WithLease, Lease},
+};
+
+pub struct Simulate {
+    price_changes: Vec<Percent>,
+    at: Timestamp,
+    liquidation_penalty: Percent,
+}
+
+impl Simulate {
+    pub fn new(price_changes: Vec<Percent>, at: Timestamp, liquidation_penalty: Percent) -> Self {
+        Self {
+            price_changes,
+            at,
+            liquidation_penalty,
+        }
+    }
+}
+
+impl WithLease for Simulate {
+    type Output = SimulateResponse;
+
+    type Error = ContractError;
+
+    fn exec<Lpn, Asset, LppLoan, Oracle>(
+        self,
+        lease: Lease<Lpn, Asset, LppLoan, Oracle>,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Lpn: Currency + Serialize,
+        LppLoan: LppLoanTrait<Lpn>,
+        Oracle: OracleTrait<Lpn>,
+        Asset: Currency + Serialize,
+    {
+        lease
+            .simulate(&self.price_changes, self.at, self.liquidation_penalty)
+            .map(|scenarios| SimulateResponse::from_scenarios(self.at, scenarios))
+    }
+}
@@ -1,5 +1,5 @@
 use currency::lease::LeaseGroup;
-use platform::response;
+use platform::{message::Response as MessageResponse, response};
 #[cfg(feature = "contract-with-bindings")]
 use sdk::cosmwasm_std::entry_point;
 use sdk::{
@@ -10,14 +10,14 @@ use sdk::{
 use versioning::{version, VersionSegment};
 
 use crate::{
-    api::{ExecuteMsg, MigrateMsg, NewLeaseContract, StateQuery},
+    api::{ExecuteMsg, MigrateMsg, NewLeaseContract, QueryMsg, StateQuery},
     contract::api::Contract,
     error::{ContractError, ContractResult},
 };
 
 #[cfg(feature = "migration")]
 use super::state::Migrate;
-use super::state::{self, Response, State};
+use super::state::{self, history, Response, State};
 
 #[cfg(feature = "migration")]
 const CONTRACT_STORAGE_VERSION_FROM: VersionSegment = 4;
@@ -26,7 +26,7 @@ const CONTRACT_STORAGE_VERSION: VersionSegment = 5;
 #[cfg_attr(feature = "contract-with-bindings", entry_point)]
 pub fn instantiate(
     mut deps: DepsMut<'_>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     new_lease: NewLeaseContract,
 ) -> ContractResult<CwResponse> {
@@ -42,13 +42,19 @@ pub fn instantiate(
     versioning::initialize(deps.storage, version!(CONTRACT_STORAGE_VERSION))?;
 
     state::new_lease(&mut deps, info, new_lease)
-        .and_then(|(batch, next_state)| state::save(deps.storage, &next_state).map(|()| batch))
+        .and_then(|(batch, next_state)| {
+            state::save(deps.storage, &next_state, env.block.time).map(|()| batch)
+        })
         .map(response::response_only_messages)
         .or_else(|err| log_error(err, deps.api))
 }
 
 #[cfg_attr(feature = "contract-with-bindings", entry_point)]
-pub fn migrate(deps: DepsMut<'_>, _env: Env, _msg: MigrateMsg) -> ContractResult<CwResponse> {
+pub fn migrate(
+    deps: DepsMut<'_>,
+    #[cfg_attr(not(feature = "migration"), allow(unused_variables))] env: Env,
+    _msg: MigrateMsg,
+) -> ContractResult<CwResponse> {
     #[cfg(feature = "migration")]
     let resp =
         versioning::update_software_and_storage::<CONTRACT_STORAGE_VERSION_FROM, _, _, _, _>(
@@ -62,7 +68,7 @@ pub fn migrate(deps: DepsMut<'_>, _env: Env, _msg: MigrateMsg) -> ContractResult
                              response,
                              next_state: lease_v5,
                          }| {
-                            state::save(storage, &lease_v5).map(|()| response)
+                            state::save(storage, &lease_v5, env.block.time).map(|()| response)
                         },
                     )
             },
@@ -79,13 +85,14 @@ pub fn migrate(deps: DepsMut<'_>, _env: Env, _msg: MigrateMsg) -> ContractResult
 
 #[cfg_attr(feature = "contract-with-bindings", entry_point)]
 pub fn reply(mut deps: DepsMut<'_>, env: Env, msg: Reply) -> ContractResult<CwResponse> {
+    let now = env.block.time;
     state::load(deps.storage)
         .and_then(|state| state.reply(&mut deps, env, msg))
         .and_then(
             |Response {
                  response,
                  next_state,
-             }| state::save(deps.storage, &next_state).map(|()| response),
+             }| state::save(deps.storage, &next_state, now).map(|()| response),
         )
         .map(response::response_only_messages)
         .or_else(|err| log_error(err, deps.api))
@@ -98,13 +105,14 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> ContractResult<CwResponse> {
+    let now = env.block.time;
     state::load(deps.storage)
         .and_then(|state| process_execute(msg, state, &mut deps, env, info))
         .and_then(
             |Response {
                  response,
                  next_state,
-             }| state::save(deps.storage, &next_state).map(|()| response),
+             }| state::save(deps.storage, &next_state, now).map(|()| response),
         )
         .map(response::response_only_messages)
         .or_else(|err| log_error(err, deps.api))
@@ -112,24 +120,41 @@ pub fn execute(
 
 #[cfg_attr(feature = "contract-with-bindings", entry_point)]
 pub fn sudo(deps: DepsMut<'_>, env: Env, msg: SudoMsg) -> ContractResult<CwResponse> {
+    let now = env.block.time;
     state::load(deps.storage)
         .and_then(|state| process_sudo(msg, state, deps.as_ref(), env))
         .and_then(
             |Response {
                  response,
                  next_state,
-             }| state::save(deps.storage, &next_state).map(|()| response),
+             }| state::save(deps.storage, &next_state, now).map(|()| response),
         )
         .map(response::response_only_messages)
         .or_else(|err| log_error(err, deps.api))
 }
 
 #[cfg_attr(feature = "contract-with-bindings", entry_point)]
-pub fn query(deps: Deps<'_>, env: Env, _msg: StateQuery) -> ContractResult<Binary> {
-    state::load(deps.storage)
-        .and_then(|state| state.state(env.block.time, &deps.querier))
-        .and_then(|resp| to_binary(&resp).map_err(Into::into))
-        .or_else(|err| log_error(err, deps.api))
+pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
+    match msg {
+        QueryMsg::History { history } => history::query(deps.storage, history)
+            .and_then(|resp| to_binary(&resp).map_err(Into::into)),
+        QueryMsg::AutoPay { auto_pay: _ } => state::load(deps.storage).and_then(|state| {
+            state
+                .auto_pay(deps)
+                .and_then(|resp| to_binary(&resp).map_err(Into::into))
+        }),
+        QueryMsg::State(StateQuery {}) => state::load(deps.storage).and_then(|state| {
+            state
+                .state(env.block.time, &deps.querier)
+                .and_then(|resp| to_binary(&resp).map_err(Into::into))
+        }),
+        QueryMsg::Simulate { simulate } => state::load(deps.storage).and_then(|state| {
+            state
+                .simulate(deps, env, simulate)
+                .and_then(|resp| to_binary(&resp).map_err(Into::into))
+        }),
+    }
+    .or_else(|err| log_error(err, deps.api))
 }
 
 fn process_execute(
@@ -141,7 +166,18 @@ fn process_execute(
 ) -> ContractResult<Response> {
     match msg {
         ExecuteMsg::Repay() => state.repay(deps, env, info),
+        ExecuteMsg::AddCollateral() => state.add_collateral(deps, env, info),
+        ExecuteMsg::IncreaseLoan { amount } => state.increase_loan(deps, env, info, amount),
         ExecuteMsg::Close() => state.close(deps, env, info),
+        ExecuteMsg::ClosePosition { amount } => state.close_position(deps, env, info, amount),
+        ExecuteMsg::ChangeClosePolicy(policy) => state.change_close_policy(deps, env, info, policy),
+        ExecuteMsg::TransferOwnership { new_customer } => {
+            state.transfer_ownership(deps, env, info, new_customer)
+        }
+        ExecuteMsg::Bid() => state.bid(deps, env, info),
+        ExecuteMsg::FundAutoPay() => state.fund_auto_pay(deps, env, info),
+        ExecuteMsg::WithdrawAutoPay() => state.withdraw_auto_pay(deps, env, info),
+        ExecuteMsg::Liquidate() => state.liquidate(deps.as_ref(), env, info),
         ExecuteMsg::TimeAlarm {} => state.on_time_alarm(deps.as_ref(), env, info),
         ExecuteMsg::PriceAlarm() => state.on_price_alarm(deps.as_ref(), env, info),
         ExecuteMsg::DexCallback() => {
@@ -153,6 +189,11 @@ fn process_execute(
             state.on_dex_inner_continue(deps.as_ref(), env)
         }
         ExecuteMsg::Heal() => state.heal(deps.as_ref(), env),
+        ExecuteMsg::RecordHistory(entry) => {
+            access_control::check(&info.sender, &env.contract.address)?;
+            history::append(deps.storage, &env, entry)
+                .map(|()| Response::from(MessageResponse::default(), state))
+        }
     }
 }
 
@@ -168,8 +209,8 @@ fn process_sudo(msg: SudoMsg, state: State, deps: Deps<'_>, env: Env) -> Contrac
         SudoMsg::Timeout { request: _ } => state.on_dex_timeout(deps, env),
         SudoMsg::Error {
             request: _,
-            details: _,
-        } => state.on_dex_error(deps, env),
+            details,
+        } => state.on_dex_error(details, deps, env),
         _ => unreachable!(),
     }
 }
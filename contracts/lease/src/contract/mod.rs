@@ -1,8 +1,12 @@
 use serde::{Deserialize, Serialize};
 
 use dex::{Account, ConnectionParams, DexConnectable};
+use finance::percent::bound::BoundToHundredPercent;
 
-use crate::lease::LeaseDTO;
+use crate::{
+    api::{legacy_max_slippage, LiquidationFee, LiquidationMode, LpnCoin},
+    lease::LeaseDTO,
+};
 
 pub use self::endpoins::{execute, instantiate, migrate, query, reply, sudo};
 
@@ -16,12 +20,20 @@ mod state;
 pub(crate) struct Lease {
     lease: LeaseDTO,
     dex: Account,
-}
-
-impl Lease {
-    fn new(lease: LeaseDTO, dex: Account) -> Self {
-        Self { lease, dex }
-    }
+    #[serde(default = "legacy_max_slippage")]
+    max_slippage: BoundToHundredPercent,
+    /// Leases opened before the liquidation fee introduction are not charged any
+    #[serde(default)]
+    liquidation_fee: LiquidationFee,
+    /// Leases opened before the auction introduction get liquidated on the market
+    #[serde(default)]
+    liquidation_mode: LiquidationMode,
+    /// The LPN put aside by the customer to pay the overdue interest on time alarms
+    ///
+    /// It is kept on the lease account apart from the lease amount. `None` if the customer
+    /// has not opted in to auto-pay.
+    #[serde(default)]
+    auto_pay: Option<LpnCoin>,
 }
 
 impl DexConnectable for Lease {
@@ -1,8 +1,17 @@
 use currency::Currency;
 
+use finance::liability::Zone as LiabilityZone;
+use sdk::cosmwasm_std::Timestamp;
+
 use crate::{
-    api::{opened, paid, StateResponse},
-    lease::{LeaseDTO, State},
+    api::{
+        opened, paid, LiabilityState, LiquidationPrices, Scenario, SimulateResponse, StateResponse,
+        Zone,
+    },
+    lease::{
+        LeaseDTO, LiabilityState as LeaseLiabilityState,
+        LiquidationPrices as LeaseLiquidationPrices, Scenario as LeaseScenario, State,
+    },
 };
 
 impl StateResponse {
@@ -23,7 +32,9 @@ impl StateResponse {
             previous_interest_due: open_lease.previous_interest_due.into(),
             current_margin_due: open_lease.current_margin_due.into(),
             current_interest_due: open_lease.current_interest_due.into(),
+            liability: open_lease.liability.map(Into::into),
             validity: open_lease.validity,
+            close_policy: open_lease.close_policy.into(),
             in_progress,
         }
     }
@@ -35,3 +46,68 @@ impl StateResponse {
         }
     }
 }
+
+impl SimulateResponse {
+    pub fn from_scenarios<Asset>(at: Timestamp, scenarios: Vec<LeaseScenario<Asset>>) -> Self
+    where
+        Asset: Currency,
+    {
+        Self {
+            at,
+            scenarios: scenarios.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<Asset> From<LeaseScenario<Asset>> for Scenario
+where
+    Asset: Currency,
+{
+    fn from(scenario: LeaseScenario<Asset>) -> Self {
+        Self {
+            price_change: scenario.price_change,
+            ltv: scenario.ltv,
+            zone: scenario.zone.map(Into::into),
+            liquidation: scenario.liquidation.map(Into::into),
+        }
+    }
+}
+
+impl<Asset, Lpn> From<LeaseLiabilityState<Asset, Lpn>> for LiabilityState
+where
+    Asset: Currency,
+    Lpn: Currency,
+{
+    fn from(state: LeaseLiabilityState<Asset, Lpn>) -> Self {
+        Self {
+            ltv: state.ltv,
+            zone: state.zone.map(Into::into),
+            liquidation_prices: state.liquidation_prices.into(),
+        }
+    }
+}
+
+impl<Asset, Lpn> From<LeaseLiquidationPrices<Asset, Lpn>> for LiquidationPrices
+where
+    Asset: Currency,
+    Lpn: Currency,
+{
+    fn from(prices: LeaseLiquidationPrices<Asset, Lpn>) -> Self {
+        Self {
+            first_liq_warn: prices.first_liq_warn.into(),
+            second_liq_warn: prices.second_liq_warn.into(),
+            third_liq_warn: prices.third_liq_warn.into(),
+            max: prices.max.into(),
+        }
+    }
+}
+
+impl From<LiabilityZone> for Zone {
+    fn from(zone: LiabilityZone) -> Self {
+        Self {
+            level: zone.low().map_or(0, |level| level.ordinal()),
+            low: zone.low().map(|level| level.ltv()),
+            high: zone.high().ltv(),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use platform::{
 use sdk::cosmwasm_std::{Deps, Env, MessageInfo, QuerierWrapper, Timestamp};
 
 use crate::{
-    api::StateResponse,
+    api::{LpnCoin, StateResponse},
     contract::cmd::Close,
     error::ContractResult,
     event::Type,
@@ -30,6 +30,20 @@ impl Closed {
         with_lease_paid::execute(lease, Close::new(lease_account))
     }
 
+    /// Send the surplus on the lease account to the customer once the lease amount is sold
+    ///
+    /// The sale proceeds are spent on the loan repayment and the change to the customer.
+    pub(super) fn enter_state_sold(
+        &self,
+        lease: LeaseDTO,
+        proceeds: LpnCoin,
+        querier: &QuerierWrapper<'_>,
+    ) -> ContractResult<Batch> {
+        let lease_addr = lease.addr.clone();
+        let lease_account = bank::account(&lease_addr, querier);
+        with_lease_paid::execute(lease, Close::sold(lease_account, proceeds))
+    }
+
     pub(super) fn emit_ok(&self, env: &Env, lease: &LeaseDTO) -> Emitter {
         Emitter::of_type(Type::Closed)
             .emit("id", lease.addr.clone())
@@ -57,9 +57,9 @@ where
         self.handler.on_response(data, deps, env).into()
     }
 
-    fn on_dex_error(self, deps: Deps<'_>, env: Env) -> ContractResult<Response> {
+    fn on_dex_error(self, details: String, deps: Deps<'_>, env: Env) -> ContractResult<Response> {
         self.handler
-            .on_error(deps, env)
+            .on_error(&details, deps, env)
             .map(state_machine::from)
             .map_err(Into::into)
     }
@@ -83,7 +83,8 @@ where
     }
 
     fn heal(self, deps: Deps<'_>, env: Env) -> ContractResult<Response> {
-        self.handler.heal(deps, env).into()
+        super::last_update(deps.storage)
+            .and_then(|since| self.handler.heal(since, deps, env).into())
     }
 
     fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> ContractResult<StateResponse> {
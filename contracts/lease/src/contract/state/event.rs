@@ -2,16 +2,22 @@ use finance::liability::Cause;
 use platform::batch::{Emit, Emitter};
 use sdk::cosmwasm_std::{Addr, Env};
 
-use crate::{api::LeaseCoin, contract::cmd::ReceiptDTO, event::Type};
+use crate::{api::LeaseCoin, contract::cmd::ReceiptDTO, event::Type, lease::PenaltyDTO};
+
+const CAUSE_LIABILITY: &str = "high liability";
+const CAUSE_OVERDUE: &str = "overdue interest";
 
 pub(super) fn emit_liquidation(
     env: &Env,
     lease_addr: &Addr,
     receipt: &ReceiptDTO,
+    penalty: &PenaltyDTO,
     liquidation_cause: &Cause,
     liquidation_amount: &LeaseCoin,
 ) -> Emitter {
-    let emitter = emit_payment_int(Type::Liquidation, env, lease_addr, receipt);
+    let emitter = emit_payment_int(Type::Liquidation, env, lease_addr, receipt)
+        .emit_coin_amount("penalty", penalty.penalty.amount())
+        .emit_coin_amount("keeper-bounty", penalty.keeper_bounty.amount());
     emit_liquidation_info(emitter, liquidation_cause, liquidation_amount)
 }
 
@@ -52,9 +58,9 @@ pub(super) fn emit_liquidation_info(
 fn emit_liquidation_cause(emitter: Emitter, cause: &Cause) -> Emitter {
     match cause {
         Cause::Liability { ltv, healthy_ltv } => emitter
-            .emit("cause", "high liability")
+            .emit("cause", CAUSE_LIABILITY)
             .emit_percent_amount("ltv", *ltv)
             .emit_percent_amount("ltv-healthy", *healthy_ltv),
-        Cause::Overdue() => emitter.emit("cause", "overdue interest"),
+        Cause::Overdue() => emitter.emit("cause", CAUSE_OVERDUE),
     }
 }
@@ -1,10 +1,15 @@
 use enum_dispatch::enum_dispatch;
 
 use platform::state_machine::Response as StateMachineResponse;
-use sdk::cosmwasm_std::{Api, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Timestamp};
+use sdk::cosmwasm_std::{
+    Addr, Api, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Timestamp,
+};
 
 use crate::{
-    api::StateResponse,
+    api::{
+        AutoPayResponse, ClosePolicy, LeaseCoin, LpnCoin, SimulateQuery, SimulateResponse,
+        StateResponse,
+    },
     error::{ContractError, ContractResult},
 };
 
@@ -18,6 +23,19 @@ where
 {
     fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> ContractResult<StateResponse>;
 
+    fn simulate(
+        self,
+        deps: Deps<'_>,
+        _env: Env,
+        _query: SimulateQuery,
+    ) -> ContractResult<SimulateResponse> {
+        err("simulate", deps.api)
+    }
+
+    fn auto_pay(self, deps: Deps<'_>) -> ContractResult<AutoPayResponse> {
+        err("auto pay", deps.api)
+    }
+
     fn reply(self, deps: &mut DepsMut<'_>, _env: Env, _msg: Reply) -> ContractResult<Response> {
         err("reply", deps.api)
     }
@@ -31,6 +49,25 @@ where
         err("repay", deps.api)
     }
 
+    fn add_collateral(
+        self,
+        deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        err("add collateral", deps.api)
+    }
+
+    fn increase_loan(
+        self,
+        deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+        _amount: LpnCoin,
+    ) -> ContractResult<Response> {
+        err("increase loan", deps.api)
+    }
+
     fn close(
         self,
         deps: &mut DepsMut<'_>,
@@ -40,6 +77,67 @@ where
         err("close", deps.api)
     }
 
+    fn close_position(
+        self,
+        deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+        _amount: Option<LeaseCoin>,
+    ) -> ContractResult<Response> {
+        err("close position", deps.api)
+    }
+
+    fn change_close_policy(
+        self,
+        deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+        _policy: ClosePolicy,
+    ) -> ContractResult<Response> {
+        err("change close policy", deps.api)
+    }
+
+    fn transfer_ownership(
+        self,
+        deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+        _new_customer: Addr,
+    ) -> ContractResult<Response> {
+        err("transfer ownership", deps.api)
+    }
+
+    fn bid(
+        self,
+        deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        err("bid", deps.api)
+    }
+
+    fn fund_auto_pay(
+        self,
+        deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        err("fund auto pay", deps.api)
+    }
+
+    fn withdraw_auto_pay(
+        self,
+        deps: &mut DepsMut<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        err("withdraw auto pay", deps.api)
+    }
+
+    fn liquidate(self, deps: Deps<'_>, _env: Env, _info: MessageInfo) -> ContractResult<Response> {
+        err("liquidate", deps.api)
+    }
+
     fn on_time_alarm(
         self,
         deps: Deps<'_>,
@@ -0,0 +1,146 @@
+use currency::native::Nls;
+use finance::liability::Cause;
+use platform::batch::Batch;
+use sdk::{
+    cosmwasm_std::{Addr, Env, Order, Storage},
+    cw_storage_plus::{Bound, Item, Map},
+};
+
+use crate::{
+    api::{
+        ExecuteMsg, HistoryEntry, HistoryQuery, HistoryRecord, HistoryRecordId, HistoryResponse,
+        LeaseCoin, LiquidationCause, Payment,
+    },
+    contract::cmd::ReceiptDTO,
+    error::ContractResult,
+    lease::PenaltyDTO,
+};
+
+const RECORDS: Map<'static, HistoryRecordId, HistoryRecord> = Map::new("history");
+const LAST_RECORD_ID: Item<'static, HistoryRecordId> = Item::new("history_last_id");
+
+/// The maximum number of records kept, the oldest ones get evicted first
+const MAX_RECORDS: HistoryRecordId = 1000;
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+/// Record a repayment in the lease history
+pub(super) fn record_repayment(lease: &Addr, receipt: &ReceiptDTO) -> ContractResult<Batch> {
+    schedule(
+        lease,
+        HistoryEntry::Repayment {
+            payment: payment(receipt),
+        },
+    )
+}
+
+/// Record a position close in the lease history
+pub(super) fn record_position_close(
+    lease: &Addr,
+    receipt: &ReceiptDTO,
+    amount: &LeaseCoin,
+) -> ContractResult<Batch> {
+    schedule(
+        lease,
+        HistoryEntry::PositionClose {
+            payment: payment(receipt),
+            amount: amount.clone(),
+        },
+    )
+}
+
+/// Record a liquidation in the lease history
+pub(super) fn record_liquidation(
+    lease: &Addr,
+    receipt: &ReceiptDTO,
+    penalty: &PenaltyDTO,
+    cause: &Cause,
+    amount: &LeaseCoin,
+) -> ContractResult<Batch> {
+    schedule(
+        lease,
+        HistoryEntry::Liquidation {
+            payment: payment(receipt),
+            penalty: penalty.penalty.clone(),
+            keeper_bounty: penalty.keeper_bounty.clone(),
+            cause: match cause {
+                Cause::Overdue() => LiquidationCause::Overdue,
+                Cause::Liability { ltv, healthy_ltv } => LiquidationCause::Liability {
+                    ltv: *ltv,
+                    healthy_ltv: *healthy_ltv,
+                },
+            },
+            amount: amount.clone(),
+        },
+    )
+}
+
+/// Append an entry to the lease history
+///
+/// The entries get recorded on a message the lease sends to itself
+/// since the lease state machine has no write access to the storage.
+pub(in crate::contract) fn append(
+    storage: &mut dyn Storage,
+    env: &Env,
+    entry: HistoryEntry,
+) -> ContractResult<()> {
+    let id = LAST_RECORD_ID.may_load(storage)?.unwrap_or_default() + 1;
+
+    LAST_RECORD_ID.save(storage, &id)?;
+    RECORDS.save(
+        storage,
+        id,
+        &HistoryRecord {
+            id,
+            at: env.block.time,
+            height: env.block.height,
+            entry,
+        },
+    )?;
+    if id > MAX_RECORDS {
+        RECORDS.remove(storage, id - MAX_RECORDS);
+    }
+    Ok(())
+}
+
+pub(in crate::contract) fn query(
+    storage: &dyn Storage,
+    query: HistoryQuery,
+) -> ContractResult<HistoryResponse> {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+
+    RECORDS
+        .range(
+            storage,
+            query.start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|record| record.map(|(_id, record)| record).map_err(Into::into))
+        .collect::<ContractResult<_>>()
+        .map(|records| HistoryResponse { records })
+}
+
+fn schedule(lease: &Addr, entry: HistoryEntry) -> ContractResult<Batch> {
+    let mut batch = Batch::default();
+    batch.schedule_execute_wasm_no_reply::<_, Nls>(
+        lease,
+        ExecuteMsg::RecordHistory(entry),
+        None,
+    )?;
+    Ok(batch)
+}
+
+fn payment(receipt: &ReceiptDTO) -> Payment {
+    Payment {
+        total: receipt.total.clone(),
+        previous_margin_interest: receipt.previous_margin_paid.clone(),
+        previous_loan_interest: receipt.previous_interest_paid.clone(),
+        current_margin_interest: receipt.current_margin_paid.clone(),
+        current_loan_interest: receipt.current_interest_paid.clone(),
+        principal: receipt.principal_paid.clone(),
+        change: receipt.change.clone(),
+        loan_close: receipt.close,
+    }
+}
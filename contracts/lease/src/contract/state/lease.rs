@@ -1,8 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-use sdk::cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Timestamp};
+use sdk::cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Timestamp};
 
-use crate::{api::StateResponse, error::ContractResult};
+use crate::{
+    api::{
+        AutoPayResponse, ClosePolicy, LeaseCoin, LpnCoin, SimulateQuery, SimulateResponse,
+        StateResponse,
+    },
+    error::ContractResult,
+};
 
 use super::{handler::Handler as LeaseHandler, Contract, Response};
 
@@ -26,6 +32,19 @@ where
         self.handler.state(now, querier)
     }
 
+    fn simulate(
+        self,
+        deps: Deps<'_>,
+        env: Env,
+        query: SimulateQuery,
+    ) -> ContractResult<SimulateResponse> {
+        self.handler.simulate(deps, env, query)
+    }
+
+    fn auto_pay(self, deps: Deps<'_>) -> ContractResult<AutoPayResponse> {
+        self.handler.auto_pay(deps)
+    }
+
     fn reply(self, deps: &mut DepsMut<'_>, env: Env, msg: Reply) -> ContractResult<Response> {
         self.handler.reply(deps, env, msg)
     }
@@ -39,6 +58,25 @@ where
         self.handler.repay(deps, env, info)
     }
 
+    fn add_collateral(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.handler.add_collateral(deps, env, info)
+    }
+
+    fn increase_loan(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+        amount: LpnCoin,
+    ) -> ContractResult<Response> {
+        self.handler.increase_loan(deps, env, info, amount)
+    }
+
     fn close(
         self,
         deps: &mut DepsMut<'_>,
@@ -48,6 +86,59 @@ where
         self.handler.close(deps, env, info)
     }
 
+    fn close_position(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+        amount: Option<LeaseCoin>,
+    ) -> ContractResult<Response> {
+        self.handler.close_position(deps, env, info, amount)
+    }
+
+    fn change_close_policy(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+        policy: ClosePolicy,
+    ) -> ContractResult<Response> {
+        self.handler.change_close_policy(deps, env, info, policy)
+    }
+
+    fn transfer_ownership(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+        new_customer: Addr,
+    ) -> ContractResult<Response> {
+        self.handler
+            .transfer_ownership(deps, env, info, new_customer)
+    }
+
+    fn bid(self, deps: &mut DepsMut<'_>, env: Env, info: MessageInfo) -> ContractResult<Response> {
+        self.handler.bid(deps, env, info)
+    }
+
+    fn fund_auto_pay(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.handler.fund_auto_pay(deps, env, info)
+    }
+
+    fn withdraw_auto_pay(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.handler.withdraw_auto_pay(deps, env, info)
+    }
+
     fn on_time_alarm(
         self,
         deps: Deps<'_>,
@@ -66,6 +157,10 @@ where
         self.handler.on_price_alarm(deps, env, info)
     }
 
+    fn liquidate(self, deps: Deps<'_>, env: Env, info: MessageInfo) -> ContractResult<Response> {
+        self.handler.liquidate(deps, env, info)
+    }
+
     fn heal(self, deps: Deps<'_>, env: Env) -> ContractResult<Response> {
         self.handler.heal(deps, env)
     }
@@ -0,0 +1,47 @@
+use currency::native::Nls;
+use platform::batch::Batch;
+use sdk::cosmwasm_std::{Env, QuerierWrapper};
+
+use crate::{
+    api::{ExecuteLeaserMsg, LeaseCoin, LpnCoin},
+    error::ContractResult,
+};
+
+/// Notify the leaser, that is the lease instantiator, the lease is open
+///
+/// The leaser accounts the lease asset and loan in its statistics.
+pub(super) fn notify_opened(
+    querier: &QuerierWrapper<'_>,
+    env: &Env,
+    amount: LeaseCoin,
+    loan: LpnCoin,
+) -> ContractResult<Batch> {
+    notify(querier, env, ExecuteLeaserMsg::LeaseOpened { amount, loan })
+}
+
+/// Notify the leaser the lease asset or loan has changed
+///
+/// The leaser queries the lease for them to count it against its exposure caps
+/// and in its statistics.
+pub(super) fn notify_updated(querier: &QuerierWrapper<'_>, env: &Env) -> ContractResult<Batch> {
+    notify(querier, env, ExecuteLeaserMsg::LeaseUpdated {})
+}
+
+/// Notify the leaser the lease is closed
+///
+/// The leaser stops counting the lease against its exposure caps.
+pub(super) fn notify_closed(
+    querier: &QuerierWrapper<'_>,
+    env: &Env,
+    liquidated: bool,
+) -> ContractResult<Batch> {
+    notify(querier, env, ExecuteLeaserMsg::LeaseClosed { liquidated })
+}
+
+fn notify(querier: &QuerierWrapper<'_>, env: &Env, msg: ExecuteLeaserMsg) -> ContractResult<Batch> {
+    let leaser = platform::contract::query_creator(querier, &env.contract.address)?;
+
+    let mut batch = Batch::default();
+    batch.schedule_execute_wasm_no_reply::<_, Nls>(&leaser, msg, None)?;
+    Ok(batch)
+}
@@ -6,13 +6,13 @@ use serde::{Deserialize, Serialize};
 use sdk::cosmwasm_std::{Addr, Deps, Env, MessageInfo, QuerierWrapper, Timestamp};
 
 use crate::{
-    api::{LeaseCoin, LpnCoin, StateResponse},
+    api::{LeaseCoin, LiquidationFee, LpnCoin, StateResponse},
     contract::{
         cmd::{FullLiquidation, FullLiquidationResult, ReceiptDTO},
         state::event,
     },
     error::ContractResult,
-    lease::{self, LeaseDTO},
+    lease::{self, LeaseDTO, PenaltyDTO},
 };
 
 use super::{Handler, Response};
@@ -25,13 +25,14 @@ impl Liquidated {
         &self,
         lease: LeaseDTO,
         liquidation_lpn: LpnCoin,
+        penalty: (LiquidationFee, Option<Addr>),
         now: Timestamp,
         profit: ProfitRef,
         querier: &QuerierWrapper<'_>,
     ) -> ContractResult<FullLiquidationResult> {
         lease::with_lease::execute(
             lease,
-            FullLiquidation::new(liquidation_lpn, now, profit),
+            FullLiquidation::new(liquidation_lpn, penalty, now, profit),
             querier,
         )
     }
@@ -41,6 +42,7 @@ impl Liquidated {
         env: &Env,
         lease_addr: &Addr,
         receipt: &ReceiptDTO,
+        penalty: &PenaltyDTO,
         liquidation_cause: &Cause,
         liquidation_amount: &LeaseCoin,
     ) -> Emitter {
@@ -48,6 +50,7 @@ impl Liquidated {
             env,
             lease_addr,
             receipt,
+            penalty,
             liquidation_cause,
             liquidation_amount,
         )
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use platform::{batch::Batch, message::Response as MessageResponse};
 use sdk::{
     cosmwasm_std::{
-        Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Storage, Timestamp,
+        to_vec, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Storage, Timestamp,
     },
     cw_storage_plus::Item,
 };
@@ -26,10 +26,13 @@ mod closed;
 mod dex;
 mod event;
 mod handler;
+pub(super) mod history;
 mod lease;
+mod leaser;
 mod liquidated;
 mod opened;
 mod opening;
+mod ownership;
 mod paid;
 mod resp_delivery;
 #[cfg(feature = "migration")]
@@ -43,8 +46,18 @@ type OpenedActive = LeaseState<opened::active::Active>;
 
 type BuyLpn = DexState<opened::repay::buy_lpn::DexState>;
 
+type BuyCollateral = DexState<opened::collateral::buy_asset::DexState>;
+
+type BuyLoanIncrease = DexState<opened::increase::buy_asset::DexState>;
+
 type SellAsset = DexState<opened::liquidation::sell_asset::DexState>;
 
+type LiquidationTransferIn = DexState<opened::liquidation::transfer_in::DexState>;
+
+type LiquidationAuction = LeaseState<opened::liquidation::auction::Auction>;
+
+type ClosingPosition = DexState<opened::close::sell_asset::DexState>;
+
 type PaidActive = LeaseState<paid::Active>;
 
 type ClosingTransferIn = DexState<paid::transfer_in::DexState>;
@@ -62,14 +75,21 @@ pub(crate) enum State {
     BuyAsset,
     OpenedActive,
     BuyLpn,
+    BuyCollateral,
+    BuyLoanIncrease,
     SellAsset,
+    LiquidationTransferIn,
+    LiquidationAuction,
+    ClosingPosition,
     PaidActive,
     ClosingTransferIn,
     Closed,
     Liquidated,
 }
 
-const STATE_DB_ITEM: Item<'static, State> = Item::new("state");
+const STATE_DB_KEY: &str = "state";
+const STATE_DB_ITEM: Item<'static, State> = Item::new(STATE_DB_KEY);
+const STATE_UPDATE_DB_ITEM: Item<'static, Timestamp> = Item::new("state_update");
 
 pub(super) fn load(storage: &dyn Storage) -> ContractResult<State> {
     STATE_DB_ITEM.load(storage).map_err(Into::into)
@@ -80,8 +100,28 @@ pub(super) fn load_v4(storage: &dyn Storage) -> ContractResult<StateV4> {
     Item::new("state").load(storage).map_err(Into::into)
 }
 
-pub(super) fn save(storage: &mut dyn Storage, next_state: &State) -> ContractResult<()> {
-    STATE_DB_ITEM.save(storage, next_state).map_err(Into::into)
+/// Store the next state and, if it differs from the current one, the time of the change
+pub(super) fn save(
+    storage: &mut dyn Storage,
+    next_state: &State,
+    now: Timestamp,
+) -> ContractResult<()> {
+    let next_state_raw = to_vec(next_state)?;
+    if storage.get(STATE_DB_KEY.as_bytes()).as_ref() != Some(&next_state_raw) {
+        STATE_UPDATE_DB_ITEM.save(storage, &now)?;
+    }
+    storage.set(STATE_DB_KEY.as_bytes(), &next_state_raw);
+    Ok(())
+}
+
+/// The time the current state has not changed from
+///
+/// The leases stored before it was first tracked report the epoch.
+fn last_update(storage: &dyn Storage) -> ContractResult<Timestamp> {
+    STATE_UPDATE_DB_ITEM
+        .may_load(storage)
+        .map(Option::unwrap_or_default)
+        .map_err(Into::into)
 }
 
 pub(super) fn new_lease(
@@ -102,8 +142,9 @@ where
 
 mod impl_from {
     use super::{
-        BuyAsset, BuyLpn, Closed, ClosingTransferIn, Liquidated, OpenedActive, PaidActive,
-        RequestLoan, SellAsset, State,
+        BuyAsset, BuyCollateral, BuyLoanIncrease, BuyLpn, Closed, ClosingPosition,
+        ClosingTransferIn, Liquidated, LiquidationAuction, LiquidationTransferIn, OpenedActive,
+        PaidActive, RequestLoan, SellAsset, State,
     };
 
     impl From<super::opening::request_loan::RequestLoan> for State {
@@ -130,12 +171,42 @@ mod impl_from {
         }
     }
 
+    impl From<super::opened::collateral::buy_asset::DexState> for State {
+        fn from(value: super::opened::collateral::buy_asset::DexState) -> Self {
+            BuyCollateral::new(value).into()
+        }
+    }
+
+    impl From<super::opened::increase::buy_asset::DexState> for State {
+        fn from(value: super::opened::increase::buy_asset::DexState) -> Self {
+            BuyLoanIncrease::new(value).into()
+        }
+    }
+
     impl From<super::opened::liquidation::sell_asset::DexState> for State {
         fn from(value: super::opened::liquidation::sell_asset::DexState) -> Self {
             SellAsset::new(value).into()
         }
     }
 
+    impl From<super::opened::liquidation::transfer_in::DexState> for State {
+        fn from(value: super::opened::liquidation::transfer_in::DexState) -> Self {
+            LiquidationTransferIn::new(value).into()
+        }
+    }
+
+    impl From<super::opened::liquidation::auction::Auction> for State {
+        fn from(value: super::opened::liquidation::auction::Auction) -> Self {
+            LiquidationAuction::new(value).into()
+        }
+    }
+
+    impl From<super::opened::close::sell_asset::DexState> for State {
+        fn from(value: super::opened::close::sell_asset::DexState) -> Self {
+            ClosingPosition::new(value).into()
+        }
+    }
+
     impl From<super::paid::Active> for State {
         fn from(value: super::paid::Active) -> Self {
             PaidActive::new(value).into()
@@ -9,26 +9,45 @@ use platform::{
     batch::{Batch, Emitter},
     message::Response as MessageResponse,
 };
-use sdk::cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Timestamp};
-use timealarms::stub::TimeAlarmsRef;
+use sdk::cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Timestamp};
 
 use crate::{
-    api::{DownpaymentCoin, LpnCoin, StateResponse},
+    api::{
+        AutoPayResponse, ClosePolicy, DownpaymentCoin, LeaseCoin, LiquidationFee, LiquidationMode,
+        LpnCoin, PaymentCoin, SimulateQuery, SimulateResponse, StateResponse,
+    },
     contract::{
         cmd::{
-            FullLiquidationResult, LiquidationDTO, LiquidationStatus, LiquidationStatusCmd,
-            OpenLoanRespResult, PartialLiquidation, PartialLiquidationResult, Repay, RepayResult,
+            AddCollateral, AddCollateralResult, AutoPay, AutoPayResult, ChangeClosePolicy,
+            ChangeClosePolicyResult, FullClose, FullCloseCheck, FullCloseResult,
+            FullLiquidationResult, IncreaseLoan, IncreaseLoanResult, LiquidationDTO,
+            LiquidationStatus, LiquidationStatusCmd, OpenLoanRespResult, PartialLiquidation,
+            PartialLiquidationResult, Repay, RepayResult, Requote, RequoteResult, Simulate,
+        },
+        state::{
+            closed::Closed, event as state_event, history, leaser, liquidated, ownership, paid,
+            Handler, Response,
         },
-        state::{event as state_event, liquidated, paid, Handler, Response},
         Lease,
     },
     error::{ContractError, ContractResult},
-    lease::with_lease,
+    lease::{with_lease, CloseTrigger},
 };
 
 use super::{
-    balance, event,
-    liquidation::sell_asset::{self, DexState as SellAssetState},
+    auto_pay, balance,
+    close::{
+        sell_asset::{self as close_sell_asset, DexState as ClosePositionState},
+        PositionClose,
+    },
+    collateral::buy_asset::{self, DexState as BuyCollateralState},
+    event,
+    increase::buy_asset::{self as increase_buy_asset, DexState as BuyLoanIncreaseState},
+    liquidation::{
+        sell_asset::{self, DexState as SellAssetState},
+        transfer_in::{self as auction_transfer_in, DexState as AuctionTransferInState},
+    },
+    pause,
     repay::buy_lpn::{self, DexState as BuyLpnState},
 };
 
@@ -45,7 +64,7 @@ impl Active {
     pub(in crate::contract::state) fn emit_opened(
         &self,
         env: &Env,
-        downpayment: DownpaymentCoin,
+        downpayment: Vec<DownpaymentCoin>,
         loan: OpenLoanRespResult,
     ) -> Emitter {
         event::emit_lease_opened(env, &self.lease.lease, loan, downpayment)
@@ -67,28 +86,184 @@ impl Active {
             liquidation,
         } = with_lease::execute(
             lease.lease,
-            Repay::new(payment, env.block.time, profit, time_alarms, price_alarms),
+            Repay::new(
+                payment,
+                env.block.time,
+                lease.liquidation_fee.penalty.percent(),
+                profit,
+                time_alarms,
+                price_alarms,
+            ),
             querier,
         )?;
 
+        let notification = leaser::notify_updated(querier, env)?;
+        let history = history::record_repayment(&env.contract.address, &receipt)?;
+
         let repay_response = MessageResponse::messages_with_events(
-            repay_messages,
+            repay_messages.merge(notification).merge(history),
             event::emit_payment(env, &lease_updated, &receipt),
         );
 
-        let lease = Lease::new(lease_updated, lease.dex);
-        match liquidation {
-            LiquidationStatus::NoDebt => Ok(finish_repay(receipt.close, repay_response, lease)),
-            LiquidationStatus::NewAlarms {
-                current_liability,
-                alarms,
-            } => {
-                let response =
-                    alarms_resp(&lease, current_liability, alarms).merge_with(repay_response);
-                Ok(finish_repay(receipt.close, response, lease))
+        let lease = Lease {
+            lease: lease_updated,
+            ..lease
+        };
+        on_liquidation_status(
+            lease,
+            receipt.close,
+            liquidation,
+            repay_response,
+            env,
+            querier,
+        )
+    }
+
+    pub(in crate::contract::state::opened) fn try_top_up_auto_pay(
+        lease: Lease,
+        payment: LpnCoin,
+        env: &Env,
+    ) -> ContractResult<Response> {
+        let reserve = auto_pay::top_up(lease.auto_pay.as_ref(), payment.clone())?;
+        let emitter = event::emit_auto_pay_funded(env, &lease.lease, &payment, &reserve);
+
+        Ok(Response::from(
+            emitter,
+            Self::new(Lease {
+                auto_pay: Some(reserve),
+                ..lease
+            }),
+        ))
+    }
+
+    fn try_auto_pay(
+        lease: Lease,
+        auto_pay: AutoPayResult,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+    ) -> ContractResult<Response> {
+        let AutoPayResult {
+            payment,
+            reserve,
+            low,
+        } = auto_pay;
+
+        let paid = event::emit_auto_pay_paid(env, &lease.lease, &payment, &reserve);
+        let auto_pay_response = if low {
+            MessageResponse::from(paid)
+                .merge_with(event::emit_auto_pay_low(&lease.lease, &reserve).into())
+        } else {
+            paid.into()
+        };
+
+        let lease = Lease {
+            auto_pay: Some(reserve),
+            ..lease
+        };
+        Self::try_repay_lpn(lease, payment, querier, env).map(
+            |Response {
+                 response,
+                 next_state,
+             }| Response::from(auto_pay_response.merge_with(response), next_state),
+        )
+    }
+
+    pub(in crate::contract::state::opened) fn try_add_collateral(
+        lease: Lease,
+        collateral: PaymentCoin,
+        collateral_asset: LeaseCoin,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+    ) -> ContractResult<Response> {
+        let profit = lease.lease.loan.profit().clone();
+        let time_alarms = lease.lease.time_alarms.clone();
+        let price_alarms = lease.lease.oracle.clone();
+        let AddCollateralResult {
+            lease: lease_updated,
+            messages,
+            liquidation,
+        } = with_lease::execute(
+            lease.lease,
+            AddCollateral::new(
+                collateral_asset.clone(),
+                env.block.time,
+                lease.liquidation_fee.penalty.percent(),
+                profit,
+                time_alarms,
+                price_alarms,
+            ),
+            querier,
+        )?;
+
+        let notification = leaser::notify_updated(querier, env)?;
+        let add_response = MessageResponse::messages_with_events(
+            messages.merge(notification),
+            event::emit_collateral_added(env, &lease_updated, &collateral, &collateral_asset),
+        );
+
+        let lease = Lease {
+            lease: lease_updated,
+            ..lease
+        };
+        on_liquidation_status(lease, false, liquidation, add_response, env, querier)
+    }
+
+    pub(in crate::contract::state::opened) fn try_increase_loan(
+        lease: Lease,
+        proceeds: LpnCoin,
+        proceeds_asset: LeaseCoin,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+    ) -> ContractResult<Response> {
+        let profit = lease.lease.loan.profit().clone();
+        let time_alarms = lease.lease.time_alarms.clone();
+        let price_alarms = lease.lease.oracle.clone();
+        let AddCollateralResult {
+            lease: lease_updated,
+            messages,
+            liquidation,
+        } = with_lease::execute(
+            lease.lease,
+            AddCollateral::new(
+                proceeds_asset.clone(),
+                env.block.time,
+                lease.liquidation_fee.penalty.percent(),
+                profit,
+                time_alarms,
+                price_alarms,
+            ),
+            querier,
+        )?;
+
+        let notification = leaser::notify_updated(querier, env)?;
+        let increase_response = MessageResponse::messages_with_events(
+            messages.merge(notification),
+            event::emit_loan_increased(env, &lease_updated, &proceeds, &proceeds_asset),
+        );
+
+        let lease = Lease {
+            lease: lease_updated,
+            ..lease
+        };
+        on_liquidation_status(lease, false, liquidation, increase_response, env, querier)
+    }
+
+    pub(in crate::contract::state::opened) fn try_close_position(
+        lease: Lease,
+        close: PositionClose,
+        close_lpn: LpnCoin,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+    ) -> ContractResult<Response> {
+        let profit = lease.lease.loan.profit().clone();
+        let close_amount = close.amount(&lease.lease).clone();
+
+        match close {
+            PositionClose::Partial(_) => {
+                try_partial_close(lease, close_amount, close_lpn, profit, env, querier)
             }
-            LiquidationStatus::NeedLiquidation(liquidation) => {
-                start_liquidation(lease, liquidation, repay_response, env, querier)
+            PositionClose::Full => {
+                try_full_close(lease, close_amount, close_lpn, profit, env, querier)
             }
         }
     }
@@ -96,6 +271,7 @@ impl Active {
     pub(in crate::contract::state::opened) fn try_liquidate(
         lease: Lease,
         liquidation: LiquidationDTO,
+        keeper: Option<Addr>,
         liquidation_lpn: LpnCoin,
         querier: &QuerierWrapper<'_>,
         env: &Env,
@@ -106,25 +282,30 @@ impl Active {
             LiquidationDTO::Partial {
                 amount: _,
                 cause: _,
-            } => {
-                let time_alarms = lease.lease.time_alarms.clone();
-                try_partial_liquidation(
-                    lease,
-                    liquidation,
-                    liquidation_lpn,
-                    profit,
-                    time_alarms,
-                    env,
-                    querier,
-                )
-            }
-            LiquidationDTO::Full(_) => {
-                try_full_liquidation(lease, liquidation, liquidation_lpn, profit, env, querier)
-            }
+            } => try_partial_liquidation(
+                lease,
+                liquidation,
+                keeper,
+                liquidation_lpn,
+                profit,
+                env,
+                querier,
+            ),
+            LiquidationDTO::Full(_) => try_full_liquidation(
+                lease,
+                liquidation,
+                keeper,
+                liquidation_lpn,
+                profit,
+                env,
+                querier,
+            ),
         }
     }
 
     fn try_repay(self, deps: Deps<'_>, env: &Env, info: MessageInfo) -> ContractResult<Response> {
+        pause::check("repay", |pause| pause.repayments, &deps.querier, env)?;
+
         let payment = bank::may_received::<PaymentGroup, _>(
             info.funds.clone(),
             IntoDTO::<PaymentGroup>::new(),
@@ -146,6 +327,187 @@ impl Active {
         }
     }
 
+    fn try_fund_auto_pay(
+        self,
+        deps: Deps<'_>,
+        env: &Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        access_control::check(&self.lease.lease.customer, &info.sender)?;
+        pause::check(
+            "fund auto pay",
+            |pause| pause.repayments,
+            &deps.querier,
+            env,
+        )?;
+
+        let payment = bank::may_received::<PaymentGroup, _>(
+            info.funds.clone(),
+            IntoDTO::<PaymentGroup>::new(),
+        )
+        .ok_or_else(ContractError::NoPaymentError)??;
+
+        if payment.ticker() == self.lease.lease.loan.lpp().currency() {
+            let payment_lpn = bank::may_received::<Lpns, _>(info.funds, IntoDTO::<Lpns>::new())
+                .ok_or_else(ContractError::NoPaymentError)??;
+
+            Self::try_top_up_auto_pay(self.lease, payment_lpn, env)
+        } else {
+            let start_buy_lpn = buy_lpn::start_auto_pay_funding(self.lease, payment);
+            start_buy_lpn
+                .enter(env.block.time, &deps.querier)
+                .map(|batch| Response::from(batch, BuyLpnState::from(start_buy_lpn)))
+                .map_err(Into::into)
+        }
+    }
+
+    fn try_withdraw_auto_pay(self, env: &Env, info: MessageInfo) -> ContractResult<Response> {
+        access_control::check(&self.lease.lease.customer, &info.sender)?;
+
+        let reserve = self
+            .lease
+            .auto_pay
+            .as_ref()
+            .ok_or_else(ContractError::NoAutoPayReserve)?;
+        let refund = auto_pay::refund(Some(reserve), &self.lease.lease.customer)?;
+        let emitter = event::emit_auto_pay_withdrawn(env, &self.lease.lease, reserve);
+
+        Ok(Response::from(
+            MessageResponse::messages_with_events(refund, emitter),
+            Self::new(Lease {
+                auto_pay: None,
+                ..self.lease
+            }),
+        ))
+    }
+
+    fn try_add_collateral_payment(
+        self,
+        deps: Deps<'_>,
+        env: &Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        let collateral =
+            bank::may_received::<PaymentGroup, _>(info.funds, IntoDTO::<PaymentGroup>::new())
+                .ok_or_else(ContractError::NoPaymentError)??;
+
+        let start_buy_asset = buy_asset::start(self.lease, collateral);
+        start_buy_asset
+            .enter(env.block.time, &deps.querier)
+            .map(|batch| Response::from(batch, BuyCollateralState::from(start_buy_asset)))
+            .map_err(Into::into)
+    }
+
+    fn try_increase_loan_request(
+        self,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+        info: MessageInfo,
+        amount: LpnCoin,
+    ) -> ContractResult<Response> {
+        access_control::check(&self.lease.lease.customer, &info.sender)?;
+        pause::check("increase loan", |pause| pause.borrows, querier, env)?;
+
+        let profit = self.lease.lease.loan.profit().clone();
+        let time_alarms = self.lease.lease.time_alarms.clone();
+        let IncreaseLoanResult {
+            lease: lease_updated,
+            proceeds,
+            messages,
+        } = with_lease::execute(
+            self.lease.lease,
+            IncreaseLoan::new(amount, env.block.time, profit, time_alarms),
+            querier,
+        )?;
+        let notification = leaser::notify_updated(querier, env)?;
+
+        let lease = Lease {
+            lease: lease_updated,
+            ..self.lease
+        };
+        let start_buy_asset = increase_buy_asset::start(lease, proceeds);
+        start_buy_asset
+            .enter(env.block.time, querier)
+            .map(|swap_msg| {
+                MessageResponse::messages_only(messages.merge(notification))
+                    .merge_with(swap_msg.into())
+            })
+            .map(|increase_resp| {
+                Response::from(increase_resp, BuyLoanIncreaseState::from(start_buy_asset))
+            })
+            .map_err(Into::into)
+    }
+
+    fn try_close_position_request(
+        self,
+        deps: Deps<'_>,
+        env: &Env,
+        info: MessageInfo,
+        amount: Option<LeaseCoin>,
+    ) -> ContractResult<Response> {
+        access_control::check(&self.lease.lease.customer, &info.sender)?;
+        pause::check(
+            "close position",
+            |pause| pause.repayments,
+            &deps.querier,
+            env,
+        )?;
+
+        let close = PositionClose::try_new(amount, &self.lease.lease)?;
+        let min_proceeds = match close {
+            PositionClose::Full => Some(full_close_min_proceeds(&self.lease, env, &deps.querier)?),
+            PositionClose::Partial(_) => None,
+        };
+        let start_close = close_sell_asset::start(self.lease, close, min_proceeds);
+        start_close
+            .enter(env.block.time, &deps.querier)
+            .map(|batch| Response::from(batch, ClosePositionState::from(start_close)))
+            .map_err(Into::into)
+    }
+
+    fn try_change_close_policy(
+        self,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+        info: MessageInfo,
+        policy: ClosePolicy,
+    ) -> ContractResult<Response> {
+        access_control::check(&self.lease.lease.customer, &info.sender)?;
+
+        let time_alarms = self.lease.lease.time_alarms.clone();
+        let price_alarms = self.lease.lease.oracle.clone();
+        let profit = self.lease.lease.loan.profit().clone();
+        let ChangeClosePolicyResult {
+            lease: lease_updated,
+            messages,
+            liquidation,
+        } = with_lease::execute(
+            self.lease.lease,
+            ChangeClosePolicy::new(
+                policy,
+                env.block.time,
+                self.lease.liquidation_fee.penalty.percent(),
+                profit,
+                time_alarms,
+                price_alarms,
+            ),
+            querier,
+        )?;
+
+        let lease = Lease {
+            lease: lease_updated,
+            ..self.lease
+        };
+        on_liquidation_status(
+            lease,
+            false,
+            liquidation,
+            MessageResponse::messages_only(messages),
+            env,
+            querier,
+        )
+    }
+
     fn try_on_price_alarm(
         self,
         querier: &QuerierWrapper<'_>,
@@ -180,50 +542,127 @@ impl Active {
     }
 
     fn try_on_alarm(self, querier: &QuerierWrapper<'_>, env: &Env) -> ContractResult<Response> {
-        let liquidation_status = with_lease::execute(
-            self.lease.lease.clone(),
-            LiquidationStatusCmd::new(
-                env.block.time,
-                &self.lease.lease.time_alarms,
-                &self.lease.lease.oracle,
-            ),
-            querier,
-        )?;
+        let pause = pause::query(querier, env)?;
+        // the overdue interest is paid out of the reserve instead of liquidating the lease,
+        // while paused repayments leave the lease to the liquidation check below
+        let auto_pay_paused = match self.may_auto_pay(querier, env)? {
+            Some(auto_pay) if !pause.repayments => {
+                return Self::try_auto_pay(self.lease, auto_pay, querier, env);
+            }
+            may_auto_pay => may_auto_pay.is_some(),
+        };
 
-        match liquidation_status {
+        match self.liquidation_status(querier, env)? {
             LiquidationStatus::NoDebt => Ok(Response::no_msgs(self)),
+            // the reserve is to cover the due interest once the repayments get resumed
+            LiquidationStatus::NewAlarms { .. } if auto_pay_paused => {
+                postpone(self.lease, Default::default(), env)
+            }
             LiquidationStatus::NewAlarms {
                 current_liability,
                 alarms,
-            } => Ok(Response::from(
-                alarms_resp(&self.lease, current_liability, alarms),
-                self,
-            )),
+            } => {
+                let alarms_response = alarms_resp(&self.lease, current_liability, alarms);
+                try_requote(self.lease, alarms_response, env, querier)
+            }
             LiquidationStatus::NeedLiquidation(liquidation) => {
-                let start_liq = MessageResponse::messages_with_events(
-                    Default::default(),
-                    event::emit_liquidation_start(&self.lease.lease, &liquidation),
-                );
-                start_liquidation(self.lease, liquidation, start_liq, env, querier)
+                if pause.liquidations {
+                    return postpone(self.lease, Default::default(), env);
+                }
+
+                start_liquidation_on_trigger(self.lease, liquidation, None, env, querier)
+            }
+            LiquidationStatus::NeedClose(trigger) => {
+                start_close(self.lease, trigger, Default::default(), env, querier)
             }
         }
     }
+
+    fn try_liquidate(
+        self,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        pause::check("liquidate", |pause| pause.liquidations, querier, env)?;
+
+        // the overdue interest is paid out of the reserve instead of liquidating the lease,
+        // unless the repayments are paused
+        let repayments_paused = pause::query(querier, env)?.repayments;
+        if let Some(auto_pay) = self
+            .may_auto_pay(querier, env)?
+            .filter(|_| !repayments_paused)
+        {
+            return Self::try_auto_pay(self.lease, auto_pay, querier, env);
+        }
+
+        match self.liquidation_status(querier, env)? {
+            LiquidationStatus::NeedLiquidation(liquidation) => start_liquidation_on_trigger(
+                self.lease,
+                liquidation,
+                Some(info.sender),
+                env,
+                querier,
+            ),
+            LiquidationStatus::NoDebt
+            | LiquidationStatus::NewAlarms { .. }
+            | LiquidationStatus::NeedClose(_) => Err(ContractError::NoLiquidation()),
+        }
+    }
+
+    fn may_auto_pay(
+        &self,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+    ) -> ContractResult<Option<AutoPayResult>> {
+        self.lease
+            .auto_pay
+            .as_ref()
+            .map(|reserve| {
+                with_lease::execute(
+                    self.lease.lease.clone(),
+                    AutoPay::new(reserve, env.block.time),
+                    querier,
+                )
+            })
+            .transpose()
+            .map(|may_auto_pay| may_auto_pay.filter(|auto_pay| !auto_pay.payment.is_zero()))
+    }
+
+    fn liquidation_status(
+        &self,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+    ) -> ContractResult<LiquidationStatus> {
+        with_lease::execute(
+            self.lease.lease.clone(),
+            LiquidationStatusCmd::new(
+                env.block.time,
+                self.lease.liquidation_fee.penalty.percent(),
+                &self.lease.lease.time_alarms,
+                &self.lease.lease.oracle,
+            ),
+            querier,
+        )
+    }
 }
 
 fn try_partial_liquidation(
     lease: Lease,
     liquidation: LiquidationDTO,
+    keeper: Option<Addr>,
     liquidation_lpn: LpnCoin,
     profit: ProfitRef,
-    time_alarms: TimeAlarmsRef,
     env: &Env,
     querier: &QuerierWrapper<'_>,
 ) -> ContractResult<Response> {
+    let time_alarms = lease.lease.time_alarms.clone();
     let price_alarms = lease.lease.oracle.clone();
     let liquidation_amount = liquidation.amount(&lease.lease).clone();
     let PartialLiquidationResult {
         lease: lease_updated,
         receipt,
+        penalty,
         messages: liquidate_messages,
         liquidation: next_liquidation,
     } = with_lease::execute(
@@ -231,6 +670,7 @@ fn try_partial_liquidation(
         PartialLiquidation::new(
             liquidation_amount.clone(),
             liquidation_lpn,
+            (lease.liquidation_fee, keeper),
             env.block.time,
             profit,
             time_alarms,
@@ -239,37 +679,45 @@ fn try_partial_liquidation(
         querier,
     )?;
 
+    let notification = leaser::notify_updated(querier, env)?;
+    let history = history::record_liquidation(
+        &env.contract.address,
+        &receipt,
+        &penalty,
+        liquidation.cause(),
+        &liquidation_amount,
+    )?;
+
     let liquidate_response = MessageResponse::messages_with_events(
-        liquidate_messages,
+        liquidate_messages.merge(notification).merge(history),
         state_event::emit_liquidation(
             env,
             &lease_updated.addr,
             &receipt,
+            &penalty,
             liquidation.cause(),
             &liquidation_amount,
         ),
     );
 
-    let lease = Lease::new(lease_updated, lease.dex);
-    match next_liquidation {
-        LiquidationStatus::NoDebt => Ok(finish_repay(receipt.close, liquidate_response, lease)),
-        LiquidationStatus::NewAlarms {
-            current_liability,
-            alarms,
-        } => {
-            let response =
-                alarms_resp(&lease, current_liability, alarms).merge_with(liquidate_response);
-            Ok(finish_repay(receipt.close, response, lease))
-        }
-        LiquidationStatus::NeedLiquidation(liquidation) => {
-            start_liquidation(lease, liquidation, liquidate_response, env, querier)
-        }
-    }
+    let lease = Lease {
+        lease: lease_updated,
+        ..lease
+    };
+    on_liquidation_status(
+        lease,
+        receipt.close,
+        next_liquidation,
+        liquidate_response,
+        env,
+        querier,
+    )
 }
 
 fn try_full_liquidation(
     lease: Lease,
     liquidation: LiquidationDTO,
+    keeper: Option<Addr>,
     liquidation_lpn: LpnCoin,
     profit: ProfitRef,
     env: &Env,
@@ -277,24 +725,39 @@ fn try_full_liquidation(
 ) -> ContractResult<Response> {
     let lease_addr = lease.lease.addr.clone();
     let liquidation_amount = liquidation.amount(&lease.lease).clone();
+    let refund = auto_pay::refund(lease.auto_pay.as_ref(), &lease.lease.customer)?;
+    let notification = leaser::notify_closed(querier, env, true)?;
 
     let liquidated = liquidated::Liquidated::default();
     let FullLiquidationResult {
         receipt,
+        penalty,
         messages: liquidate_messages,
     } = liquidated.enter_state(
         lease.lease,
         liquidation_lpn,
+        (lease.liquidation_fee, keeper),
         env.block.time,
         profit,
         querier,
     )?;
+    let history = history::record_liquidation(
+        &env.contract.address,
+        &receipt,
+        &penalty,
+        liquidation.cause(),
+        &liquidation_amount,
+    )?;
     let liquidate_response = MessageResponse::messages_with_events(
-        liquidate_messages,
+        liquidate_messages
+            .merge(refund)
+            .merge(notification)
+            .merge(history),
         liquidated.emit_ok(
             env,
             &lease_addr,
             &receipt,
+            &penalty,
             liquidation.cause(),
             &liquidation_amount,
         ),
@@ -303,21 +766,257 @@ fn try_full_liquidation(
     Ok(Response::from(liquidate_response, liquidated))
 }
 
+fn try_partial_close(
+    lease: Lease,
+    close_amount: LeaseCoin,
+    close_lpn: LpnCoin,
+    profit: ProfitRef,
+    env: &Env,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<Response> {
+    let time_alarms = lease.lease.time_alarms.clone();
+    let price_alarms = lease.lease.oracle.clone();
+    let PartialLiquidationResult {
+        lease: lease_updated,
+        receipt,
+        penalty: _,
+        messages: close_messages,
+        liquidation,
+    } = with_lease::execute(
+        lease.lease,
+        PartialLiquidation::new(
+            close_amount.clone(),
+            close_lpn,
+            (LiquidationFee::default(), None),
+            env.block.time,
+            profit,
+            time_alarms,
+            price_alarms,
+        ),
+        querier,
+    )?;
+
+    let notification = leaser::notify_updated(querier, env)?;
+    let history = history::record_position_close(&env.contract.address, &receipt, &close_amount)?;
+
+    let close_response = MessageResponse::messages_with_events(
+        close_messages.merge(notification).merge(history),
+        event::emit_position_close(env, &lease_updated, &receipt, &close_amount),
+    );
+
+    let lease = Lease {
+        lease: lease_updated,
+        ..lease
+    };
+    on_liquidation_status(
+        lease,
+        receipt.close,
+        liquidation,
+        close_response,
+        env,
+        querier,
+    )
+}
+
+fn try_full_close(
+    lease: Lease,
+    close_amount: LeaseCoin,
+    close_lpn: LpnCoin,
+    profit: ProfitRef,
+    env: &Env,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<Response> {
+    let lease_dto = lease.lease.clone();
+
+    let FullCloseResult {
+        receipt,
+        messages: close_messages,
+    } = with_lease::execute(
+        lease.lease,
+        FullClose::new(close_lpn.clone(), env.block.time, profit),
+        querier,
+    )?;
+    if !receipt.close {
+        // the sale yields at least the total due a day after it has been quoted,
+        // a completion that comes later and falls short must not leave any debt in the LPP
+        return Err(ContractError::InsufficientPositionClose());
+    }
+
+    let close_events = event::emit_position_close(env, &lease_dto, &receipt, &close_amount);
+    let history = history::record_position_close(&env.contract.address, &receipt, &close_amount)?;
+
+    let closed = Closed::default();
+    let sweep = closed.enter_state_sold(lease_dto.clone(), close_lpn, querier)?;
+    let notification = leaser::notify_closed(querier, env, false)?;
+    let close_response = MessageResponse::messages_with_events(
+        close_messages
+            .merge(history)
+            .merge(sweep)
+            .merge(notification),
+        close_events,
+    )
+    .merge_with(closed.emit_ok(env, &lease_dto).into());
+
+    Ok(Response::from(close_response, closed))
+}
+
+fn start_liquidation_on_trigger(
+    lease: Lease,
+    liquidation: LiquidationDTO,
+    keeper: Option<Addr>,
+    env: &Env,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<Response> {
+    let start_liq = MessageResponse::messages_with_events(
+        Default::default(),
+        event::emit_liquidation_start(&lease.lease, &liquidation),
+    );
+    start_unpaused_liquidation(lease, liquidation, keeper, start_liq, env, querier)
+}
+
 fn start_liquidation(
     lease: Lease,
     liquidation: LiquidationDTO,
+    keeper: Option<Addr>,
     curr_request_response: MessageResponse,
     env: &Env,
     querier: &QuerierWrapper<'_>,
 ) -> ContractResult<Response> {
-    let start_liquidaion = sell_asset::start(lease, liquidation);
-    start_liquidaion
+    if pause::query(querier, env)?.liquidations {
+        postpone(lease, curr_request_response, env)
+    } else {
+        start_unpaused_liquidation(
+            lease,
+            liquidation,
+            keeper,
+            curr_request_response,
+            env,
+            querier,
+        )
+    }
+}
+
+fn start_unpaused_liquidation(
+    lease: Lease,
+    liquidation: LiquidationDTO,
+    keeper: Option<Addr>,
+    curr_request_response: MessageResponse,
+    env: &Env,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<Response> {
+    match lease.liquidation_mode {
+        LiquidationMode::Market => {
+            let start_liquidaion = sell_asset::start(lease, liquidation, keeper);
+            start_liquidaion
+                .enter(env.block.time, querier)
+                .map(|swap_msg| curr_request_response.merge_with(swap_msg.into()))
+                .map(|start_liq| Response::from(start_liq, SellAssetState::from(start_liquidaion)))
+                .map_err(Into::into)
+        }
+        LiquidationMode::Auction(spec) => {
+            let start_transfer_in = auction_transfer_in::start(lease, liquidation, keeper, spec);
+            start_transfer_in
+                .enter(env.block.time, querier)
+                .map(|transfer_msg| curr_request_response.merge_with(transfer_msg.into()))
+                .map(|start_liq| {
+                    Response::from(start_liq, AuctionTransferInState::from(start_transfer_in))
+                })
+                .map_err(Into::into)
+        }
+    }
+}
+
+fn start_close(
+    lease: Lease,
+    trigger: CloseTrigger,
+    curr_request_response: MessageResponse,
+    env: &Env,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<Response> {
+    // the same as a close requested by the customer
+    if pause::query(querier, env)?.repayments {
+        return postpone(lease, curr_request_response, env);
+    }
+
+    // a close the proceeds of which would not repay the loan is left to the liquidations
+    let min_proceeds = match full_close_min_proceeds(&lease, env, querier) {
+        Ok(min_proceeds) => min_proceeds,
+        Err(ContractError::InsufficientPositionClose()) => {
+            return postpone(lease, curr_request_response, env)
+        }
+        Err(err) => return Err(err),
+    };
+
+    let close_response = curr_request_response
+        .merge_with(event::emit_close_policy_trigger(&lease.lease, trigger).into());
+    let start_close = close_sell_asset::start(lease, PositionClose::Full, Some(min_proceeds));
+    start_close
         .enter(env.block.time, querier)
-        .map(|swap_msg| curr_request_response.merge_with(swap_msg.into()))
-        .map(|start_liq| Response::from(start_liq, SellAssetState::from(start_liquidaion)))
+        .map(|swap_msg| close_response.merge_with(swap_msg.into()))
+        .map(|start_close_resp| {
+            Response::from(start_close_resp, ClosePositionState::from(start_close))
+        })
+        .map_err(Into::into)
+}
+
+/// The proceeds the sale of the whole lease amount should yield at least to repay the loan
+fn full_close_min_proceeds(
+    lease: &Lease,
+    env: &Env,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<LpnCoin> {
+    with_lease::execute(
+        lease.lease.clone(),
+        FullCloseCheck::new(lease.max_slippage, env.block.time),
+        querier,
+    )
+}
+
+/// Check the lease again later instead of liquidating or closing it while that is paused
+fn postpone(
+    lease: Lease,
+    curr_request_response: MessageResponse,
+    env: &Env,
+) -> ContractResult<Response> {
+    lease
+        .lease
+        .time_alarms
+        .setup_alarm(env.block.time + lease.lease.liability.recalculation_time())
+        .map(|recheck| curr_request_response.merge_with(MessageResponse::messages_only(recheck)))
+        .map(|response| Response::from(response, Active::new(lease)))
         .map_err(Into::into)
 }
 
+/// Re-quote the loan rate if it is floating and a new due period has started
+fn try_requote(
+    lease: Lease,
+    curr_request_response: MessageResponse,
+    env: &Env,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<Response> {
+    let profit = lease.lease.loan.profit().clone();
+    let time_alarms = lease.lease.time_alarms.clone();
+    let requote = with_lease::execute(
+        lease.lease.clone(),
+        Requote::new(env.block.time, profit, time_alarms),
+        querier,
+    )?;
+
+    Ok(match requote {
+        Some(RequoteResult {
+            lease: lease_updated,
+            messages,
+        }) => Response::from(
+            curr_request_response.merge_with(MessageResponse::messages_only(messages)),
+            Active::new(Lease {
+                lease: lease_updated,
+                ..lease
+            }),
+        ),
+        None => Response::from(curr_request_response, Active::new(lease)),
+    })
+}
+
 fn alarms_resp(lease: &Lease, current_liability: Zone, alarms: Batch) -> MessageResponse {
     if let Some(events) = current_liability
         .low()
@@ -329,6 +1028,38 @@ fn alarms_resp(lease: &Lease, current_liability: Zone, alarms: Batch) -> Message
     }
 }
 
+fn on_liquidation_status(
+    lease: Lease,
+    loan_paid: bool,
+    liquidation: LiquidationStatus,
+    curr_request_response: MessageResponse,
+    env: &Env,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<Response> {
+    match liquidation {
+        LiquidationStatus::NoDebt => Ok(finish_repay(loan_paid, curr_request_response, lease)),
+        LiquidationStatus::NewAlarms {
+            current_liability,
+            alarms,
+        } => {
+            let response =
+                alarms_resp(&lease, current_liability, alarms).merge_with(curr_request_response);
+            Ok(finish_repay(loan_paid, response, lease))
+        }
+        LiquidationStatus::NeedLiquidation(liquidation) => start_liquidation(
+            lease,
+            liquidation,
+            None,
+            curr_request_response,
+            env,
+            querier,
+        ),
+        LiquidationStatus::NeedClose(trigger) => {
+            start_close(lease, trigger, curr_request_response, env, querier)
+        }
+    }
+}
+
 fn finish_repay(loan_paid: bool, repay_response: MessageResponse, lease: Lease) -> Response {
     if loan_paid {
         Response::from(repay_response, paid::Active::new(lease))
@@ -342,6 +1073,30 @@ impl Handler for Active {
         super::lease_state(self.lease.lease, None, now, querier)
     }
 
+    fn simulate(
+        self,
+        deps: Deps<'_>,
+        env: Env,
+        query: SimulateQuery,
+    ) -> ContractResult<SimulateResponse> {
+        let at = query.at.map_or(env.block.time, |at| at.max(env.block.time));
+        with_lease::execute(
+            self.lease.lease,
+            Simulate::new(
+                query.price_change,
+                at,
+                self.lease.liquidation_fee.penalty.percent(),
+            ),
+            &deps.querier,
+        )
+    }
+
+    fn auto_pay(self, _deps: Deps<'_>) -> ContractResult<AutoPayResponse> {
+        Ok(AutoPayResponse {
+            reserve: self.lease.auto_pay,
+        })
+    }
+
     fn repay(
         self,
         deps: &mut DepsMut<'_>,
@@ -350,6 +1105,81 @@ impl Handler for Active {
     ) -> ContractResult<Response> {
         self.try_repay(deps.as_ref(), &env, info)
     }
+
+    fn fund_auto_pay(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.try_fund_auto_pay(deps.as_ref(), &env, info)
+    }
+
+    fn withdraw_auto_pay(
+        self,
+        _deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.try_withdraw_auto_pay(&env, info)
+    }
+
+    fn add_collateral(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.try_add_collateral_payment(deps.as_ref(), &env, info)
+    }
+
+    fn increase_loan(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+        amount: LpnCoin,
+    ) -> ContractResult<Response> {
+        self.try_increase_loan_request(&deps.querier, &env, info, amount)
+    }
+
+    fn close_position(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+        amount: Option<LeaseCoin>,
+    ) -> ContractResult<Response> {
+        self.try_close_position_request(deps.as_ref(), &env, info, amount)
+    }
+
+    fn change_close_policy(
+        self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+        policy: ClosePolicy,
+    ) -> ContractResult<Response> {
+        self.try_change_close_policy(&deps.querier, &env, info, policy)
+    }
+
+    fn transfer_ownership(
+        mut self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+        new_customer: Addr,
+    ) -> ContractResult<Response> {
+        ownership::transfer(
+            &mut self.lease.lease,
+            new_customer,
+            deps.as_ref(),
+            &env,
+            &info,
+        )
+        .map(|response| Response::from(response, self))
+    }
+
     fn on_time_alarm(
         self,
         deps: Deps<'_>,
@@ -366,6 +1196,9 @@ impl Handler for Active {
     ) -> ContractResult<Response> {
         self.try_on_price_alarm(&deps.querier, &env, info)
     }
+    fn liquidate(self, deps: Deps<'_>, env: Env, info: MessageInfo) -> ContractResult<Response> {
+        self.try_liquidate(&deps.querier, &env, info)
+    }
     fn heal(self, deps: Deps<'_>, env: Env) -> ContractResult<Response> {
         let lease_addr = self.lease.lease.addr.clone();
         balance::balance(
@@ -373,6 +1206,7 @@ impl Handler for Active {
             self.lease.lease.loan.lpp().currency(),
             &deps.querier,
         )
+        .and_then(|balance| auto_pay::exclude(balance, self.lease.auto_pay.as_ref()))
         .and_then(|balance| {
             if balance.is_zero() {
                 Err(ContractError::InconsistencyNotDetected())
@@ -0,0 +1,89 @@
+use currency::Currency;
+use finance::coin::{Coin, WithCoin, WithCoinResult};
+use platform::{
+    bank::{FixedAddressSender, LazySenderStub},
+    batch::Batch,
+};
+use sdk::cosmwasm_std::Addr;
+
+use crate::{
+    api::LpnCoin,
+    error::{ContractError, ContractResult},
+};
+
+/// Add a payment to the auto-pay reserve, if any
+pub(super) fn top_up(reserve: Option<&LpnCoin>, payment: LpnCoin) -> ContractResult<LpnCoin> {
+    match reserve {
+        Some(reserve) => payment.with_coin(TopUp { reserve }),
+        None => Ok(payment),
+    }
+}
+
+/// Exclude the auto-pay reserve, if any, from an LPN balance of the lease account
+pub(super) fn exclude(balance: LpnCoin, reserve: Option<&LpnCoin>) -> ContractResult<LpnCoin> {
+    match reserve {
+        Some(reserve) => balance.with_coin(Exclude { reserve }),
+        None => Ok(balance),
+    }
+}
+
+/// Send the auto-pay reserve, if any, back to the customer
+pub(super) fn refund(reserve: Option<&LpnCoin>, customer: &Addr) -> ContractResult<Batch> {
+    reserve.map_or(Ok(Batch::default()), |reserve| {
+        reserve.with_coin(Refund { customer })
+    })
+}
+
+struct TopUp<'a> {
+    reserve: &'a LpnCoin,
+}
+
+impl<'a> WithCoin for TopUp<'a> {
+    type Output = LpnCoin;
+    type Error = ContractError;
+
+    fn on<C>(&self, payment: Coin<C>) -> WithCoinResult<Self>
+    where
+        C: Currency,
+    {
+        Coin::<C>::try_from(self.reserve)
+            .map(|reserve| (reserve + payment).into())
+            .map_err(Into::into)
+    }
+}
+
+struct Exclude<'a> {
+    reserve: &'a LpnCoin,
+}
+
+impl<'a> WithCoin for Exclude<'a> {
+    type Output = LpnCoin;
+    type Error = ContractError;
+
+    fn on<C>(&self, balance: Coin<C>) -> WithCoinResult<Self>
+    where
+        C: Currency,
+    {
+        Coin::<C>::try_from(self.reserve)
+            .map(|reserve| balance.saturating_sub(reserve).into())
+            .map_err(Into::into)
+    }
+}
+
+struct Refund<'a> {
+    customer: &'a Addr,
+}
+
+impl<'a> WithCoin for Refund<'a> {
+    type Output = Batch;
+    type Error = ContractError;
+
+    fn on<C>(&self, reserve: Coin<C>) -> WithCoinResult<Self>
+    where
+        C: Currency,
+    {
+        let mut customer = LazySenderStub::new(self.customer.clone());
+        customer.send(reserve);
+        Ok(customer.into())
+    }
+}
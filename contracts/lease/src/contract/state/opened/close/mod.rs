@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use sdk::cosmwasm_std::{QuerierWrapper, Timestamp};
+
+use crate::{
+    api::{
+        opened::{OngoingTrx, PositionCloseTrx},
+        LeaseCoin, StateResponse,
+    },
+    error::{ContractError, ContractResult},
+    lease::LeaseDTO,
+};
+
+pub mod sell_asset;
+
+#[derive(Serialize, Deserialize)]
+pub(crate) enum PositionClose {
+    Partial(LeaseCoin),
+    Full,
+}
+
+impl PositionClose {
+    pub(in crate::contract::state::opened) fn try_new(
+        amount: Option<LeaseCoin>,
+        lease: &LeaseDTO,
+    ) -> ContractResult<Self> {
+        match amount {
+            None => Ok(Self::Full),
+            Some(amount) => {
+                if amount.ticker() != lease.amount.ticker()
+                    || amount.is_zero()
+                    || amount.amount() > lease.amount.amount()
+                {
+                    Err(ContractError::InvalidPositionCloseAmount(amount))
+                } else if amount.amount() == lease.amount.amount() {
+                    Ok(Self::Full)
+                } else {
+                    Ok(Self::Partial(amount))
+                }
+            }
+        }
+    }
+
+    pub(crate) fn amount<'a>(&'a self, lease: &'a LeaseDTO) -> &LeaseCoin {
+        match self {
+            Self::Partial(amount) => amount,
+            Self::Full => &lease.amount,
+        }
+    }
+}
+
+fn query(
+    lease: LeaseDTO,
+    close: PositionClose,
+    in_progress: PositionCloseTrx,
+    now: Timestamp,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<StateResponse> {
+    let in_progress = OngoingTrx::ClosePosition {
+        close: close.amount(&lease).clone(),
+        in_progress,
+    };
+
+    super::lease_state(lease, Some(in_progress), now, querier)
+}
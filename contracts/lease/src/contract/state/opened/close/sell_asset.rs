@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+
+use currency::{lpn::Lpns, Symbol};
+use dex::{
+    Account, CoinVisitor, ContractInSwap, IterNext, IterState, StartRemoteLocalState, SwapState,
+    SwapTask, TransferInFinishState, TransferInInitState, TransferOutState,
+};
+use finance::{coin::CoinDTO, percent::bound::BoundToHundredPercent};
+use oracle::stub::OracleRef;
+use sdk::cosmwasm_std::{Env, QuerierWrapper, Timestamp};
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    api::{self, opened::PositionCloseTrx, LpnCoin},
+    contract::{
+        state::{
+            opened::active::Active,
+            resp_delivery::{ForwardToDexEntry, ForwardToDexEntryContinue},
+            SwapResult,
+        },
+        Lease,
+    },
+    error::ContractResult,
+    event::Type,
+};
+
+use super::PositionClose;
+
+pub(in crate::contract::state) type StartState =
+    StartRemoteLocalState<SellAsset, ForwardToDexEntry, ForwardToDexEntryContinue>;
+pub(crate) type DexState =
+    dex::StateLocalOut<SellAsset, ForwardToDexEntry, ForwardToDexEntryContinue>;
+
+pub(in crate::contract::state) fn start(
+    lease: Lease,
+    close: PositionClose,
+    min_proceeds: Option<LpnCoin>,
+) -> StartState {
+    dex::start_remote_local(SellAsset::new(lease, close, min_proceeds))
+}
+
+type SellAssetStateResponse = <SellAsset as SwapTask>::StateResponse;
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct SellAsset {
+    lease: Lease,
+    close: PositionClose,
+    /// The proceeds a full close should yield at least to repay the loan
+    #[serde(default)]
+    min_proceeds: Option<LpnCoin>,
+}
+
+impl SellAsset {
+    fn new(lease: Lease, close: PositionClose, min_proceeds: Option<LpnCoin>) -> Self {
+        Self {
+            lease,
+            close,
+            min_proceeds,
+        }
+    }
+}
+
+impl SwapTask for SellAsset {
+    type OutG = Lpns;
+    type Label = Type;
+    type StateResponse = ContractResult<api::StateResponse>;
+    type Result = SwapResult;
+
+    fn label(&self) -> Self::Label {
+        Type::ClosePositionSwap
+    }
+
+    fn dex_account(&self) -> &Account {
+        &self.lease.dex
+    }
+
+    fn oracle(&self) -> &OracleRef {
+        &self.lease.lease.oracle
+    }
+
+    fn time_alarm(&self) -> &TimeAlarmsRef {
+        &self.lease.lease.time_alarms
+    }
+
+    fn out_currency(&self) -> Symbol<'_> {
+        self.lease.lease.loan.lpp().currency()
+    }
+
+    fn max_slippage(&self) -> BoundToHundredPercent {
+        self.lease.max_slippage
+    }
+
+    fn out_reserve(&self) -> Option<CoinDTO<Self::OutG>> {
+        self.lease.auto_pay.clone()
+    }
+
+    fn min_out(&self) -> Option<CoinDTO<Self::OutG>> {
+        self.min_proceeds.clone()
+    }
+
+    fn on_coins<Visitor>(&self, visitor: &mut Visitor) -> Result<IterState, Visitor::Error>
+    where
+        Visitor: CoinVisitor<Result = IterNext>,
+    {
+        dex::on_coin(self.close.amount(&self.lease.lease), visitor)
+    }
+
+    fn finish(
+        self,
+        amount_out: CoinDTO<Self::OutG>,
+        env: &Env,
+        querier: &QuerierWrapper<'_>,
+    ) -> Self::Result {
+        Active::try_close_position(self.lease, self.close, amount_out, querier, env)
+    }
+}
+
+impl ContractInSwap<TransferOutState, SellAssetStateResponse> for SellAsset {
+    fn state(self, _now: Timestamp, _querier: &QuerierWrapper<'_>) -> SellAssetStateResponse {
+        // it's due to reusing the same enum dex::State
+        // have to define a tailored enum dex::State that starts from SwapExactIn
+        unreachable!(
+            "The sell lease asset on position close task never goes through a 'TransferOut' state!"
+        )
+    }
+}
+
+impl ContractInSwap<SwapState, SellAssetStateResponse> for SellAsset {
+    fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> SellAssetStateResponse {
+        super::query(
+            self.lease.lease,
+            self.close,
+            PositionCloseTrx::Swap,
+            now,
+            querier,
+        )
+    }
+}
+
+impl ContractInSwap<TransferInInitState, SellAssetStateResponse> for SellAsset {
+    fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> SellAssetStateResponse {
+        super::query(
+            self.lease.lease,
+            self.close,
+            PositionCloseTrx::TransferInInit,
+            now,
+            querier,
+        )
+    }
+}
+
+impl ContractInSwap<TransferInFinishState, SellAssetStateResponse> for SellAsset {
+    fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> SellAssetStateResponse {
+        super::query(
+            self.lease.lease,
+            self.close,
+            PositionCloseTrx::TransferInFinish,
+            now,
+            querier,
+        )
+    }
+}
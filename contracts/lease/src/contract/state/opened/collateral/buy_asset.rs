@@ -0,0 +1,164 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+
+use currency::{lease::LeaseGroup, Symbol};
+use dex::{
+    Account, CoinVisitor, ConnectionParams, Contract as DexContract, ContractInSwap,
+    DexConnectable, DexResult, IcaConnectee, IterNext, IterState, SwapState, SwapTask, TimeAlarm,
+    TransferOut, TransferOutState,
+};
+use finance::{coin::CoinDTO, percent::bound::BoundToHundredPercent};
+use oracle::stub::OracleRef;
+use platform::batch::Batch;
+use sdk::cosmwasm_std::{Env, QuerierWrapper, Timestamp};
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    api::{self, opened::CollateralTrx, PaymentCoin},
+    contract::{
+        state::{
+            opened::{active::Active, collateral},
+            resp_delivery::{ForwardToDexEntry, ForwardToDexEntryContinue},
+            SwapResult,
+        },
+        Lease,
+    },
+    error::ContractResult,
+    event::Type,
+};
+
+pub(in crate::contract::state) type StartState = TransferOut<BuyAsset, DexState>;
+pub(crate) type DexState =
+    dex::StateRemoteOut<IcaOpened, BuyAsset, ForwardToDexEntry, ForwardToDexEntryContinue>;
+
+pub(in crate::contract::state) fn start(lease: Lease, collateral: PaymentCoin) -> StartState {
+    TransferOut::new(BuyAsset::new(lease, collateral))
+}
+
+type BuyAssetStateResponse = <BuyAsset as SwapTask>::StateResponse;
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct BuyAsset {
+    lease: Lease,
+    collateral: PaymentCoin,
+}
+
+impl BuyAsset {
+    fn new(lease: Lease, collateral: PaymentCoin) -> Self {
+        Self { lease, collateral }
+    }
+}
+
+impl SwapTask for BuyAsset {
+    type OutG = LeaseGroup;
+    type Label = Type;
+    type StateResponse = ContractResult<api::StateResponse>;
+    type Result = SwapResult;
+
+    fn label(&self) -> Self::Label {
+        Type::CollateralSwap
+    }
+
+    fn dex_account(&self) -> &Account {
+        &self.lease.dex
+    }
+
+    fn oracle(&self) -> &OracleRef {
+        &self.lease.lease.oracle
+    }
+
+    fn time_alarm(&self) -> &TimeAlarmsRef {
+        &self.lease.lease.time_alarms
+    }
+
+    fn out_currency(&self) -> Symbol<'_> {
+        self.lease.lease.amount.ticker()
+    }
+
+    fn max_slippage(&self) -> BoundToHundredPercent {
+        self.lease.max_slippage
+    }
+
+    fn on_coins<Visitor>(&self, visitor: &mut Visitor) -> Result<IterState, Visitor::Error>
+    where
+        Visitor: CoinVisitor<Result = IterNext>,
+    {
+        dex::on_coin(&self.collateral, visitor)
+    }
+
+    fn finish(
+        self,
+        amount_out: CoinDTO<Self::OutG>,
+        env: &Env,
+        querier: &QuerierWrapper<'_>,
+    ) -> Self::Result {
+        Active::try_add_collateral(self.lease, self.collateral, amount_out, querier, env)
+    }
+}
+
+impl ContractInSwap<TransferOutState, BuyAssetStateResponse> for BuyAsset {
+    fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> BuyAssetStateResponse {
+        collateral::query(
+            self.lease.lease,
+            self.collateral,
+            CollateralTrx::TransferOut,
+            now,
+            querier,
+        )
+    }
+}
+
+impl ContractInSwap<SwapState, BuyAssetStateResponse> for BuyAsset {
+    fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> BuyAssetStateResponse {
+        collateral::query(
+            self.lease.lease,
+            self.collateral,
+            CollateralTrx::Swap,
+            now,
+            querier,
+        )
+    }
+}
+
+/// The 'OpenIca' stage of the dex flow
+///
+/// The lease has already got an ICA on the DEX so the flow starts with a 'TransferOut'.
+/// The type is uninhabited which guarantees statically that the flow never gets here.
+#[derive(Serialize, Deserialize)]
+pub(crate) enum IcaOpened {}
+
+impl IcaConnectee for IcaOpened {
+    type State = DexState;
+    type NextState = TransferOut<BuyAsset, Self::State>;
+
+    fn connected(self, _dex_account: Account) -> Self::NextState {
+        match self {}
+    }
+}
+
+impl DexConnectable for IcaOpened {
+    fn dex(&self) -> &ConnectionParams {
+        match *self {}
+    }
+}
+
+impl DexContract for IcaOpened {
+    type StateResponse = ContractResult<api::StateResponse>;
+
+    fn state(self, _now: Timestamp, _querier: &QuerierWrapper<'_>) -> Self::StateResponse {
+        match self {}
+    }
+}
+
+impl Display for IcaOpened {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> FmtResult {
+        match *self {}
+    }
+}
+
+impl TimeAlarm for IcaOpened {
+    fn setup_alarm(&self, _forr: Timestamp) -> DexResult<Batch> {
+        match *self {}
+    }
+}
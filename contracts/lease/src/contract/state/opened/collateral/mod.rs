@@ -0,0 +1,27 @@
+use sdk::cosmwasm_std::{QuerierWrapper, Timestamp};
+
+use crate::{
+    api::{
+        opened::{CollateralTrx, OngoingTrx},
+        PaymentCoin, StateResponse,
+    },
+    error::ContractResult,
+    lease::LeaseDTO,
+};
+
+pub mod buy_asset;
+
+fn query(
+    lease: LeaseDTO,
+    collateral: PaymentCoin,
+    in_progress: CollateralTrx,
+    now: Timestamp,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<StateResponse> {
+    let in_progress = OngoingTrx::Collateral {
+        collateral,
+        in_progress,
+    };
+
+    super::lease_state(lease, Some(in_progress), now, querier)
+}
@@ -1,24 +1,24 @@
 use finance::liability::Level;
 use platform::batch::{Emit, Emitter};
-use sdk::cosmwasm_std::Env;
+use sdk::cosmwasm_std::{Addr, Env};
 
 use crate::{
-    api::DownpaymentCoin,
+    api::{DownpaymentCoin, LeaseCoin, LpnCoin, PaymentCoin},
     contract::{
         cmd::{LiquidationDTO, OpenLoanRespResult, ReceiptDTO},
         state::event as state_event,
     },
     event::Type,
-    lease::LeaseDTO,
+    lease::{CloseTrigger, LeaseDTO},
 };
 
 pub(super) fn emit_lease_opened(
     env: &Env,
     lease: &LeaseDTO,
     loan: OpenLoanRespResult,
-    downpayment: DownpaymentCoin,
+    downpayment: Vec<DownpaymentCoin>,
 ) -> Emitter {
-    Emitter::of_type(Type::OpenedActive)
+    let emitter = Emitter::of_type(Type::OpenedActive)
         .emit_tx_info(env)
         .emit("id", &lease.addr)
         .emit("customer", lease.customer.clone())
@@ -28,14 +28,86 @@ pub(super) fn emit_lease_opened(
         )
         .emit("currency", lease.amount.ticker())
         .emit("loan-pool-id", lease.loan.lpp().addr())
-        .emit_coin_dto("loan", &loan.principal)
-        .emit_coin_dto("downpayment", &downpayment)
+        .emit_coin_dto("loan", &loan.principal);
+    downpayment.iter().fold(emitter, |emitter, coin| {
+        emitter.emit_coin_dto("downpayment", coin)
+    })
 }
 
 pub(super) fn emit_payment(env: &Env, lease: &LeaseDTO, receipt: &ReceiptDTO) -> Emitter {
     state_event::emit_payment_int(Type::PaidActive, env, &lease.addr, receipt)
 }
 
+pub(super) fn emit_position_close(
+    env: &Env,
+    lease: &LeaseDTO,
+    receipt: &ReceiptDTO,
+    close_amount: &LeaseCoin,
+) -> Emitter {
+    state_event::emit_payment_int(Type::ClosePosition, env, &lease.addr, receipt)
+        .emit_coin_dto("amount", close_amount)
+}
+
+pub(super) fn emit_collateral_added(
+    env: &Env,
+    lease: &LeaseDTO,
+    collateral: &PaymentCoin,
+    collateral_asset: &LeaseCoin,
+) -> Emitter {
+    emit_lease(Emitter::of_type(Type::CollateralAdded), lease)
+        .emit_tx_info(env)
+        .emit_coin_dto("collateral", collateral)
+        .emit_coin_dto("collateral-asset", collateral_asset)
+        .emit_coin_dto("amount", &lease.amount)
+}
+
+pub(super) fn emit_loan_increased(
+    env: &Env,
+    lease: &LeaseDTO,
+    proceeds: &LpnCoin,
+    proceeds_asset: &LeaseCoin,
+) -> Emitter {
+    emit_lease(Emitter::of_type(Type::LoanIncreased), lease)
+        .emit_tx_info(env)
+        .emit_coin_dto("proceeds", proceeds)
+        .emit_coin_dto("proceeds-asset", proceeds_asset)
+        .emit_coin_dto("amount", &lease.amount)
+}
+
+pub(super) fn emit_auto_pay_funded(
+    env: &Env,
+    lease: &LeaseDTO,
+    payment: &LpnCoin,
+    reserve: &LpnCoin,
+) -> Emitter {
+    emit_lease(Emitter::of_type(Type::AutoPayFunded), lease)
+        .emit_tx_info(env)
+        .emit_coin_dto("payment", payment)
+        .emit_coin_dto("reserve", reserve)
+}
+
+pub(super) fn emit_auto_pay_paid(
+    env: &Env,
+    lease: &LeaseDTO,
+    payment: &LpnCoin,
+    reserve: &LpnCoin,
+) -> Emitter {
+    emit_lease(Emitter::of_type(Type::AutoPayPaid), lease)
+        .emit_tx_info(env)
+        .emit_coin_dto("payment", payment)
+        .emit_coin_dto("reserve", reserve)
+}
+
+pub(super) fn emit_auto_pay_low(lease: &LeaseDTO, reserve: &LpnCoin) -> Emitter {
+    emit_lease(Emitter::of_type(Type::AutoPayLow), lease).emit_coin_dto("reserve", reserve)
+}
+
+pub(super) fn emit_auto_pay_withdrawn(env: &Env, lease: &LeaseDTO, reserve: &LpnCoin) -> Emitter {
+    emit_lease(Emitter::of_type(Type::AutoPayWithdrawn), lease)
+        .emit_tx_info(env)
+        .emit_coin_dto("reserve", reserve)
+}
+
 pub(super) fn emit_liquidation_warning(lease: &LeaseDTO, level: &Level) -> Emitter {
     emit_lease(Emitter::of_type(Type::LiquidationWarning), lease)
         .emit_percent_amount("ltv", level.ltv())
@@ -47,6 +119,48 @@ pub(super) fn emit_liquidation_start(lease: &LeaseDTO, liquidation: &Liquidation
     state_event::emit_liquidation_info(emitter, liquidation.cause(), liquidation.amount(lease))
 }
 
+pub(super) fn emit_liquidation_auction(
+    env: &Env,
+    lease: &LeaseDTO,
+    amount: &LeaseCoin,
+    ask: &LpnCoin,
+) -> Emitter {
+    emit_lease(Emitter::of_type(Type::LiquidationAuction), lease)
+        .emit_tx_info(env)
+        .emit_coin_dto("amount", amount)
+        .emit_coin_dto("ask", ask)
+}
+
+pub(super) fn emit_liquidation_bid(
+    env: &Env,
+    lease: &LeaseDTO,
+    buyer: &Addr,
+    ask: &LpnCoin,
+) -> Emitter {
+    emit_lease(Emitter::of_type(Type::LiquidationBid), lease)
+        .emit_tx_info(env)
+        .emit("buyer", buyer)
+        .emit_coin_dto("ask", ask)
+}
+
+pub(super) fn emit_liquidation_auction_end(
+    env: &Env,
+    lease: &LeaseDTO,
+    amount: &LeaseCoin,
+    floor: &LpnCoin,
+) -> Emitter {
+    emit_lease(Emitter::of_type(Type::LiquidationAuctionEnd), lease)
+        .emit_tx_info(env)
+        .emit_coin_dto("amount", amount)
+        .emit_coin_dto("floor", floor)
+}
+
+pub(super) fn emit_close_policy_trigger(lease: &LeaseDTO, trigger: CloseTrigger) -> Emitter {
+    emit_lease(Emitter::of_type(Type::ClosePolicyTrigger), lease)
+        .emit("cause", trigger.as_str())
+        .emit_coin_dto("amount", &lease.amount)
+}
+
 fn emit_lease(emitter: Emitter, lease: &LeaseDTO) -> Emitter {
     emitter
         .emit("customer", lease.customer.clone())
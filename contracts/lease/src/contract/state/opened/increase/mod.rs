@@ -0,0 +1,27 @@
+use sdk::cosmwasm_std::{QuerierWrapper, Timestamp};
+
+use crate::{
+    api::{
+        opened::{LoanIncreaseTrx, OngoingTrx},
+        LpnCoin, StateResponse,
+    },
+    error::ContractResult,
+    lease::LeaseDTO,
+};
+
+pub mod buy_asset;
+
+fn query(
+    lease: LeaseDTO,
+    proceeds: LpnCoin,
+    in_progress: LoanIncreaseTrx,
+    now: Timestamp,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<StateResponse> {
+    let in_progress = OngoingTrx::LoanIncrease {
+        proceeds,
+        in_progress,
+    };
+
+    super::lease_state(lease, Some(in_progress), now, querier)
+}
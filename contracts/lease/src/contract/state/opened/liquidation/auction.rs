@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+
+use currency::lpn::Lpns;
+use dex::Enterable;
+use finance::{coin::IntoDTO, percent::Units};
+use platform::{bank, batch::Batch, message::Response as MessageResponse};
+use sdk::cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Timestamp};
+
+use crate::{
+    api::{opened::LiquidateTrx, AuctionSpec, LpnCoin, StateResponse},
+    contract::{
+        cmd::{AuctionAsks, Bid, DecayedAsk, LiquidationDTO},
+        state::{
+            self,
+            opened::{active::Active, event},
+            Handler, Response,
+        },
+        Lease,
+    },
+    error::{ContractError, ContractResult},
+    lease::with_lease,
+};
+
+use super::sell_asset::{self, DexState as SellAssetState};
+
+/// The asset under liquidation offered at a declining ask to anyone paying in LPN
+///
+/// The ask decays on every time alarm the auction schedules. Once it reaches its floor,
+/// the asset is sold on the DEX as a market liquidation does.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Auction {
+    lease: Lease,
+    liquidation: LiquidationDTO,
+    keeper: Option<Addr>,
+    spec: AuctionSpec,
+    start_ask: LpnCoin,
+    floor_ask: LpnCoin,
+    periods: Units,
+}
+
+impl Auction {
+    pub(super) fn start(
+        lease: Lease,
+        liquidation: LiquidationDTO,
+        keeper: Option<Addr>,
+        spec: AuctionSpec,
+        env: &Env,
+        querier: &QuerierWrapper<'_>,
+    ) -> ContractResult<Response> {
+        let (start_ask, floor_ask) = with_lease::execute(
+            lease.lease.clone(),
+            AuctionAsks::new(
+                liquidation.amount(&lease.lease),
+                spec.premium,
+                spec.floor.percent(),
+            ),
+            querier,
+        )?;
+
+        let auction = Self {
+            lease,
+            liquidation,
+            keeper,
+            spec,
+            start_ask,
+            floor_ask,
+            periods: 0,
+        };
+        auction.schedule_decay(env.block.time).map(|alarm| {
+            let emitter = event::emit_liquidation_auction(
+                env,
+                &auction.lease.lease,
+                auction.liquidation.amount(&auction.lease.lease),
+                &auction.start_ask,
+            );
+            Response::from(
+                MessageResponse::messages_with_events(alarm, emitter),
+                auction,
+            )
+        })
+    }
+
+    fn ask(&self) -> ContractResult<LpnCoin> {
+        self.start_ask.with_coin(DecayedAsk::new(
+            self.spec.decay.percent(),
+            self.periods,
+            &self.floor_ask,
+        ))
+    }
+
+    /// An ask that does not decay is at its floor past the first period
+    fn at_floor(&self) -> ContractResult<bool> {
+        if self.spec.decay.percent().is_zero() {
+            Ok(self.periods > 0)
+        } else {
+            self.ask().map(|ask| ask == self.floor_ask)
+        }
+    }
+
+    fn schedule_decay(&self, now: Timestamp) -> ContractResult<Batch> {
+        self.lease
+            .lease
+            .time_alarms
+            .setup_alarm(now + self.spec.period)
+            .map_err(Into::into)
+    }
+
+    fn try_bid(self, deps: Deps<'_>, env: &Env, info: MessageInfo) -> ContractResult<Response> {
+        let payment = bank::may_received::<Lpns, _>(info.funds, IntoDTO::<Lpns>::new())
+            .ok_or_else(ContractError::NoPaymentError)??;
+        let ask = self.ask()?;
+
+        let settlement = with_lease::execute(
+            self.lease.lease.clone(),
+            Bid::new(
+                self.liquidation.amount(&self.lease.lease),
+                &ask,
+                payment,
+                info.sender.clone(),
+            ),
+            &deps.querier,
+        )?;
+        let bid_response = MessageResponse::messages_with_events(
+            settlement,
+            event::emit_liquidation_bid(env, &self.lease.lease, &info.sender, &ask),
+        );
+
+        Active::try_liquidate(
+            self.lease,
+            self.liquidation,
+            self.keeper,
+            ask,
+            &deps.querier,
+            env,
+        )
+        .map(|liquidate_response| {
+            Response::from(
+                bid_response.merge_with(liquidate_response.response),
+                liquidate_response.next_state,
+            )
+        })
+    }
+
+    fn try_decay(
+        mut self,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        // TODO ref. the TODO in Active::try_on_time_alarm
+        if !self.lease.lease.time_alarms.owned_by(&info.sender) {
+            return Err(ContractError::Unauthorized(
+                access_control::error::Error::Unauthorized {},
+            ));
+        }
+
+        self.periods = self.periods.saturating_add(1);
+        if self.at_floor()? {
+            self.try_sell_on_market(querier, env)
+        } else {
+            self.schedule_decay(env.block.time)
+                .map(|alarm| Response::from(alarm, self))
+        }
+    }
+
+    fn try_sell_on_market(
+        self,
+        querier: &QuerierWrapper<'_>,
+        env: &Env,
+    ) -> ContractResult<Response> {
+        let emitter = event::emit_liquidation_auction_end(
+            env,
+            &self.lease.lease,
+            self.liquidation.amount(&self.lease.lease),
+            &self.floor_ask,
+        );
+        let start_sell = sell_asset::start_on_lease(self.lease, self.liquidation, self.keeper);
+        start_sell
+            .enter(env.block.time, querier)
+            .map(|swap_msg| MessageResponse::messages_with_events(swap_msg, emitter))
+            .map(|sell_resp| Response::from(sell_resp, SellAssetState::from(start_sell)))
+            .map_err(Into::into)
+    }
+}
+
+impl Handler for Auction {
+    fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> ContractResult<StateResponse> {
+        let ask = self.ask()?;
+        super::query(
+            self.lease.lease,
+            self.liquidation,
+            LiquidateTrx::Auction { ask },
+            now,
+            querier,
+        )
+    }
+
+    fn bid(self, deps: &mut DepsMut<'_>, env: Env, info: MessageInfo) -> ContractResult<Response> {
+        self.try_bid(deps.as_ref(), &env, info)
+    }
+
+    fn on_time_alarm(
+        self,
+        deps: Deps<'_>,
+        env: Env,
+        info: MessageInfo,
+    ) -> ContractResult<Response> {
+        self.try_decay(&deps.querier, &env, info)
+    }
+
+    fn on_price_alarm(
+        self,
+        _deps: Deps<'_>,
+        _env: Env,
+        _info: MessageInfo,
+    ) -> ContractResult<Response> {
+        state::ignore_msg(self)
+    }
+}
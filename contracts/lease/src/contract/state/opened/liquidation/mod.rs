@@ -10,7 +10,9 @@ use crate::{
     lease::LeaseDTO,
 };
 
+pub mod auction;
 pub mod sell_asset;
+pub mod transfer_in;
 
 fn query(
     lease: LeaseDTO,
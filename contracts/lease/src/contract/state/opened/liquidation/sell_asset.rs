@@ -2,12 +2,13 @@ use serde::{Deserialize, Serialize};
 
 use currency::{lpn::Lpns, Symbol};
 use dex::{
-    Account, CoinVisitor, ContractInSwap, IterNext, IterState, StartRemoteLocalState, SwapState,
-    SwapTask, TransferInFinishState, TransferInInitState, TransferOutState,
+    Account, CoinVisitor, ContractInSwap, IterNext, IterState, StartLocalLocalState,
+    StartRemoteLocalState, SwapState, SwapTask, TransferInFinishState, TransferInInitState,
+    TransferOutState,
 };
-use finance::coin::CoinDTO;
+use finance::{coin::CoinDTO, percent::bound::BoundToHundredPercent};
 use oracle::stub::OracleRef;
-use sdk::cosmwasm_std::{Env, QuerierWrapper, Timestamp};
+use sdk::cosmwasm_std::{Addr, Env, QuerierWrapper, Timestamp};
 use timealarms::stub::TimeAlarmsRef;
 
 use crate::{
@@ -25,13 +26,28 @@ use crate::{
     event::Type,
 };
 
+pub(super) type StartLocalState =
+    StartLocalLocalState<SellAsset, ForwardToDexEntry, ForwardToDexEntryContinue>;
 pub(super) type StartState =
     StartRemoteLocalState<SellAsset, ForwardToDexEntry, ForwardToDexEntryContinue>;
 pub(crate) type DexState =
     dex::StateLocalOut<SellAsset, ForwardToDexEntry, ForwardToDexEntryContinue>;
 
-pub(in crate::contract::state) fn start(lease: Lease, liquidation: LiquidationDTO) -> StartState {
-    dex::start_remote_local(SellAsset::new(lease, liquidation))
+pub(in crate::contract::state) fn start(
+    lease: Lease,
+    liquidation: LiquidationDTO,
+    keeper: Option<Addr>,
+) -> StartState {
+    dex::start_remote_local(SellAsset::new(lease, liquidation, keeper))
+}
+
+/// Sell an asset already transferred from the DEX account to the lease
+pub(super) fn start_on_lease(
+    lease: Lease,
+    liquidation: LiquidationDTO,
+    keeper: Option<Addr>,
+) -> StartLocalState {
+    dex::start_local_local(SellAsset::new(lease, liquidation, keeper))
 }
 
 type SellAssetStateResponse = <SellAsset as SwapTask>::StateResponse;
@@ -40,11 +56,22 @@ type SellAssetStateResponse = <SellAsset as SwapTask>::StateResponse;
 pub(crate) struct SellAsset {
     lease: Lease,
     liquidation: LiquidationDTO,
+    /// The keeper that triggered the liquidation, if any
+    #[serde(default)]
+    keeper: Option<Addr>,
 }
 
 impl SellAsset {
-    pub(in crate::contract::state) fn new(lease: Lease, liquidation: LiquidationDTO) -> Self {
-        Self { lease, liquidation }
+    pub(in crate::contract::state) fn new(
+        lease: Lease,
+        liquidation: LiquidationDTO,
+        keeper: Option<Addr>,
+    ) -> Self {
+        Self {
+            lease,
+            liquidation,
+            keeper,
+        }
     }
 }
 
@@ -74,6 +101,14 @@ impl SwapTask for SellAsset {
         self.lease.lease.loan.lpp().currency()
     }
 
+    fn max_slippage(&self) -> BoundToHundredPercent {
+        self.lease.max_slippage
+    }
+
+    fn out_reserve(&self) -> Option<CoinDTO<Self::OutG>> {
+        self.lease.auto_pay.clone()
+    }
+
     fn on_coins<Visitor>(&self, visitor: &mut Visitor) -> Result<IterState, Visitor::Error>
     where
         Visitor: CoinVisitor<Result = IterNext>,
@@ -87,7 +122,14 @@ impl SwapTask for SellAsset {
         env: &Env,
         querier: &QuerierWrapper<'_>,
     ) -> Self::Result {
-        Active::try_liquidate(self.lease, self.liquidation, amount_out, querier, env)
+        Active::try_liquidate(
+            self.lease,
+            self.liquidation,
+            self.keeper,
+            amount_out,
+            querier,
+            env,
+        )
     }
 }
 
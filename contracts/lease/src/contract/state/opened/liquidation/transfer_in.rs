@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+
+use currency::{lease::LeaseGroup, Symbol};
+use dex::{
+    Account, CoinVisitor, ContractInSwap, IterNext, IterState, StartTransferInState, SwapState,
+    SwapTask, TransferInFinishState, TransferInInitState, TransferOutState,
+};
+use finance::{coin::CoinDTO, percent::bound::BoundToHundredPercent};
+use oracle::stub::OracleRef;
+use sdk::cosmwasm_std::{Addr, Env, QuerierWrapper, Timestamp};
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    api::{self, opened::LiquidateTrx, AuctionSpec},
+    contract::{
+        cmd::LiquidationDTO,
+        state::{
+            resp_delivery::{ForwardToDexEntry, ForwardToDexEntryContinue},
+            SwapResult,
+        },
+        Lease,
+    },
+    error::ContractResult,
+    event::Type,
+};
+
+use super::auction::Auction;
+
+type AssetGroup = LeaseGroup;
+pub(in crate::contract::state) type StartState =
+    StartTransferInState<TransferIn, ForwardToDexEntry, ForwardToDexEntryContinue>;
+pub(crate) type DexState =
+    dex::StateLocalOut<TransferIn, ForwardToDexEntry, ForwardToDexEntryContinue>;
+
+pub(in crate::contract::state) fn start(
+    lease: Lease,
+    liquidation: LiquidationDTO,
+    keeper: Option<Addr>,
+    spec: AuctionSpec,
+) -> StartState {
+    let amount_in = liquidation.amount(&lease.lease).clone();
+    StartState::new(TransferIn::new(lease, liquidation, keeper, spec), amount_in)
+}
+
+type TransferInStateResponse = <TransferIn as SwapTask>::StateResponse;
+
+/// Transfer the asset under liquidation from the DEX account to the lease to put it up for auction
+#[derive(Serialize, Deserialize)]
+pub(crate) struct TransferIn {
+    lease: Lease,
+    liquidation: LiquidationDTO,
+    keeper: Option<Addr>,
+    spec: AuctionSpec,
+}
+
+impl TransferIn {
+    fn new(
+        lease: Lease,
+        liquidation: LiquidationDTO,
+        keeper: Option<Addr>,
+        spec: AuctionSpec,
+    ) -> Self {
+        Self {
+            lease,
+            liquidation,
+            keeper,
+            spec,
+        }
+    }
+
+    fn state(
+        self,
+        in_progress: LiquidateTrx,
+        now: Timestamp,
+        querier: &QuerierWrapper<'_>,
+    ) -> TransferInStateResponse {
+        super::query(
+            self.lease.lease,
+            self.liquidation,
+            in_progress,
+            now,
+            querier,
+        )
+    }
+}
+
+impl SwapTask for TransferIn {
+    type OutG = AssetGroup;
+    type Label = Type;
+    type StateResponse = ContractResult<api::StateResponse>;
+    type Result = SwapResult;
+
+    fn label(&self) -> Self::Label {
+        Type::LiquidationTransferIn
+    }
+
+    fn dex_account(&self) -> &Account {
+        &self.lease.dex
+    }
+
+    fn oracle(&self) -> &OracleRef {
+        &self.lease.lease.oracle
+    }
+
+    fn time_alarm(&self) -> &TimeAlarmsRef {
+        &self.lease.lease.time_alarms
+    }
+
+    fn out_currency(&self) -> Symbol<'_> {
+        self.lease.lease.amount.ticker()
+    }
+
+    fn max_slippage(&self) -> BoundToHundredPercent {
+        self.lease.max_slippage
+    }
+
+    fn on_coins<Visitor>(&self, visitor: &mut Visitor) -> Result<IterState, Visitor::Error>
+    where
+        Visitor: CoinVisitor<Result = IterNext>,
+    {
+        dex::on_coin(self.liquidation.amount(&self.lease.lease), visitor)
+    }
+
+    fn finish(
+        self,
+        amount_out: CoinDTO<Self::OutG>,
+        env: &Env,
+        querier: &QuerierWrapper<'_>,
+    ) -> Self::Result {
+        debug_assert!(&amount_out == self.liquidation.amount(&self.lease.lease));
+        Auction::start(
+            self.lease,
+            self.liquidation,
+            self.keeper,
+            self.spec,
+            env,
+            querier,
+        )
+    }
+}
+
+impl ContractInSwap<TransferOutState, TransferInStateResponse> for TransferIn {
+    fn state(self, _now: Timestamp, _querier: &QuerierWrapper<'_>) -> TransferInStateResponse {
+        // it's due to reusing the same enum dex::State
+        // have to define a tailored enum dex::State that starts from TransferIn
+        unreachable!(
+            "The liquidation asset transfer-in task never goes through a 'TransferOut' state!"
+        )
+    }
+}
+
+impl ContractInSwap<SwapState, TransferInStateResponse> for TransferIn {
+    fn state(self, _now: Timestamp, _querier: &QuerierWrapper<'_>) -> TransferInStateResponse {
+        // it's due to reusing the same enum dex::State
+        // have to define a tailored enum dex::State that starts from TransferIn
+        unreachable!("The liquidation asset transfer-in task never goes through a 'Swap'!")
+    }
+}
+
+impl ContractInSwap<TransferInInitState, TransferInStateResponse> for TransferIn {
+    fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> TransferInStateResponse {
+        self.state(LiquidateTrx::TransferInInit, now, querier)
+    }
+}
+
+impl ContractInSwap<TransferInFinishState, TransferInStateResponse> for TransferIn {
+    fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> TransferInStateResponse {
+        self.state(LiquidateTrx::TransferInFinish, now, querier)
+    }
+}
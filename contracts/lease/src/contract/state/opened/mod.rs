@@ -8,9 +8,14 @@ use crate::{
 };
 
 pub mod active;
+mod auto_pay;
 mod balance;
+pub mod close;
+pub mod collateral;
 mod event;
+pub mod increase;
 pub mod liquidation;
+mod pause;
 pub mod repay;
 
 fn lease_state(
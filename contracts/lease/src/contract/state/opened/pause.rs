@@ -0,0 +1,33 @@
+use sdk::cosmwasm_std::{Env, QuerierWrapper};
+
+use crate::{
+    api::{ProtocolPause, QueryLeaserMsg},
+    error::{ContractError, ContractResult},
+};
+
+/// Query the lease operations paused by the leaser, that is the lease instantiator
+pub(super) fn query(querier: &QuerierWrapper<'_>, env: &Env) -> ContractResult<ProtocolPause> {
+    let leaser = platform::contract::query_creator(querier, &env.contract.address)?;
+    querier
+        .query_wasm_smart(leaser, &QueryLeaserMsg::ProtocolPause {})
+        .map_err(Into::into)
+}
+
+/// Fail if the operation is paused
+pub(super) fn check<IsPaused>(
+    op: &str,
+    is_paused: IsPaused,
+    querier: &QuerierWrapper<'_>,
+    env: &Env,
+) -> ContractResult<()>
+where
+    IsPaused: FnOnce(&ProtocolPause) -> bool,
+{
+    query(querier, env).and_then(|pause| {
+        if is_paused(&pause) {
+            Err(ContractError::OperationPaused(op.into()))
+        } else {
+            Ok(())
+        }
+    })
+}
@@ -5,7 +5,7 @@ use dex::{
     Account, CoinVisitor, ContractInSwap, IterNext, IterState, StartLocalLocalState, SwapState,
     SwapTask, TransferInFinishState, TransferInInitState, TransferOutState,
 };
-use finance::coin::CoinDTO;
+use finance::{coin::CoinDTO, percent::bound::BoundToHundredPercent};
 use oracle::stub::OracleRef;
 use sdk::cosmwasm_std::{Env, QuerierWrapper, Timestamp};
 use timealarms::stub::TimeAlarmsRef;
@@ -29,7 +29,14 @@ pub(super) type StartState =
 pub(crate) type DexState = dex::StateLocalOut<BuyLpn, ForwardToDexEntry, ForwardToDexEntryContinue>;
 
 pub(in crate::contract::state) fn start(lease: Lease, payment: PaymentCoin) -> StartState {
-    dex::start_local_local(BuyLpn::new(lease, payment))
+    dex::start_local_local(BuyLpn::new(lease, payment, Target::Repayment))
+}
+
+pub(in crate::contract::state) fn start_auto_pay_funding(
+    lease: Lease,
+    payment: PaymentCoin,
+) -> StartState {
+    dex::start_local_local(BuyLpn::new(lease, payment, Target::AutoPay))
 }
 
 type BuyLpnStateResponse = <BuyLpn as SwapTask>::StateResponse;
@@ -38,11 +45,46 @@ type BuyLpnStateResponse = <BuyLpn as SwapTask>::StateResponse;
 pub(crate) struct BuyLpn {
     lease: Lease,
     payment: PaymentCoin,
+    /// Swaps started before the auto-pay introduction are all repayments
+    #[serde(default)]
+    target: Target,
+}
+
+/// What the bought LPN goes to
+#[derive(Serialize, Deserialize, Default)]
+enum Target {
+    #[default]
+    Repayment,
+    AutoPay,
 }
 
 impl BuyLpn {
-    fn new(lease: Lease, payment: PaymentCoin) -> Self {
-        Self { lease, payment }
+    fn new(lease: Lease, payment: PaymentCoin, target: Target) -> Self {
+        Self {
+            lease,
+            payment,
+            target,
+        }
+    }
+
+    fn query(
+        self,
+        in_progress: RepayTrx,
+        now: Timestamp,
+        querier: &QuerierWrapper<'_>,
+    ) -> BuyLpnStateResponse {
+        match self.target {
+            Target::Repayment => {
+                repay::query(self.lease.lease, self.payment, in_progress, now, querier)
+            }
+            Target::AutoPay => repay::query_auto_pay_funding(
+                self.lease.lease,
+                self.payment,
+                in_progress,
+                now,
+                querier,
+            ),
+        }
     }
 
     // fn emit_ok(&self) -> Emitter {
@@ -76,6 +118,14 @@ impl SwapTask for BuyLpn {
         self.lease.lease.loan.lpp().currency()
     }
 
+    fn max_slippage(&self) -> BoundToHundredPercent {
+        self.lease.max_slippage
+    }
+
+    fn out_reserve(&self) -> Option<CoinDTO<Self::OutG>> {
+        self.lease.auto_pay.clone()
+    }
+
     fn on_coins<Visitor>(&self, visitor: &mut Visitor) -> Result<IterState, Visitor::Error>
     where
         Visitor: CoinVisitor<Result = IterNext>,
@@ -89,48 +139,33 @@ impl SwapTask for BuyLpn {
         env: &Env,
         querier: &QuerierWrapper<'_>,
     ) -> Self::Result {
-        Active::try_repay_lpn(self.lease, amount_out, querier, env)
+        match self.target {
+            Target::Repayment => Active::try_repay_lpn(self.lease, amount_out, querier, env),
+            Target::AutoPay => Active::try_top_up_auto_pay(self.lease, amount_out, env),
+        }
     }
 }
 
 impl ContractInSwap<TransferOutState, BuyLpnStateResponse> for BuyLpn {
     fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> BuyLpnStateResponse {
-        repay::query(
-            self.lease.lease,
-            self.payment,
-            RepayTrx::TransferOut,
-            now,
-            querier,
-        )
+        self.query(RepayTrx::TransferOut, now, querier)
     }
 }
 
 impl ContractInSwap<SwapState, BuyLpnStateResponse> for BuyLpn {
     fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> BuyLpnStateResponse {
-        repay::query(self.lease.lease, self.payment, RepayTrx::Swap, now, querier)
+        self.query(RepayTrx::Swap, now, querier)
     }
 }
 
 impl ContractInSwap<TransferInInitState, BuyLpnStateResponse> for BuyLpn {
     fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> BuyLpnStateResponse {
-        repay::query(
-            self.lease.lease,
-            self.payment,
-            RepayTrx::TransferInInit,
-            now,
-            querier,
-        )
+        self.query(RepayTrx::TransferInInit, now, querier)
     }
 }
 
 impl ContractInSwap<TransferInFinishState, BuyLpnStateResponse> for BuyLpn {
     fn state(self, now: Timestamp, querier: &QuerierWrapper<'_>) -> BuyLpnStateResponse {
-        repay::query(
-            self.lease.lease,
-            self.payment,
-            RepayTrx::TransferInInit,
-            now,
-            querier,
-        )
+        self.query(RepayTrx::TransferInInit, now, querier)
     }
 }
@@ -25,3 +25,18 @@ fn query(
 
     super::lease_state(lease, Some(in_progress), now, querier)
 }
+
+fn query_auto_pay_funding(
+    lease: LeaseDTO,
+    payment: PaymentCoin,
+    in_progress: RepayTrx,
+    now: Timestamp,
+    querier: &QuerierWrapper<'_>,
+) -> ContractResult<StateResponse> {
+    let in_progress = OngoingTrx::AutoPayFunding {
+        payment,
+        in_progress,
+    };
+
+    super::lease_state(lease, Some(in_progress), now, querier)
+}
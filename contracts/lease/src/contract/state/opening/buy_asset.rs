@@ -5,7 +5,7 @@ use dex::{
     Account, CoinVisitor, ContractInSwap, IterNext, IterState, StartLocalRemoteState, SwapState,
     SwapTask, TransferOutState,
 };
-use finance::coin::CoinDTO;
+use finance::{coin::CoinDTO, percent::bound::BoundToHundredPercent};
 use lpp::stub::LppRef;
 use oracle::stub::OracleRef;
 use platform::{
@@ -17,11 +17,12 @@ use timealarms::stub::TimeAlarmsRef;
 
 use crate::{
     api::{
-        self, opening::OngoingTrx, DownpaymentCoin, NewLeaseContract, NewLeaseForm, StateResponse,
+        self, opening::OngoingTrx, DownpaymentCoins, NewLeaseContract, NewLeaseForm, StateResponse,
     },
     contract::{
         cmd::{self, OpenLoanRespResult},
         state::{
+            leaser,
             opened::active::Active,
             resp_delivery::{ForwardToDexEntry, ForwardToDexEntryContinue},
             SwapResult,
@@ -42,7 +43,7 @@ pub(in crate::contract::state) type DexState =
 
 pub(in crate::contract::state::opening) fn start(
     new_lease: NewLeaseContract,
-    downpayment: DownpaymentCoin,
+    downpayment: DownpaymentCoins,
     loan: OpenLoanRespResult,
     deps: (LppRef, OracleRef, TimeAlarmsRef),
     start_opening_at: Timestamp,
@@ -62,7 +63,7 @@ type BuyAssetStateResponse = <BuyAsset as SwapTask>::StateResponse;
 pub(crate) struct BuyAsset {
     form: NewLeaseForm,
     dex_account: Account,
-    downpayment: DownpaymentCoin,
+    downpayment: DownpaymentCoins,
     loan: OpenLoanRespResult,
     deps: (LppRef, OracleRef, TimeAlarmsRef),
     start_opening_at: Timestamp,
@@ -72,7 +73,7 @@ impl BuyAsset {
     pub(super) fn new(
         form: NewLeaseForm,
         dex_account: Account,
-        downpayment: DownpaymentCoin,
+        downpayment: DownpaymentCoins,
         loan: OpenLoanRespResult,
         deps: (LppRef, OracleRef, TimeAlarmsRef),
         start_opening_at: Timestamp,
@@ -130,11 +131,15 @@ impl SwapTask for BuyAsset {
         &self.form.currency
     }
 
+    fn max_slippage(&self) -> BoundToHundredPercent {
+        self.form.max_slippage
+    }
+
     fn on_coins<Visitor>(&self, visitor: &mut Visitor) -> Result<IterState, Visitor::Error>
     where
         Visitor: CoinVisitor<Result = IterNext>,
     {
-        dex::on_coins(&self.downpayment, &self.loan.principal, visitor)
+        dex::on_many_coins(self.downpayment.as_slice(), &self.loan.principal, visitor)
     }
 
     fn finish(
@@ -143,6 +148,15 @@ impl SwapTask for BuyAsset {
         env: &Env,
         querier: &QuerierWrapper<'_>,
     ) -> Self::Result {
+        let max_slippage = self.form.max_slippage;
+        let liquidation_fee = self.form.liquidation_fee;
+        let liquidation_mode = self.form.liquidation_mode;
+        let notification = leaser::notify_opened(
+            querier,
+            env,
+            amount_out.clone(),
+            self.loan.principal.clone(),
+        )?;
         let IntoDTOResult { lease, batch } = cmd::open_lease(
             self.form,
             self.dex_account.owner().clone(),
@@ -155,10 +169,14 @@ impl SwapTask for BuyAsset {
         let active = Active::new(Lease {
             lease,
             dex: self.dex_account,
+            max_slippage,
+            liquidation_fee,
+            liquidation_mode,
+            auto_pay: None,
         });
-        let emitter = active.emit_opened(env, self.downpayment, self.loan);
+        let emitter = active.emit_opened(env, self.downpayment.into(), self.loan);
         Ok(StateMachineResponse::from(
-            MessageResponse::messages_with_events(batch, emitter),
+            MessageResponse::messages_with_events(batch.merge(notification), emitter),
             active,
         ))
     }
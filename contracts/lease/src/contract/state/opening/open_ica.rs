@@ -13,7 +13,7 @@ use sdk::cosmwasm_std::{QuerierWrapper, Timestamp};
 use timealarms::stub::TimeAlarmsRef;
 
 use crate::{
-    api::{self, opening::OngoingTrx, DownpaymentCoin, NewLeaseContract, StateResponse},
+    api::{self, opening::OngoingTrx, DownpaymentCoins, NewLeaseContract, StateResponse},
     contract::cmd::OpenLoanRespResult,
     error::ContractResult,
 };
@@ -23,7 +23,7 @@ use super::buy_asset::{BuyAsset, DexState};
 #[derive(Serialize, Deserialize)]
 pub(crate) struct OpenIcaAccount {
     new_lease: NewLeaseContract,
-    downpayment: DownpaymentCoin,
+    downpayment: DownpaymentCoins,
     loan: OpenLoanRespResult,
     deps: (LppRef, OracleRef, TimeAlarmsRef),
     start_opening_at: Timestamp,
@@ -32,7 +32,7 @@ pub(crate) struct OpenIcaAccount {
 impl OpenIcaAccount {
     pub(super) fn new(
         new_lease: NewLeaseContract,
-        downpayment: DownpaymentCoin,
+        downpayment: DownpaymentCoins,
         loan: OpenLoanRespResult,
         deps: (LppRef, OracleRef, TimeAlarmsRef),
         start_opening_at: Timestamp,
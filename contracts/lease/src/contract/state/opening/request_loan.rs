@@ -11,7 +11,7 @@ use sdk::cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, R
 use timealarms::stub::TimeAlarmsRef;
 
 use crate::{
-    api::{DownpaymentCoin, NewLeaseContract},
+    api::{DownpaymentCoins, NewLeaseContract},
     contract::{
         cmd::{OpenLoanReq, OpenLoanReqResult, OpenLoanResp},
         state::{Handler, Response},
@@ -25,7 +25,7 @@ use super::buy_asset::DexState;
 #[derive(Serialize, Deserialize)]
 pub(crate) struct RequestLoan {
     new_lease: NewLeaseContract,
-    downpayment: DownpaymentCoin,
+    downpayment: DownpaymentCoins,
     deps: (LppRef, OracleRef, TimeAlarmsRef),
 }
 
@@ -55,7 +55,7 @@ impl RequestLoan {
         Ok((batch, {
             Self {
                 new_lease: spec,
-                downpayment,
+                downpayment: downpayment.into(),
                 deps: (lpp, oracle, timealarms),
             }
         }))
@@ -0,0 +1,50 @@
+use currency::native::Nls;
+use platform::{
+    batch::{Batch, Emit, Emitter},
+    message::Response as MessageResponse,
+};
+use sdk::cosmwasm_std::{Addr, Deps, Env, MessageInfo};
+
+use crate::{api::ExecuteLeaserMsg, error::ContractResult, event::Type, lease::LeaseDTO};
+
+/// Transfer the lease to a new customer
+///
+/// The leaser, that is the lease instantiator, is notified to move the lease
+/// under the new customer.
+pub(super) fn transfer(
+    lease: &mut LeaseDTO,
+    new_customer: Addr,
+    deps: Deps<'_>,
+    env: &Env,
+    info: &MessageInfo,
+) -> ContractResult<MessageResponse> {
+    access_control::check(&lease.customer, &info.sender)?;
+
+    let new_customer = deps.api.addr_validate(new_customer.as_str())?;
+    let leaser = platform::contract::query_creator(&deps.querier, &env.contract.address)?;
+
+    let customer = std::mem::replace(&mut lease.customer, new_customer.clone());
+
+    let mut batch = Batch::default();
+    batch.schedule_execute_wasm_no_reply::<_, Nls>(
+        &leaser,
+        ExecuteLeaserMsg::ChangeLeaseCustomer {
+            customer: customer.clone(),
+            new_customer: new_customer.clone(),
+        },
+        None,
+    )?;
+
+    Ok(MessageResponse::messages_with_events(
+        batch,
+        emit_transfer(env, lease, customer),
+    ))
+}
+
+fn emit_transfer(env: &Env, lease: &LeaseDTO, prev_customer: Addr) -> Emitter {
+    Emitter::of_type(Type::OwnershipTransfer)
+        .emit_tx_info(env)
+        .emit("id", &lease.addr)
+        .emit("customer", prev_customer)
+        .emit("new-customer", lease.customer.clone())
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use dex::Enterable;
-use sdk::cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Timestamp};
+use sdk::cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Timestamp};
 
 use crate::{api::StateResponse, contract::Lease, error::ContractResult};
 
@@ -45,6 +45,24 @@ impl Handler for Active {
             .map(|batch| Response::from(batch, DexState::from(start_transfer_in)))
             .map_err(Into::into)
     }
+
+    fn transfer_ownership(
+        mut self,
+        deps: &mut DepsMut<'_>,
+        env: Env,
+        info: MessageInfo,
+        new_customer: Addr,
+    ) -> ContractResult<Response> {
+        super::ownership::transfer(
+            &mut self.lease.lease,
+            new_customer,
+            deps.as_ref(),
+            &env,
+            &info,
+        )
+        .map(|response| Response::from(response, self))
+    }
+
     fn on_time_alarm(
         self,
         _deps: Deps<'_>,
@@ -5,7 +5,7 @@ use dex::{
     Account, CoinVisitor, ContractInSwap, IterNext, IterState, StartTransferInState, SwapState,
     SwapTask, TransferInFinishState, TransferInInitState, TransferOutState,
 };
-use finance::coin::CoinDTO;
+use finance::{coin::CoinDTO, percent::bound::BoundToHundredPercent};
 use oracle::stub::OracleRef;
 use platform::{
     message::Response as MessageResponse, state_machine::Response as StateMachineResponse,
@@ -18,6 +18,7 @@ use crate::{
     contract::{
         state::{
             closed::Closed,
+            leaser,
             resp_delivery::{ForwardToDexEntry, ForwardToDexEntryContinue},
             SwapResult,
         },
@@ -88,6 +89,10 @@ impl SwapTask for TransferIn {
         self.lease.lease.amount.ticker()
     }
 
+    fn max_slippage(&self) -> BoundToHundredPercent {
+        self.lease.max_slippage
+    }
+
     fn on_coins<Visitor>(&self, visitor: &mut Visitor) -> Result<IterState, Visitor::Error>
     where
         Visitor: CoinVisitor<Result = IterNext>,
@@ -104,7 +109,9 @@ impl SwapTask for TransferIn {
         debug_assert!(amount_out == self.lease.lease.amount);
         let closed = Closed::default();
         let emitter = closed.emit_ok(env, &self.lease.lease);
-        let batch = closed.enter_state(self.lease.lease, querier)?;
+        let batch = closed
+            .enter_state(self.lease.lease, querier)?
+            .merge(leaser::notify_closed(querier, env, false)?);
         Ok(StateMachineResponse::from(
             MessageResponse::messages_with_events(batch, emitter),
             closed,
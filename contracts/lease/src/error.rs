@@ -4,7 +4,7 @@ use thiserror::Error;
 
 use currency::error::Error as CurrencyError;
 use dex::Error as DexError;
-use finance::error::Error as FinanceError;
+use finance::{error::Error as FinanceError, percent::Percent};
 use lpp::error::ContractError as LppError;
 use oracle::error::ContractError as OracleError;
 use platform::error::Error as PlatformError;
@@ -13,7 +13,7 @@ use sdk::cosmwasm_std::StdError;
 use swap::error::Error as SwapError;
 use timealarms::error::ContractError as TimeAlarmsError;
 
-use crate::api::PaymentCoin;
+use crate::api::{LeaseCoin, LpnCoin, PaymentCoin};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
@@ -53,12 +53,45 @@ pub enum ContractError {
     #[error("[Lease] No payment sent")]
     NoPaymentError(),
 
+    #[error("[Lease] The customer has not opted in to auto-pay")]
+    NoAutoPayReserve(),
+
     #[error("[Lease] Insufficient payment amount {0}")]
     InsufficientPayment(PaymentCoin),
 
+    #[error("[Lease] Invalid position close amount {0}, it should be a positive amount of the lease asset not exceeding the lease amount")]
+    InvalidPositionCloseAmount(LeaseCoin),
+
+    #[error("[Lease] Insufficient position close proceeds to repay the loan")]
+    InsufficientPositionClose(),
+
+    #[error("[Lease] The close policy should not trigger at the current market price")]
+    ClosePolicyTriggerNow(),
+
+    #[error("[Lease] Invalid loan increase {0}, it should be a positive amount exceeding the interest due")]
+    InvalidLoanIncrease(LpnCoin),
+
+    #[error("[Lease] The loan may not be increased while there is overdue interest")]
+    LoanIncreaseOverdue(),
+
+    #[error("[Lease] The loan increase would result in a LTV of {0} above the initial one")]
+    LoanIncreaseAboveInitialLtv(Percent),
+
+    #[error("[Lease] Invalid simulated price change {0}, it should be a positive percent of the current price")]
+    InvalidPriceChange(Percent),
+
+    #[error("[Lease] Insufficient bid, it should be at least {0}")]
+    InsufficientBid(LpnCoin),
+
     #[error("[Lease] Insufficient liquidation amount")]
     InsufficientLiquidation(),
 
+    #[error("[Lease] The lease is not subject to liquidation")]
+    NoLiquidation(),
+
+    #[error("[Lease] The operation '{0}' is paused by the protocol")]
+    OperationPaused(String),
+
     #[error("[Lease] The operation '{0}' is not supported in the current state")]
     UnsupportedOperation(String),
 
@@ -7,9 +7,25 @@ pub enum Type {
     PaidActive,
     ClosingTransferIn,
     Closed,
+    CollateralSwap,
+    CollateralAdded,
+    LoanIncreaseSwap,
+    LoanIncreased,
+    ClosePositionSwap,
+    ClosePosition,
+    ClosePolicyTrigger,
+    OwnershipTransfer,
+    AutoPayFunded,
+    AutoPayPaid,
+    AutoPayLow,
+    AutoPayWithdrawn,
     LiquidationWarning,
     LiquidationStart,
     LiquidationSwap,
+    LiquidationTransferIn,
+    LiquidationAuction,
+    LiquidationBid,
+    LiquidationAuctionEnd,
     Liquidation,
 }
 
@@ -25,9 +41,25 @@ impl Type {
             Self::PaidActive => "ls-repay",
             Self::ClosingTransferIn => "ls-close-transfer-in",
             Self::Closed => "ls-close",
+            Self::CollateralSwap => "ls-collateral-swap",
+            Self::CollateralAdded => "ls-collateral-add",
+            Self::LoanIncreaseSwap => "ls-loan-increase-swap",
+            Self::LoanIncreased => "ls-loan-increase",
+            Self::ClosePositionSwap => "ls-close-position-swap",
+            Self::ClosePosition => "ls-close-position",
+            Self::ClosePolicyTrigger => "ls-close-policy-trigger",
+            Self::OwnershipTransfer => "ls-ownership-transfer",
+            Self::AutoPayFunded => "ls-auto-pay-fund",
+            Self::AutoPayPaid => "ls-auto-pay",
+            Self::AutoPayLow => "ls-auto-pay-low",
+            Self::AutoPayWithdrawn => "ls-auto-pay-withdraw",
             Self::LiquidationWarning => "ls-liquidation-warning",
             Self::LiquidationStart => "ls-liquidation-start",
             Self::LiquidationSwap => "ls-liquidation-swap",
+            Self::LiquidationTransferIn => "ls-liquidation-transfer-in",
+            Self::LiquidationAuction => "ls-liquidation-auction",
+            Self::LiquidationBid => "ls-liquidation-bid",
+            Self::LiquidationAuctionEnd => "ls-liquidation-auction-end",
             Self::Liquidation => "ls-liquidation",
         }
     }
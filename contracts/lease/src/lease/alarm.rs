@@ -51,8 +51,13 @@ where
             now < &grace_period_end,
             "Rescheduling when the lease is in overdue! A liquidation is expected!"
         );
+        let recalc_at = grace_period_end.min(*now + self.liability.recalculation_time());
         time_alarms
-            .setup_alarm(grace_period_end.min(*now + self.liability.recalculation_time()))
+            .setup_alarm(
+                self.loan
+                    .requote_after(*now)
+                    .map_or(recalc_at, |requote_at| recalc_at.min(requote_at)),
+            )
             .map_err(Into::into)
     }
 
@@ -73,12 +78,13 @@ where
             .total;
         debug_assert!(!total_liability.is_zero());
 
-        let below = self.price_alarm_at_level(total_liability, liquidation_zone.high())?;
+        let below = self.price_alarm_at_level(total_liability, liquidation_zone.high());
 
         let above_or_equal = liquidation_zone
             .low()
-            .map(|low| self.price_alarm_at_level(total_liability, low))
-            .transpose()?;
+            .map(|low| self.price_alarm_at_level(total_liability, low));
+
+        let (below, above_or_equal) = self.close_policy.alarm_range(below, above_or_equal);
 
         price_alarms
             .add_alarm(Alarm::new(
@@ -88,11 +94,11 @@ where
             .map_err(Into::into)
     }
 
-    fn price_alarm_at_level(
+    pub(super) fn price_alarm_at_level(
         &self,
         liability: Coin<Lpn>,
         alarm_at: Level,
-    ) -> ContractResult<Price<Asset, Lpn>> {
+    ) -> Price<Asset, Lpn> {
         debug_assert!(!self.amount.is_zero(), "Invariant broken, asset = 0!");
         debug_assert!(
             !liability.is_zero(),
@@ -100,7 +106,7 @@ where
         );
         debug_assert!(!alarm_at.ltv().is_zero());
 
-        Ok(total_of(alarm_at.ltv().of(self.amount)).is(liability))
+        total_of(alarm_at.ltv().of(self.amount)).is(liability)
     }
 }
 
@@ -0,0 +1,171 @@
+use serde::Serialize;
+
+use currency::Currency;
+use finance::{
+    coin::Coin,
+    fraction::Fraction,
+    percent::{Percent, Units},
+    price,
+};
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::Oracle as OracleTrait;
+use platform::{
+    bank::{FixedAddressSender, LazySenderStub},
+    batch::Batch,
+};
+use sdk::cosmwasm_std::Addr;
+
+use crate::error::{ContractError, ContractResult};
+
+use super::Lease;
+
+impl<Lpn, Asset, Lpp, Oracle> Lease<Lpn, Asset, Lpp, Oracle>
+where
+    Lpn: Currency + Serialize,
+    Lpp: LppLoanTrait<Lpn>,
+    Oracle: OracleTrait<Lpn>,
+    Asset: Currency + Serialize,
+{
+    /// The asks an auction of the lease asset starts from and ends at
+    ///
+    /// They are the value of the asset at the oracle price increased by the premium, and
+    /// the floor share of that value, respectively.
+    pub(crate) fn auction_asks(
+        &self,
+        asset: Coin<Asset>,
+        premium: Percent,
+        floor: Percent,
+    ) -> ContractResult<(Coin<Lpn>, Coin<Lpn>)> {
+        self.price_of_lease_currency().map(|price| {
+            let value = price::total(asset, price);
+            (value + premium.of(value), floor.of(value))
+        })
+    }
+}
+
+/// The ask of an auction after `periods` of decay
+///
+/// The ask goes down by `decay` of the initial one on each period until it reaches the floor.
+pub(crate) fn decayed_ask<Lpn>(
+    start: Coin<Lpn>,
+    decay: Percent,
+    periods: Units,
+    floor: Coin<Lpn>,
+) -> Coin<Lpn>
+where
+    Lpn: Currency,
+{
+    decay
+        .of(start)
+        .checked_mul(periods.into())
+        .map_or(floor, |decline| start.saturating_sub(decline))
+        .max(floor)
+}
+
+/// Sell the asset to the buyer of an auction
+///
+/// The payment should cover the ask. The asset and the excess payment are sent to the buyer.
+pub(crate) fn settle_bid<Lpn, Asset>(
+    asset: Coin<Asset>,
+    ask: Coin<Lpn>,
+    payment: Coin<Lpn>,
+    buyer: Addr,
+) -> ContractResult<Batch>
+where
+    Lpn: Currency,
+    Asset: Currency,
+{
+    if payment < ask {
+        return Err(ContractError::InsufficientBid(ask.into()));
+    }
+
+    let mut sender = LazySenderStub::new(buyer);
+    sender.send(asset);
+    sender.send(payment - ask);
+    Ok(sender.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use finance::{coin::Coin, percent::Percent};
+    use platform::{
+        bank::{FixedAddressSender, LazySenderStub},
+        batch::Batch,
+    };
+    use sdk::cosmwasm_std::Addr;
+
+    use crate::{
+        error::ContractError,
+        lease::tests::{self, TestCurrency, TestLpn},
+    };
+
+    const BUYER: &str = "buyer";
+
+    #[test]
+    fn asks() {
+        let lease = tests::open_lease(tests::coin(1000), tests::loan());
+        assert_eq!(
+            (tests::lpn_coin(550), tests::lpn_coin(450)),
+            lease
+                .auction_asks(
+                    tests::coin(500),
+                    Percent::from_percent(10),
+                    Percent::from_percent(90)
+                )
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn decay() {
+        let start = tests::lpn_coin(1000);
+        let decay = Percent::from_percent(15);
+        let floor = tests::lpn_coin(300);
+        assert_eq!(start, super::decayed_ask(start, decay, 0, floor));
+        assert_eq!(
+            tests::lpn_coin(850),
+            super::decayed_ask(start, decay, 1, floor)
+        );
+        assert_eq!(
+            tests::lpn_coin(400),
+            super::decayed_ask(start, decay, 4, floor)
+        );
+        assert_eq!(floor, super::decayed_ask(start, decay, 5, floor));
+        assert_eq!(floor, super::decayed_ask(start, decay, 7, floor));
+        assert_eq!(floor, super::decayed_ask(start, decay, u32::MAX, floor));
+        assert_eq!(
+            start,
+            super::decayed_ask(start, Percent::ZERO, u32::MAX, floor)
+        );
+    }
+
+    #[test]
+    fn settle() {
+        let asset = tests::coin(300);
+        let batch = super::settle_bid(
+            asset,
+            tests::lpn_coin(200),
+            tests::lpn_coin(210),
+            Addr::unchecked(BUYER),
+        )
+        .unwrap();
+
+        let mut sender = LazySenderStub::new(Addr::unchecked(BUYER));
+        sender.send(asset);
+        sender.send(tests::lpn_coin(10));
+        assert_eq!(Batch::from(sender), batch);
+    }
+
+    #[test]
+    fn settle_insufficient() {
+        assert_eq!(
+            Err(ContractError::InsufficientBid(tests::lpn_coin(200).into())),
+            super::settle_bid(
+                Coin::<TestCurrency>::new(300),
+                tests::lpn_coin(200),
+                Coin::<TestLpn>::new(199),
+                Addr::unchecked(BUYER),
+            )
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+
+use currency::Currency;
+use finance::price::Price;
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::Oracle as OracleTrait;
+
+use crate::{
+    api::ClosePolicy as ClosePolicyDTO,
+    error::{ContractError, ContractResult},
+};
+
+use super::Lease;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClosePolicy<Asset, Lpn>
+where
+    Asset: Currency,
+    Lpn: Currency,
+{
+    stop_loss: Option<Price<Asset, Lpn>>,
+    take_profit: Option<Price<Asset, Lpn>>,
+}
+
+/// The reason a position gets closed on a customer-defined price
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Trigger {
+    StopLoss,
+    TakeProfit,
+}
+
+impl Trigger {
+    pub(crate) const fn as_str(&self) -> &'static str {
+        match self {
+            Self::StopLoss => "stop-loss",
+            Self::TakeProfit => "take-profit",
+        }
+    }
+}
+
+impl<Asset, Lpn> ClosePolicy<Asset, Lpn>
+where
+    Asset: Currency,
+    Lpn: Currency,
+{
+    fn is_empty(&self) -> bool {
+        self.stop_loss.is_none() && self.take_profit.is_none()
+    }
+
+    fn trigger(&self, price: Price<Asset, Lpn>) -> Option<Trigger> {
+        if self.stop_loss.map_or(false, |stop_loss| price < stop_loss) {
+            Some(Trigger::StopLoss)
+        } else if self
+            .take_profit
+            .map_or(false, |take_profit| price >= take_profit)
+        {
+            Some(Trigger::TakeProfit)
+        } else {
+            None
+        }
+    }
+
+    /// Narrow the price range outside of which an alarm should be raised
+    pub(super) fn alarm_range(
+        &self,
+        below: Price<Asset, Lpn>,
+        above_or_equal: Option<Price<Asset, Lpn>>,
+    ) -> (Price<Asset, Lpn>, Option<Price<Asset, Lpn>>) {
+        let below = match self.stop_loss {
+            Some(stop_loss) if stop_loss > below => stop_loss,
+            _ => below,
+        };
+        let above_or_equal = match (above_or_equal, self.take_profit) {
+            (Some(above_or_equal), Some(take_profit)) if take_profit < above_or_equal => {
+                Some(take_profit)
+            }
+            (None, take_profit) => take_profit,
+            (above_or_equal, _) => above_or_equal,
+        };
+        (below, above_or_equal)
+    }
+}
+
+impl<Asset, Lpn> Default for ClosePolicy<Asset, Lpn>
+where
+    Asset: Currency,
+    Lpn: Currency,
+{
+    fn default() -> Self {
+        Self {
+            stop_loss: None,
+            take_profit: None,
+        }
+    }
+}
+
+impl<Asset, Lpn> TryFrom<ClosePolicyDTO> for ClosePolicy<Asset, Lpn>
+where
+    Asset: Currency,
+    Lpn: Currency,
+{
+    type Error = ContractError;
+
+    fn try_from(dto: ClosePolicyDTO) -> ContractResult<Self> {
+        Ok(Self {
+            stop_loss: dto.stop_loss.map(TryInto::try_into).transpose()?,
+            take_profit: dto.take_profit.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl<Asset, Lpn> From<ClosePolicy<Asset, Lpn>> for ClosePolicyDTO
+where
+    Asset: Currency,
+    Lpn: Currency,
+{
+    fn from(policy: ClosePolicy<Asset, Lpn>) -> Self {
+        Self {
+            stop_loss: policy.stop_loss.map(Into::into),
+            take_profit: policy.take_profit.map(Into::into),
+        }
+    }
+}
+
+impl<Lpn, Asset, Lpp, Oracle> Lease<Lpn, Asset, Lpp, Oracle>
+where
+    Lpn: Currency + Serialize,
+    Lpp: LppLoanTrait<Lpn>,
+    Oracle: OracleTrait<Lpn>,
+    Asset: Currency + Serialize,
+{
+    pub(crate) fn change_close_policy(
+        &mut self,
+        policy: ClosePolicy<Asset, Lpn>,
+    ) -> ContractResult<()> {
+        if !policy.is_empty() && policy.trigger(self.price_of_lease_currency()?).is_some() {
+            return Err(ContractError::ClosePolicyTriggerNow());
+        }
+        self.close_policy = policy;
+        Ok(())
+    }
+
+    pub(crate) fn close_policy_trigger(&self) -> ContractResult<Option<Trigger>> {
+        if self.close_policy.is_empty() {
+            Ok(None)
+        } else {
+            self.price_of_lease_currency()
+                .map(|price| self.close_policy.trigger(price))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use currency::{lease::Atom, lpn::Usdc};
+    use finance::{coin::Coin, price};
+
+    use super::{ClosePolicy, Trigger};
+
+    type Policy = ClosePolicy<Atom, Usdc>;
+
+    fn price(asset: u128, lpn: u128) -> price::Price<Atom, Usdc> {
+        price::total_of(Coin::new(asset)).is(Coin::new(lpn))
+    }
+
+    #[test]
+    fn trigger() {
+        let policy = Policy {
+            stop_loss: Some(price(1, 2)),
+            take_profit: Some(price(1, 4)),
+        };
+        assert_eq!(None, policy.trigger(price(1, 2)));
+        assert_eq!(None, policy.trigger(price(1, 3)));
+        assert_eq!(Some(Trigger::StopLoss), policy.trigger(price(2, 3)));
+        assert_eq!(Some(Trigger::TakeProfit), policy.trigger(price(1, 4)));
+        assert_eq!(None, Policy::default().trigger(price(1, 1)));
+    }
+
+    #[test]
+    fn alarm_range() {
+        let policy = Policy {
+            stop_loss: Some(price(1, 2)),
+            take_profit: Some(price(1, 4)),
+        };
+        assert_eq!(
+            (price(1, 2), Some(price(1, 4))),
+            policy.alarm_range(price(1, 1), None)
+        );
+        assert_eq!(
+            (price(2, 5), Some(price(1, 3))),
+            policy.alarm_range(price(2, 5), Some(price(1, 3)))
+        );
+        assert_eq!(
+            (price(1, 1), Some(price(1, 5))),
+            Policy::default().alarm_range(price(1, 1), Some(price(1, 5)))
+        );
+    }
+}
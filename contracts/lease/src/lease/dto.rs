@@ -1,11 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-use finance::liability::Liability;
+use finance::{coin::Amount, liability::Liability};
 use oracle::stub::OracleRef;
 use sdk::cosmwasm_std::Addr;
 use timealarms::stub::TimeAlarmsRef;
 
-use crate::{api::LeaseCoin, loan::LoanDTO};
+use crate::{
+    api::{ClosePolicy, LeaseCoin},
+    loan::LoanDTO,
+};
 
 #[derive(Serialize, Deserialize, Clone)]
 #[cfg_attr(test, derive(Debug))]
@@ -14,29 +17,24 @@ pub struct LeaseDTO {
     pub(crate) customer: Addr,
     pub(crate) amount: LeaseCoin,
     pub(crate) liability: Liability,
+    /// The minimum liquidation amount in LPN
+    #[serde(default = "legacy_min_liquidation")]
+    pub(crate) min_liquidation: Amount,
+    /// The minimum asset amount in LPN to remain after a partial liquidation
+    #[serde(default = "legacy_min_asset")]
+    pub(crate) min_asset: Amount,
+    #[serde(default)]
+    pub(crate) close_policy: ClosePolicy,
     pub(crate) loan: LoanDTO,
     pub(crate) time_alarms: TimeAlarmsRef,
     pub(crate) oracle: OracleRef,
 }
 
-impl LeaseDTO {
-    pub(crate) fn new(
-        addr: Addr,
-        customer: Addr,
-        amount: LeaseCoin,
-        liability: Liability,
-        loan: LoanDTO,
-        time_alarms: TimeAlarmsRef,
-        oracle: OracleRef,
-    ) -> Self {
-        Self {
-            addr,
-            customer,
-            amount,
-            liability,
-            loan,
-            time_alarms,
-            oracle,
-        }
-    }
+// The amounts the leases opened before they became configurable have been working with
+const fn legacy_min_liquidation() -> Amount {
+    10_000
+}
+
+const fn legacy_min_asset() -> Amount {
+    15_000_000
 }
@@ -3,7 +3,8 @@ use serde::Serialize;
 use currency::Currency;
 use finance::{
     coin::Coin,
-    liability::{self, Status},
+    liability::{self, Level, Status},
+    percent::Percent,
     price::{self, Price},
     zero::Zero,
 };
@@ -13,7 +14,7 @@ use sdk::cosmwasm_std::Timestamp;
 
 use crate::{error::ContractResult, loan::LiabilityStatus};
 
-use super::Lease;
+use super::{Lease, LiabilityState, LiquidationPrices};
 
 impl<Lpn, Asset, Lpp, Oracle> Lease<Lpn, Asset, Lpp, Oracle>
 where
@@ -22,11 +23,27 @@ where
     Oracle: OracleTrait<Lpn>,
     Asset: Currency + Serialize,
 {
-    const MIN_LIQUIDATION_AMOUNT: Coin<Lpn> = Coin::new(10_000); // $0.01 TODO issue #40
-    const MIN_ASSET_AMOUNT_BEFORE_LIQUIDATION: Coin<Lpn> = Coin::new(15_000_000); // TODO issue #50
+    /// Evaluate the liability of the lease
+    ///
+    /// The liquidation amounts include the `liquidation_penalty` share of the proceeds that
+    /// does not go to repay the loan.
+    pub(crate) fn liquidation_status(
+        &self,
+        now: Timestamp,
+        liquidation_penalty: Percent,
+    ) -> ContractResult<Status<Asset>> {
+        self.price_of_lease_currency()
+            .map(|price| self.liquidation_status_at(price, now, liquidation_penalty))
+    }
 
-    pub(crate) fn liquidation_status(&self, now: Timestamp) -> ContractResult<Status<Asset>> {
-        let price_in_asset = self.price_of_lease_currency()?.inv();
+    /// Evaluate the liability of the lease as if the lease currency were at the given price
+    pub(super) fn liquidation_status_at(
+        &self,
+        price: Price<Asset, Lpn>,
+        now: Timestamp,
+        liquidation_penalty: Percent,
+    ) -> Status<Asset> {
+        let price_in_asset = price.inv();
 
         let LiabilityStatus {
             total: total_due,
@@ -39,20 +56,50 @@ where
             Coin::ZERO
         };
 
-        let status = liability::check_liability(
+        // a liability exceeding the lease amount leads to a full liquidation anyway
+        let total_due_in_asset = price::total(total_due, price_in_asset).min(self.amount);
+        let overdue_in_asset = price::total(overdue, price_in_asset).min(total_due_in_asset);
+
+        let status = liability::check_liability_with_penalty(
             &self.liability,
             self.amount,
-            price::total(total_due, price_in_asset),
-            price::total(overdue, price_in_asset),
-            price::total(Self::MIN_LIQUIDATION_AMOUNT, price_in_asset),
-            price::total(Self::MIN_ASSET_AMOUNT_BEFORE_LIQUIDATION, price_in_asset),
+            total_due_in_asset,
+            overdue_in_asset,
+            liquidation_penalty,
+            price::total(self.min_liquidation, price_in_asset),
+            price::total(self.min_asset, price_in_asset),
         );
         #[cfg(debug_assertion)]
         debug_assert!(status.amount() <= self.amount());
-        Ok(status)
+        status
+    }
+
+    pub(super) fn liability_state(
+        &self,
+        price: Price<Asset, Lpn>,
+        now: Timestamp,
+    ) -> LiabilityState<Asset, Lpn> {
+        let total_due = self.loan.liability_status(now).total;
+        // the zone does not depend on the penalty, it increases only the amounts to liquidate
+        let zone = match self.liquidation_status_at(price, now, Percent::ZERO) {
+            Status::No(zone) => Some(zone),
+            Status::NoDebt | Status::Liquidation(_) => None,
+        };
+        let price_at = |level| self.price_alarm_at_level(total_due, level);
+
+        LiabilityState {
+            ltv: Percent::from_ratio(price::total(total_due, price.inv()), self.amount),
+            zone,
+            liquidation_prices: LiquidationPrices {
+                first_liq_warn: price_at(Level::First(self.liability.first_liq_warn())),
+                second_liq_warn: price_at(Level::Second(self.liability.second_liq_warn())),
+                third_liq_warn: price_at(Level::Third(self.liability.third_liq_warn())),
+                max: price_at(Level::Max(self.liability.max())),
+            },
+        }
     }
 
-    fn price_of_lease_currency(&self) -> ContractResult<Price<Asset, Lpn>> {
+    pub(super) fn price_of_lease_currency(&self) -> ContractResult<Price<Asset, Lpn>> {
         Ok(self.oracle.price_of::<Asset>()?)
     }
 }
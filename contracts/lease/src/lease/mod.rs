@@ -1,10 +1,17 @@
 use serde::Serialize;
 
 use currency::{self, Currency};
-use finance::{coin::Coin, liability::Liability};
+use finance::{
+    coin::Coin,
+    duration::Duration,
+    fraction::Fraction,
+    liability::Liability,
+    percent::{bound::BoundToHundredPercent, Percent},
+    price,
+};
 use lpp::stub::loan::LppLoan as LppLoanTrait;
 use oracle::stub::Oracle as OracleTrait;
-use platform::batch::Batch;
+use platform::{bank::FixedAddressSender, batch::Batch};
 use profit::stub::ProfitRef;
 use sdk::cosmwasm_std::{Addr, Timestamp};
 use timealarms::stub::TimeAlarmsRef;
@@ -14,29 +21,51 @@ use crate::{
     loan::Loan,
 };
 
+pub(crate) use self::close_policy::{ClosePolicy, Trigger as CloseTrigger};
 pub(super) use self::{
-    dto::LeaseDTO, paid::Lease as LeasePaid, repay::FullRepayReceipt, state::State,
+    dto::LeaseDTO,
+    paid::Lease as LeasePaid,
+    penalty::{Penalty, PenaltyDTO},
+    repay::FullRepayReceipt,
+    simulation::Scenario,
+    state::{LiabilityState, LiquidationPrices, State},
 };
 
 mod alarm;
+pub(crate) mod auction;
+mod close_policy;
 mod dto;
 mod liquidation;
 mod paid;
+mod penalty;
 mod repay;
+mod simulation;
 mod state;
 pub(crate) mod with_lease;
 pub(crate) mod with_lease_deps;
 pub(crate) mod with_lease_paid;
 
+/// How long ahead the interest is covered by the proceeds of a full close
+///
+/// It spans the sale, with its requotes, and the transfer of the proceeds back to the lease.
+const FULL_CLOSE_DUE_AHEAD: Duration = Duration::from_days(1);
+
 // TODO look into reducing the type parameters to Lpn and Asset only!
 // the others could be provided on demand when certain operation is being performed
 // then review the methods that take `&mut self` whether could be transformed into `&self`
 // and those that take `self` into `&mut self` or `&self`
-pub struct Lease<Lpn, Asset, Lpp, Oracle> {
+pub struct Lease<Lpn, Asset, Lpp, Oracle>
+where
+    Lpn: Currency,
+    Asset: Currency,
+{
     addr: Addr,
     customer: Addr,
     amount: Coin<Asset>,
     liability: Liability,
+    min_liquidation: Coin<Lpn>,
+    min_asset: Coin<Lpn>,
+    close_policy: ClosePolicy<Asset, Lpn>,
     loan: Loan<Lpn, Lpp>,
     oracle: Oracle,
 }
@@ -59,6 +88,7 @@ where
         customer: Addr,
         amount: Coin<Asset>,
         liability: Liability,
+        (min_liquidation, min_asset): (Coin<Lpn>, Coin<Lpn>),
         loan: Loan<Lpn, LppLoan>,
         oracle: Oracle,
     ) -> Self {
@@ -71,6 +101,9 @@ where
             customer,
             amount,
             liability,
+            min_liquidation,
+            min_asset,
+            close_policy: Default::default(),
             loan,
             oracle,
         }
@@ -80,11 +113,17 @@ where
         let amount = dto.amount.try_into().expect(
             "The DTO -> Lease conversion should have resulted in Asset == dto.amount.symbol()",
         );
+        let close_policy = dto.close_policy.try_into().expect(
+            "The DTO -> Lease conversion should have resulted in a close policy of Asset to Lpn prices",
+        );
         Self {
             addr: dto.addr,
             customer: dto.customer,
             amount,
             liability: dto.liability,
+            min_liquidation: Coin::new(dto.min_liquidation),
+            min_asset: Coin::new(dto.min_asset),
+            close_policy,
             loan: Loan::from_dto(dto.loan, lpp_loan),
             oracle,
         }
@@ -101,7 +140,70 @@ where
             previous_interest_due: loan.previous_interest_due,
             current_margin_due: loan.current_margin_interest_due,
             current_interest_due: loan.current_interest_due,
+            // the state remains available while the oracle does not provide a price
+            liability: self
+                .price_of_lease_currency()
+                .ok()
+                .map(|price| self.liability_state(price, now)),
             validity: now,
+            close_policy: self.close_policy,
+        }
+    }
+
+    pub(crate) fn add_collateral(&mut self, collateral: Coin<Asset>) {
+        debug_assert!(!collateral.is_zero());
+        self.amount += collateral;
+    }
+
+    /// Re-quote a floating rate loan at the start of each due period
+    ///
+    /// Return whether a re-quote has been requested from the LPP.
+    pub(crate) fn requote(&mut self, now: Timestamp) -> bool {
+        self.loan.requote(now)
+    }
+
+    /// Borrow more against the lease
+    ///
+    /// The LTV after the increase, with the net proceeds added to the lease amount,
+    /// should not exceed the initial one. Return the net proceeds to be swapped into
+    /// the lease currency.
+    pub(crate) fn increase_loan<Profit>(
+        &mut self,
+        amount: Coin<Lpn>,
+        now: Timestamp,
+        profit: &mut Profit,
+    ) -> ContractResult<Coin<Lpn>>
+    where
+        Profit: FixedAddressSender,
+    {
+        let proceeds = self.loan.increase(amount, now, profit)?;
+
+        let total_due = self.loan.liability_status(now).total;
+        let lease_lpn = price::total(self.amount, self.price_of_lease_currency()?);
+        let ltv = Percent::from_ratio(total_due, lease_lpn + proceeds);
+        if ltv > self.liability.initial_percent() {
+            return Err(ContractError::LoanIncreaseAboveInitialLtv(ltv));
+        }
+        Ok(proceeds)
+    }
+
+    /// Check that selling the whole lease amount would repay the loan
+    ///
+    /// The sale is expected to yield no less than the lease amount at the current price
+    /// reduced by the maximum slippage. Return the minimum proceeds the sale should yield,
+    /// the total due by the time the proceeds get back to the lease.
+    pub(crate) fn check_close_full(
+        &self,
+        max_slippage: BoundToHundredPercent,
+        now: Timestamp,
+    ) -> ContractResult<Coin<Lpn>> {
+        let total_due = self.loan.liability_status(now + FULL_CLOSE_DUE_AHEAD).total;
+        let lease_lpn = price::total(self.amount, self.price_of_lease_currency()?);
+        let min_proceeds = lease_lpn - max_slippage.percent().of(lease_lpn);
+        if min_proceeds < total_due {
+            Err(ContractError::InsufficientPositionClose())
+        } else {
+            Ok(total_due)
         }
     }
 }
@@ -122,15 +224,18 @@ where
         let (loan_dto, loan_batch) = self.loan.try_into_dto(profit)?;
 
         Ok(IntoDTOResult {
-            lease: LeaseDTO::new(
-                self.addr,
-                self.customer,
-                self.amount.into(),
-                self.liability,
-                loan_dto,
+            lease: LeaseDTO {
+                addr: self.addr,
+                customer: self.customer,
+                amount: self.amount.into(),
+                liability: self.liability,
+                min_liquidation: self.min_liquidation.into(),
+                min_asset: self.min_asset.into(),
+                close_policy: self.close_policy.into(),
+                loan: loan_dto,
                 time_alarms,
-                self.oracle.into(),
-            ),
+                oracle: self.oracle.into(),
+            },
             batch: loan_batch,
         })
     }
@@ -146,7 +251,12 @@ mod tests {
 
     use ::currency::{lease::Atom, lpn::Usdc, Currency};
     use finance::{
-        coin::Coin, duration::Duration, liability::Liability, percent::Percent, price::Price,
+        coin::Coin,
+        duration::Duration,
+        fraction::Fraction,
+        liability::{Liability, Zone},
+        percent::{bound::BoundToHundredPercent, Percent},
+        price::{total_of, Price},
     };
     use lpp::{
         error::{ContractError as LppError, Result as LppResult},
@@ -156,23 +266,30 @@ mod tests {
     };
     use oracle::stub::{Oracle, OracleRef};
     use platform::batch::Batch;
-    use profit::stub::Profit;
+    use profit::stub::{Profit, ProfitRef};
     use sdk::cosmwasm_std::{Addr, Timestamp};
 
-    use crate::{api::InterestPaymentSpec, loan::Loan};
+    use crate::{
+        api::{InterestPaymentSpec, RateType},
+        error::ContractError,
+        loan::Loan,
+    };
 
-    use super::{Lease, State};
+    use super::{Lease, LiabilityState, LiquidationPrices, State};
 
     const CUSTOMER: &str = "customer";
     const LEASE_ADDR: &str = "lease_addr";
     const ORACLE_ADDR: &str = "oracle_addr";
+    const PROFIT_ADDR: &str = "profit_addr";
     pub const MARGIN_INTEREST_RATE: Percent = Percent::from_permille(23);
     pub const LEASE_START: Timestamp = Timestamp::from_nanos(100);
     pub const LEASE_STATE_AT: Timestamp = Timestamp::from_nanos(200);
     pub const DUE_PERIOD: Duration = Duration::from_days(100);
     pub const GRACE_PERIOD: Duration = Duration::from_days(10);
     pub const RECALC_TIME: Duration = Duration::from_hours(24);
-    type TestLpn = Usdc;
+    pub const MIN_LIQUIDATION: Coin<TestLpn> = Coin::new(10_000);
+    pub const MIN_ASSET: Coin<TestLpn> = Coin::new(15_000_000);
+    pub type TestLpn = Usdc;
     pub type TestCurrency = Atom;
     pub type TestLease = Lease<TestLpn, TestCurrency, LppLoanLocal<TestLpn>, OracleLocalStub>;
 
@@ -220,9 +337,16 @@ mod tests {
             self.loan.repay(by, repayment)
         }
 
+        fn increase(&mut self, by: Timestamp, amount: Coin<Lpn>) -> Coin<Lpn> {
+            let annual_interest_rate = self.loan.annual_interest_rate;
+            self.loan.increase(by, amount, annual_interest_rate)
+        }
+
         fn annual_interest_rate(&self) -> Percent {
             self.loan.annual_interest_rate
         }
+
+        fn requote(&mut self) {}
     }
 
     impl<Lpn> TryFrom<LppLoanLocal<Lpn>> for LppBatch<LppRef>
@@ -303,7 +427,13 @@ mod tests {
         let oracle: OracleLocalStub = Addr::unchecked(ORACLE_ADDR).into();
 
         let loan = loan.into();
-        let loan = Loan::new(LEASE_START, loan, MARGIN_INTEREST_RATE, interest_spec);
+        let loan = Loan::new(
+            LEASE_START,
+            loan,
+            MARGIN_INTEREST_RATE,
+            interest_spec,
+            RateType::Fixed,
+        );
         Lease::new(
             lease,
             Addr::unchecked(CUSTOMER),
@@ -317,6 +447,7 @@ mod tests {
                 Percent::from_percent(2),
                 RECALC_TIME,
             ),
+            (MIN_LIQUIDATION, MIN_ASSET),
             loan,
             oracle,
         )
@@ -350,6 +481,9 @@ mod tests {
 
         let lease = open_lease(lease_amount, loan.clone());
 
+        let liability = lease.liability;
+        let price_at = |ltv: Percent| total_of(ltv.of(lease_amount)).is(loan.principal_due);
+
         let res = request_state(lease);
         let exp = State {
             amount: lease_amount,
@@ -360,9 +494,105 @@ mod tests {
             previous_interest_due: lpn_coin(0),
             current_margin_due: lpn_coin(0),
             current_interest_due: lpn_coin(0),
+            liability: Some(LiabilityState {
+                ltv: Percent::from_percent(30),
+                zone: Some(Zone::no_warnings(liability.first_liq_warn())),
+                liquidation_prices: LiquidationPrices {
+                    first_liq_warn: price_at(liability.first_liq_warn()),
+                    second_liq_warn: price_at(liability.second_liq_warn()),
+                    third_liq_warn: price_at(liability.third_liq_warn()),
+                    max: price_at(liability.max()),
+                },
+            }),
             validity: LEASE_STATE_AT,
+            close_policy: Default::default(),
         };
 
         assert_eq!(exp, res);
     }
+
+    #[test]
+    fn state_after_add_collateral() {
+        let lease_amount = coin(1000);
+        let collateral = coin(250);
+        let loan = LoanResponse {
+            principal_due: lpn_coin(300),
+            annual_interest_rate: Percent::from_permille(50),
+            interest_paid: Timestamp::from_nanos(0),
+        };
+
+        let mut lease = open_lease(lease_amount, loan.clone());
+        lease.add_collateral(collateral);
+
+        let res = request_state(lease);
+        assert_eq!(lease_amount + collateral, res.amount);
+        assert_eq!(loan.principal_due, res.principal_due);
+    }
+
+    #[test]
+    fn increase_loan() {
+        let lease_amount = coin(1000);
+        let loan = LoanResponse {
+            principal_due: lpn_coin(300),
+            annual_interest_rate: Percent::from_permille(50),
+            interest_paid: LEASE_START,
+        };
+        let mut profit = ProfitRef::unchecked(PROFIT_ADDR).as_stub();
+
+        let mut lease = open_lease(lease_amount, loan.clone());
+        assert_eq!(
+            Ok(lpn_coin(500)),
+            lease.increase_loan(lpn_coin(500), LEASE_STATE_AT, &mut profit)
+        );
+
+        let res = request_state(lease);
+        assert_eq!(lease_amount, res.amount);
+        assert_eq!(loan.principal_due + lpn_coin(500), res.principal_due);
+    }
+
+    #[test]
+    fn check_close_full() {
+        let max_slippage = BoundToHundredPercent::try_from_percent(Percent::from_percent(10))
+            .expect("a valid slippage");
+        let loan = |principal_due| LoanResponse {
+            principal_due,
+            annual_interest_rate: Percent::from_permille(50),
+            interest_paid: LEASE_START,
+        };
+
+        let lease = open_lease(coin(1000), loan(lpn_coin(800)));
+        let total_due = lease
+            .loan
+            .liability_status(LEASE_STATE_AT + super::FULL_CLOSE_DUE_AHEAD)
+            .total;
+        assert!(total_due > lpn_coin(800));
+        assert_eq!(
+            Ok(total_due),
+            lease.check_close_full(max_slippage, LEASE_STATE_AT)
+        );
+
+        let lease = open_lease(coin(1000), loan(lpn_coin(950)));
+        assert_eq!(
+            Err(ContractError::InsufficientPositionClose()),
+            lease.check_close_full(max_slippage, LEASE_STATE_AT)
+        );
+    }
+
+    #[test]
+    fn increase_loan_above_initial_ltv() {
+        let loan = LoanResponse {
+            principal_due: lpn_coin(300),
+            annual_interest_rate: Percent::from_permille(50),
+            interest_paid: LEASE_START,
+        };
+        let mut profit = ProfitRef::unchecked(PROFIT_ADDR).as_stub();
+
+        let mut lease = open_lease(coin(1000), loan);
+        assert_eq!(
+            Err(ContractError::LoanIncreaseAboveInitialLtv(
+                Percent::from_permille(766)
+            )),
+            lease.increase_loan(lpn_coin(2000), LEASE_STATE_AT, &mut profit)
+        );
+    }
 }
@@ -45,6 +45,26 @@ where
 
         Ok(lease_account.into())
     }
+
+    /// Send the LPN surplus to the customer once the lease amount has been sold
+    ///
+    /// The sale proceeds, still on the lease account, are not part of the surplus.
+    pub(crate) fn close_sold<B>(
+        self,
+        mut lease_account: B,
+        proceeds: Coin<Lpn>,
+    ) -> ContractResult<Batch>
+    where
+        B: BankAccount,
+    {
+        let surplus = lease_account.balance::<Lpn>()?.saturating_sub(proceeds);
+
+        if !surplus.is_zero() {
+            lease_account.send(surplus, &self.customer);
+        }
+
+        Ok(lease_account.into())
+    }
 }
 
 #[cfg(test)]
@@ -152,4 +172,26 @@ mod tests {
             )
         });
     }
+
+    #[test]
+    fn close_sold_no_surplus() {
+        let proceeds = 30.into();
+        let lease: Lease<TestAsset, TestLpn> = create_lease(10.into());
+        let lease_account = BankStub::new(MockBankView::new(Coin::ZERO, proceeds));
+        let res = lease.close_sold(lease_account, proceeds).unwrap();
+        assert_eq!(res, Batch::default());
+    }
+
+    #[test]
+    fn close_sold_with_surplus() {
+        let proceeds = 30.into();
+        let surplus_amount = 2.into();
+        let lease: Lease<TestAsset, TestLpn> = create_lease(10.into());
+        let lease_account = BankStub::new(MockBankView::new(Coin::ZERO, proceeds + surplus_amount));
+        let res = lease.close_sold(lease_account, proceeds).unwrap();
+        assert_eq!(
+            res,
+            bank::bank_send(Batch::default(), CUSTOMER, surplus_amount)
+        );
+    }
 }
@@ -0,0 +1,213 @@
+use serde::Serialize;
+
+use currency::Currency;
+use finance::{coin::Coin, fraction::Fraction, zero::Zero};
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::Oracle as OracleTrait;
+use platform::{
+    bank::{FixedAddressSender, LazySenderStub},
+    batch::Batch,
+};
+use sdk::cosmwasm_std::{Addr, Timestamp};
+
+use crate::api::{LiquidationFee, LpnCoin};
+
+use super::Lease;
+
+/// The penalty charged on the proceeds of a liquidation
+pub(crate) struct Penalty<Lpn>
+where
+    Lpn: Currency,
+{
+    proceeds: Coin<Lpn>,
+    penalty: Coin<Lpn>,
+    keeper_bounty: Coin<Lpn>,
+    keeper: Option<Addr>,
+}
+
+pub(crate) struct PenaltyDTO {
+    pub penalty: LpnCoin,
+    pub keeper_bounty: LpnCoin,
+}
+
+impl<Lpn> Penalty<Lpn>
+where
+    Lpn: Currency,
+{
+    fn new(
+        proceeds: Coin<Lpn>,
+        penalty: Coin<Lpn>,
+        fee: &LiquidationFee,
+        keeper: Option<Addr>,
+    ) -> Self {
+        debug_assert!(penalty <= proceeds);
+        let keeper_bounty = keeper
+            .as_ref()
+            .map_or(Coin::ZERO, |_| fee.keeper_bounty.percent().of(penalty));
+        Self {
+            proceeds,
+            penalty,
+            keeper_bounty,
+            keeper,
+        }
+    }
+
+    /// The part of the proceeds left to repay the loan
+    pub(crate) fn payment(&self) -> Coin<Lpn> {
+        self.proceeds - self.penalty
+    }
+
+    /// Send the penalty, less the keeper bounty, to the Profit and the bounty to the keeper
+    pub(crate) fn charge<Profit>(self, profit: &mut Profit) -> (PenaltyDTO, Batch)
+    where
+        Profit: FixedAddressSender,
+    {
+        profit.send(self.penalty - self.keeper_bounty);
+
+        let bounty_messages = self.keeper.map_or_else(Batch::default, |keeper| {
+            let mut keeper = LazySenderStub::new(keeper);
+            keeper.send(self.keeper_bounty);
+            keeper.into()
+        });
+
+        (
+            PenaltyDTO {
+                penalty: self.penalty.into(),
+                keeper_bounty: self.keeper_bounty.into(),
+            },
+            bounty_messages,
+        )
+    }
+}
+
+impl<Lpn, Asset, Lpp, Oracle> Lease<Lpn, Asset, Lpp, Oracle>
+where
+    Lpn: Currency + Serialize,
+    Lpp: LppLoanTrait<Lpn>,
+    Oracle: OracleTrait<Lpn>,
+    Asset: Currency + Serialize,
+{
+    pub(crate) fn partial_liquidation_penalty(
+        &self,
+        proceeds: Coin<Lpn>,
+        fee: &LiquidationFee,
+        keeper: Option<Addr>,
+    ) -> Penalty<Lpn> {
+        Penalty::new(proceeds, fee.penalty.percent().of(proceeds), fee, keeper)
+    }
+
+    /// The penalty of a full liquidation does not cut into the amount due
+    pub(crate) fn full_liquidation_penalty(
+        &self,
+        proceeds: Coin<Lpn>,
+        fee: &LiquidationFee,
+        keeper: Option<Addr>,
+        now: Timestamp,
+    ) -> Penalty<Lpn> {
+        let surplus = proceeds.saturating_sub(self.loan.liability_status(now).total);
+        Penalty::new(
+            proceeds,
+            fee.penalty.percent().of(proceeds).min(surplus),
+            fee,
+            keeper,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use finance::{
+        coin::Coin,
+        percent::{bound::BoundToHundredPercent, Percent, Units},
+        zero::Zero,
+    };
+    use platform::{
+        bank::{FixedAddressSender, LazySenderStub},
+        batch::Batch,
+    };
+    use sdk::cosmwasm_std::Addr;
+
+    use crate::{
+        api::LiquidationFee,
+        lease::tests::{self, TestLpn, LEASE_START},
+    };
+
+    const PROFIT: &str = "profit";
+    const KEEPER: &str = "keeper";
+
+    #[test]
+    fn partial_with_keeper() {
+        let lease = tests::open_lease(tests::coin(1000), tests::loan());
+        let penalty = lease.partial_liquidation_penalty(
+            tests::lpn_coin(200),
+            &fee(50, 200),
+            Some(Addr::unchecked(KEEPER)),
+        );
+        assert_eq!(tests::lpn_coin(190), penalty.payment());
+
+        let mut profit = LazySenderStub::new(Addr::unchecked(PROFIT));
+        let (charged, bounty_messages) = penalty.charge(&mut profit);
+        assert_eq!(tests::lpn_coin(10), charged.penalty.try_into().unwrap());
+        assert_eq!(
+            tests::lpn_coin(2),
+            charged.keeper_bounty.try_into().unwrap()
+        );
+        assert_eq!(sent(PROFIT, tests::lpn_coin(8)), profit.into());
+        assert_eq!(sent(KEEPER, tests::lpn_coin(2)), bounty_messages);
+    }
+
+    #[test]
+    fn partial_no_keeper() {
+        let lease = tests::open_lease(tests::coin(1000), tests::loan());
+        let penalty = lease.partial_liquidation_penalty(tests::lpn_coin(200), &fee(50, 200), None);
+        assert_eq!(tests::lpn_coin(190), penalty.payment());
+
+        let mut profit = LazySenderStub::new(Addr::unchecked(PROFIT));
+        let (charged, bounty_messages) = penalty.charge(&mut profit);
+        assert_eq!(tests::lpn_coin(10), charged.penalty.try_into().unwrap());
+        assert_eq!(
+            Coin::<TestLpn>::ZERO,
+            charged.keeper_bounty.try_into().unwrap()
+        );
+        assert_eq!(sent(PROFIT, tests::lpn_coin(10)), profit.into());
+        assert_eq!(Batch::default(), bounty_messages);
+    }
+
+    #[test]
+    fn full_capped_by_surplus() {
+        let lease = tests::open_lease(tests::coin(1000), tests::loan());
+        let total_due = lease.loan.liability_status(LEASE_START).total;
+
+        let penalty = lease.full_liquidation_penalty(
+            total_due + tests::lpn_coin(3),
+            &fee(100, 0),
+            None,
+            LEASE_START,
+        );
+        assert_eq!(total_due, penalty.payment());
+
+        let penalty = lease.full_liquidation_penalty(
+            total_due - tests::lpn_coin(3),
+            &fee(100, 0),
+            None,
+            LEASE_START,
+        );
+        assert_eq!(total_due - tests::lpn_coin(3), penalty.payment());
+    }
+
+    fn fee(penalty: Units, keeper_bounty: Units) -> LiquidationFee {
+        let bound = |permille| {
+            BoundToHundredPercent::try_from_percent(Percent::from_permille(permille)).unwrap()
+        };
+        LiquidationFee {
+            penalty: bound(penalty),
+            keeper_bounty: bound(keeper_bounty),
+        }
+    }
+
+    fn sent(to: &str, amount: Coin<TestLpn>) -> Batch {
+        let mut sender = LazySenderStub::new(Addr::unchecked(to));
+        sender.send(amount);
+        sender.into()
+    }
+}
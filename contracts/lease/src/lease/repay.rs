@@ -1,4 +1,7 @@
-use platform::{bank::FixedAddressSender, batch::Batch};
+use platform::{
+    bank::{FixedAddressSender, LazySenderStub},
+    batch::Batch,
+};
 use serde::Serialize;
 
 use currency::Currency;
@@ -100,4 +103,31 @@ where
             FullRepayReceipt::new(receipt, lease_messages.merge(profit.into()))
         })
     }
+
+    /// Repay the loan with the proceeds of selling the whole lease amount
+    ///
+    /// The change goes to the customer. The proceeds may fall short of the total due only
+    /// if the price has dropped during the sale. They repay the loan partially then,
+    /// and the returned receipt is not a closing one.
+    pub(crate) fn close_full<Profit>(
+        mut self,
+        payment: Coin<Lpn>,
+        now: Timestamp,
+        mut profit: Profit,
+    ) -> ContractResult<(RepayReceipt<Lpn>, Batch)>
+    where
+        Profit: FixedAddressSender,
+    {
+        let receipt = self.loan.repay(payment, now, &mut profit)?;
+
+        let mut customer = LazySenderStub::new(self.customer.clone());
+        customer.send(receipt.change());
+
+        self.try_into_messages().map(|lease_messages| {
+            (
+                receipt,
+                lease_messages.merge(profit.into()).merge(customer.into()),
+            )
+        })
+    }
 }
@@ -0,0 +1,212 @@
+use serde::Serialize;
+
+use currency::Currency;
+use finance::{
+    coin::Coin,
+    liability::{Liquidation, Status, Zone},
+    percent::Percent,
+    price::{self, Price},
+};
+use lpp::stub::loan::LppLoan as LppLoanTrait;
+use oracle::stub::Oracle as OracleTrait;
+use sdk::cosmwasm_std::Timestamp;
+
+use crate::{
+    error::{ContractError, ContractResult},
+    loan::LiabilityStatus,
+};
+
+use super::Lease;
+
+/// The liability of a lease under a hypothetical lease currency price
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct Scenario<Asset>
+where
+    Asset: Currency,
+{
+    pub price_change: Percent,
+    pub ltv: Percent,
+    pub zone: Option<Zone>,
+    pub liquidation: Option<Coin<Asset>>,
+}
+
+impl<Lpn, Asset, Lpp, Oracle> Lease<Lpn, Asset, Lpp, Oracle>
+where
+    Lpn: Currency + Serialize,
+    Lpp: LppLoanTrait<Lpn>,
+    Oracle: OracleTrait<Lpn>,
+    Asset: Currency + Serialize,
+{
+    /// Evaluate the liability of the lease at `at` for each of the price changes
+    ///
+    /// A price change is the hypothetical lease currency price expressed as a percent
+    /// of the current one, for example, 80% stands for a 20% price drop.
+    pub(crate) fn simulate(
+        &self,
+        price_changes: &[Percent],
+        at: Timestamp,
+        liquidation_penalty: Percent,
+    ) -> ContractResult<Vec<Scenario<Asset>>> {
+        let price = self.price_of_lease_currency()?;
+        let LiabilityStatus { total, .. } = self.loan.liability_status(at);
+
+        price_changes
+            .iter()
+            .map(|&price_change| {
+                changed_price(price, price_change).map(|price| {
+                    let (zone, liquidation) =
+                        match self.liquidation_status_at(price, at, liquidation_penalty) {
+                            Status::NoDebt => (None, None),
+                            Status::No(zone) => (Some(zone), None),
+                            Status::Liquidation(Liquidation::Partial { amount, cause: _ }) => {
+                                (None, Some(amount))
+                            }
+                            Status::Liquidation(Liquidation::Full(_)) => (None, Some(self.amount)),
+                        };
+                    Scenario {
+                        price_change,
+                        ltv: Percent::from_ratio(price::total(total, price.inv()), self.amount),
+                        zone,
+                        liquidation,
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+fn changed_price<Asset, Lpn>(
+    price: Price<Asset, Lpn>,
+    change: Percent,
+) -> ContractResult<Price<Asset, Lpn>>
+where
+    Asset: Currency,
+    Lpn: Currency,
+{
+    if change.is_zero() {
+        Err(ContractError::InvalidPriceChange(change))
+    } else {
+        Ok(price
+            * price::total_of(Coin::<Lpn>::new(Percent::HUNDRED.units().into()))
+                .is(Coin::new(change.units().into())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use finance::{liability::Zone, percent::Percent};
+    use lpp::msg::LoanResponse;
+
+    use crate::{
+        error::ContractError,
+        lease::tests::{coin, lpn_coin, open_lease, TestLpn, LEASE_START},
+    };
+
+    use super::Scenario;
+
+    #[test]
+    fn no_debt() {
+        let lease_amount = coin(1_000_000_000);
+        let lease = open_lease(lease_amount, loan(0));
+
+        assert_eq!(
+            vec![Scenario {
+                price_change: Percent::from_percent(50),
+                ltv: Percent::ZERO,
+                zone: None,
+                liquidation: None,
+            }],
+            lease
+                .simulate(&[Percent::from_percent(50)], LEASE_START, Percent::ZERO)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn warning_zones() {
+        let lease_amount = coin(1_000_000_000);
+        let lease = open_lease(lease_amount, loan(600_000_000));
+
+        let scenarios = lease
+            .simulate(
+                &[Percent::HUNDRED, Percent::from_percent(80)],
+                LEASE_START,
+                Percent::ZERO,
+            )
+            .unwrap();
+        assert_eq!(
+            vec![
+                Scenario {
+                    price_change: Percent::HUNDRED,
+                    ltv: Percent::from_percent(60),
+                    zone: Some(Zone::no_warnings(lease.liability.first_liq_warn())),
+                    liquidation: None,
+                },
+                Scenario {
+                    price_change: Percent::from_percent(80),
+                    ltv: Percent::from_percent(75),
+                    zone: Some(Zone::second(
+                        lease.liability.second_liq_warn(),
+                        lease.liability.third_liq_warn()
+                    )),
+                    liquidation: None,
+                }
+            ],
+            scenarios
+        );
+    }
+
+    #[test]
+    fn liquidations() {
+        let lease_amount = coin(1_000_000_000);
+        let lease = open_lease(lease_amount, loan(600_000_000));
+
+        let scenarios = lease
+            .simulate(
+                &[Percent::from_percent(75), Percent::from_percent(50)],
+                LEASE_START,
+                Percent::ZERO,
+            )
+            .unwrap();
+
+        assert_eq!(Percent::from_percent(80), scenarios[0].ltv);
+        assert_eq!(None, scenarios[0].zone);
+        let partial = scenarios[0].liquidation.unwrap();
+        assert!(coin(0) < partial && partial < lease_amount);
+
+        assert_eq!(Percent::from_percent(120), scenarios[1].ltv);
+        assert_eq!(None, scenarios[1].zone);
+        assert_eq!(Some(lease_amount), scenarios[1].liquidation);
+
+        let penalized = lease
+            .simulate(
+                &[Percent::from_percent(75)],
+                LEASE_START,
+                Percent::from_percent(5),
+            )
+            .unwrap();
+        assert!(partial < penalized[0].liquidation.unwrap());
+    }
+
+    #[test]
+    fn zero_price_change() {
+        let lease = open_lease(coin(1_000_000_000), loan(600_000_000));
+
+        assert_eq!(
+            Err(ContractError::InvalidPriceChange(Percent::ZERO)),
+            lease.simulate(
+                &[Percent::HUNDRED, Percent::ZERO],
+                LEASE_START,
+                Percent::ZERO
+            )
+        );
+    }
+
+    fn loan(principal: u128) -> LoanResponse<TestLpn> {
+        LoanResponse {
+            principal_due: lpn_coin(principal),
+            annual_interest_rate: Percent::from_percent(10),
+            interest_paid: LEASE_START,
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use currency::Currency;
-use finance::{coin::Coin, percent::Percent};
+use finance::{coin::Coin, liability::Zone, percent::Percent, price::Price};
 use sdk::cosmwasm_std::Timestamp;
 
-#[derive(Debug, PartialEq, Eq)]
+use super::ClosePolicy;
+
+#[derive(PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
 pub struct State<Asset, Lpn>
 where
     Asset: Currency,
@@ -16,5 +19,34 @@ where
     pub previous_interest_due: Coin<Lpn>,
     pub current_margin_due: Coin<Lpn>,
     pub current_interest_due: Coin<Lpn>,
+    pub liability: Option<LiabilityState<Asset, Lpn>>,
     pub validity: Timestamp,
+    pub close_policy: ClosePolicy<Asset, Lpn>,
+}
+
+/// The liability of the lease at the current lease currency price
+#[derive(PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct LiabilityState<Asset, Lpn>
+where
+    Asset: Currency,
+    Lpn: Currency,
+{
+    pub ltv: Percent,
+    pub zone: Option<Zone>,
+    pub liquidation_prices: LiquidationPrices<Asset, Lpn>,
+}
+
+/// The lease currency prices at which the liquidation levels are reached
+#[derive(PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub struct LiquidationPrices<Asset, Lpn>
+where
+    Asset: Currency,
+    Lpn: Currency,
+{
+    pub first_liq_warn: Price<Asset, Lpn>,
+    pub second_liq_warn: Price<Asset, Lpn>,
+    pub third_liq_warn: Price<Asset, Lpn>,
+    pub max: Price<Asset, Lpn>,
 }
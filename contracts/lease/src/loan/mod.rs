@@ -19,7 +19,7 @@ use profit::stub::ProfitRef;
 use sdk::cosmwasm_std::Timestamp;
 
 use crate::{
-    api::InterestPaymentSpec,
+    api::{InterestPaymentSpec, RateType},
     error::{ContractError, ContractResult},
 };
 
@@ -36,6 +36,10 @@ pub(crate) struct LoanDTO {
     interest_payment_spec: InterestPaymentSpec,
     current_period: InterestPeriod<Units, Percent>,
     profit: ProfitRef,
+    #[serde(default)]
+    rate_type: RateType,
+    #[serde(default)]
+    rate_quoted_for: Timestamp,
 }
 
 impl LoanDTO {
@@ -44,12 +48,16 @@ impl LoanDTO {
         interest_payment_spec: InterestPaymentSpec,
         current_period: InterestPeriod<Units, Percent>,
         profit: ProfitRef,
+        rate_type: RateType,
+        rate_quoted_for: Timestamp,
     ) -> Self {
         Self {
             lpp,
             interest_payment_spec,
             current_period,
             profit,
+            rate_type,
+            rate_quoted_for,
         }
     }
 
@@ -71,6 +79,9 @@ pub struct Loan<Lpn, LppLoan> {
     lpp_loan: LppLoan,
     interest_payment_spec: InterestPaymentSpec,
     due_period: InterestPeriod<Units, Percent>,
+    rate_type: RateType,
+    // the start of the due period the floating LPP loan rate has been quoted for
+    rate_quoted_for: Timestamp,
 }
 
 impl<Lpn, LppLoan> Loan<Lpn, LppLoan>
@@ -87,6 +98,8 @@ where
                     self.interest_payment_spec,
                     self.due_period,
                     profit,
+                    self.rate_type,
+                    self.rate_quoted_for,
                 ),
                 lpp_batch.batch,
             )
@@ -112,6 +125,7 @@ where
         lpp_loan: LppLoan,
         annual_margin_interest: Percent,
         interest_payment_spec: InterestPaymentSpec,
+        rate_type: RateType,
     ) -> Self {
         let due_period = InterestPeriod::with_interest(annual_margin_interest).and_period(
             Period::from_length(start, interest_payment_spec.due_period()),
@@ -121,6 +135,8 @@ where
             lpp_loan,
             interest_payment_spec,
             due_period,
+            rate_type,
+            rate_quoted_for: start,
         }
     }
 
@@ -131,6 +147,8 @@ where
                 lpp_loan,
                 interest_payment_spec: dto.interest_payment_spec,
                 due_period: dto.current_period,
+                rate_type: dto.rate_type,
+                rate_quoted_for: dto.rate_quoted_for,
             }
         }
     }
@@ -147,6 +165,19 @@ where
         self.grace_period_end_impl(&current_period)
     }
 
+    /// Re-quote a floating LPP loan rate if a new due period has started by the given time
+    ///
+    /// The new rate applies to the loan interest not paid yet. Return whether a re-quote
+    /// has been requested.
+    pub(crate) fn requote(&mut self, now: Timestamp) -> bool {
+        self.requote_for(self.rate_period_at(now).start())
+    }
+
+    /// The time the next re-quote of a floating LPP loan rate is due at
+    pub(crate) fn requote_after(&self, now: Timestamp) -> Option<Timestamp> {
+        (self.rate_type == RateType::Floating).then(|| self.rate_period_at(now).till())
+    }
+
     /// Repay the loan interests and principal by the given timestamp.
     ///
     /// The time intervals are always open-ended!
@@ -191,6 +222,42 @@ where
         Ok(receipt)
     }
 
+    /// Increase the loan principal by the given timestamp.
+    ///
+    /// The loan interest due is settled by the LPP out of the 'amount' and the margin interest
+    /// due is paid out of the rest. Return the net proceeds, what remains after the interest
+    /// settlement.
+    pub(crate) fn increase<Profit>(
+        &mut self,
+        amount: Coin<Lpn>,
+        by: Timestamp,
+        profit: &mut Profit,
+    ) -> ContractResult<Coin<Lpn>>
+    where
+        Profit: FixedAddressSender,
+    {
+        self.debug_check_start_due_before(by, "before the 'increase-by' time");
+
+        if self.overdue_at(by) {
+            return Err(ContractError::LoanIncreaseOverdue());
+        }
+
+        let principal_due = self.lpp_loan.principal_due();
+        let interest_paid = self.lpp_loan.increase(by, amount);
+        if amount <= interest_paid {
+            return Err(ContractError::InvalidLoanIncrease(amount.into()));
+        }
+
+        let (margin_paid, proceeds) =
+            self.repay_margin_interest(principal_due, by, amount - interest_paid);
+        if proceeds.is_zero() {
+            return Err(ContractError::InvalidLoanIncrease(amount.into()));
+        }
+        profit.send(margin_paid);
+
+        Ok(proceeds)
+    }
+
     pub(crate) fn state(&self, now: Timestamp) -> State<Lpn> {
         self.debug_check_start_due_before(now, "in the past. Now is ");
 
@@ -327,6 +394,26 @@ where
             self.due_period.period(),
             &self.interest_payment_spec,
         ));
+
+        self.requote_for(self.due_period.start());
+    }
+
+    fn requote_for(&mut self, period_start: Timestamp) -> bool {
+        let requote = self.rate_type == RateType::Floating && self.rate_quoted_for < period_start;
+        if requote {
+            self.lpp_loan.requote();
+            self.rate_quoted_for = period_start;
+        }
+        requote
+    }
+
+    /// The due period the given time falls into, the end of the period being excluded
+    fn rate_period_at(&self, when: Timestamp) -> Period {
+        let mut period = self.due_period_before_payments();
+        while period.till() <= when {
+            period = next_due_period(period, &self.interest_payment_spec);
+        }
+        period
     }
 
     fn overdue_at(&self, when: Timestamp) -> bool {
@@ -412,7 +499,7 @@ mod tests {
     use profit::stub::ProfitRef;
     use sdk::cosmwasm_std::Timestamp;
 
-    use crate::api::InterestPaymentSpec;
+    use crate::api::{InterestPaymentSpec, RateType};
 
     use super::Loan;
 
@@ -1141,6 +1228,86 @@ mod tests {
         }
     }
 
+    mod test_increase {
+        use finance::{coin::Coin, duration::Duration, fraction::Fraction};
+        use lpp::msg::LoanResponse;
+
+        use crate::{error::ContractError, loan::tests::profit_stub};
+
+        use super::{create_loan, Lpn, LEASE_START, LOAN_INTEREST_RATE, MARGIN_INTEREST_RATE};
+
+        #[test]
+        fn settle_interest() {
+            let principal = 1000;
+            let increase = 2000;
+            let increase_at = LEASE_START + Duration::YEAR;
+            let one_year_margin = MARGIN_INTEREST_RATE.of(principal);
+            let one_year_interest = LOAN_INTEREST_RATE.of(principal);
+
+            let mut loan = create_loan(LoanResponse {
+                principal_due: principal.into(),
+                annual_interest_rate: LOAN_INTEREST_RATE,
+                interest_paid: LEASE_START,
+            });
+
+            let proceeds = loan
+                .increase(increase.into(), increase_at, &mut profit_stub())
+                .unwrap();
+            assert_eq!(
+                Coin::new(increase - one_year_interest - one_year_margin),
+                proceeds
+            );
+
+            let state = loan.state(increase_at);
+            assert_eq!(Coin::new(principal + increase), state.principal_due);
+            assert_eq!(Coin::default(), state.previous_interest_due);
+            assert_eq!(Coin::default(), state.previous_margin_interest_due);
+            assert_eq!(Coin::default(), state.current_interest_due);
+            assert_eq!(Coin::default(), state.current_margin_interest_due);
+        }
+
+        #[test]
+        fn insufficient_increase() {
+            let principal = 1000;
+            let increase = LOAN_INTEREST_RATE.of(principal);
+
+            let mut loan = create_loan(LoanResponse {
+                principal_due: principal.into(),
+                annual_interest_rate: LOAN_INTEREST_RATE,
+                interest_paid: LEASE_START,
+            });
+
+            assert_eq!(
+                Err(ContractError::InvalidLoanIncrease(
+                    Coin::<Lpn>::new(increase).into()
+                )),
+                loan.increase(
+                    increase.into(),
+                    LEASE_START + Duration::YEAR,
+                    &mut profit_stub()
+                )
+            );
+        }
+
+        #[test]
+        fn overdue() {
+            let mut loan = create_loan(LoanResponse {
+                principal_due: 1000.into(),
+                annual_interest_rate: LOAN_INTEREST_RATE,
+                interest_paid: LEASE_START,
+            });
+
+            assert_eq!(
+                Err(ContractError::LoanIncreaseOverdue()),
+                loan.increase(
+                    500.into(),
+                    LEASE_START + Duration::YEAR + Duration::from_nanos(1),
+                    &mut profit_stub()
+                )
+            );
+        }
+    }
+
     mod test_grace_period_end {
         use finance::duration::Duration;
         use lpp::msg::LoanResponse;
@@ -1204,14 +1371,110 @@ mod tests {
         }
     }
 
+    mod test_requote {
+        use finance::{duration::Duration, fraction::Fraction};
+        use lpp::msg::LoanResponse;
+
+        use crate::{api::RateType, loan::tests::profit_stub};
+
+        use super::{
+            create_loan_with_rate_type, InterestPaymentSpec, Loan, Lpn, LppLoanLocal, LEASE_START,
+            LOAN_INTEREST_RATE, MARGIN_INTEREST_RATE,
+        };
+
+        #[test]
+        fn floating() {
+            assert!(pay_first_period(RateType::Floating));
+        }
+
+        #[test]
+        fn fixed() {
+            assert!(!pay_first_period(RateType::Fixed));
+        }
+
+        #[test]
+        fn floating_unpaid_period() {
+            let mut loan = create_loan(RateType::Floating);
+            assert_eq!(
+                Some(LEASE_START + Duration::YEAR),
+                loan.requote_after(LEASE_START)
+            );
+
+            assert!(!loan.requote(LEASE_START + Duration::YEAR - Duration::from_nanos(1)));
+            assert!(!loan.lpp_loan.requoted);
+
+            assert!(loan.requote(LEASE_START + Duration::YEAR));
+            assert!(loan.lpp_loan.requoted);
+            assert_eq!(
+                Some(LEASE_START + Duration::YEAR + Duration::YEAR),
+                loan.requote_after(LEASE_START + Duration::YEAR)
+            );
+            assert!(!loan.requote(LEASE_START + Duration::YEAR + Duration::from_days(1)));
+        }
+
+        #[test]
+        fn floating_missed_periods() {
+            let mut loan = create_loan(RateType::Floating);
+            let now = LEASE_START + Duration::YEAR + Duration::YEAR + Duration::from_days(1);
+            assert!(loan.requote(now));
+            assert!(!loan.requote(now));
+            assert!(loan.requote(LEASE_START + Duration::YEAR + Duration::YEAR + Duration::YEAR));
+        }
+
+        #[test]
+        fn fixed_no_requote() {
+            let mut loan = create_loan(RateType::Fixed);
+            assert_eq!(None, loan.requote_after(LEASE_START));
+            assert!(!loan.requote(LEASE_START + Duration::YEAR + Duration::YEAR));
+            assert!(!loan.lpp_loan.requoted);
+        }
+
+        fn pay_first_period(rate_type: RateType) -> bool {
+            let principal = 1000;
+            let mut loan = create_loan(rate_type);
+            let partial_payment = MARGIN_INTEREST_RATE.of(principal);
+            loan.repay(
+                partial_payment.into(),
+                LEASE_START + Duration::YEAR,
+                &mut profit_stub(),
+            )
+            .unwrap();
+            assert!(!loan.lpp_loan.requoted);
+
+            loan.repay(
+                LOAN_INTEREST_RATE.of(principal).into(),
+                LEASE_START + Duration::YEAR,
+                &mut profit_stub(),
+            )
+            .unwrap();
+            loan.lpp_loan.requoted
+        }
+
+        fn create_loan(rate_type: RateType) -> Loan<Lpn, LppLoanLocal> {
+            create_loan_with_rate_type(
+                LoanResponse {
+                    principal_due: 1000.into(),
+                    annual_interest_rate: LOAN_INTEREST_RATE,
+                    interest_paid: LEASE_START,
+                },
+                InterestPaymentSpec::new(Duration::YEAR, Duration::from_secs(0)),
+                rate_type,
+            )
+        }
+    }
+
     #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
     struct LppLoanLocal {
         loan: LoanResponse<Lpn>,
+        requoted: bool,
     }
 
     impl LppLoanLocal {
         fn new(loan: LoanResponse<Lpn>) -> Self {
-            Self { loan }
+            Self {
+                loan,
+                requoted: false,
+            }
         }
     }
 
@@ -1228,9 +1491,18 @@ mod tests {
             self.loan.repay(by, repayment)
         }
 
+        fn increase(&mut self, by: Timestamp, amount: Coin<Lpn>) -> Coin<Lpn> {
+            let annual_interest_rate = self.loan.annual_interest_rate;
+            self.loan.increase(by, amount, annual_interest_rate)
+        }
+
         fn annual_interest_rate(&self) -> Percent {
             self.loan.annual_interest_rate
         }
+
+        fn requote(&mut self) {
+            self.requoted = true;
+        }
     }
 
     impl TryFrom<LppLoanLocal> for LppBatch<LppRef> {
@@ -1249,12 +1521,21 @@ mod tests {
     fn create_loan_with_interest_spec(
         loan: LoanResponse<Lpn>,
         interest_spec: InterestPaymentSpec,
+    ) -> Loan<Lpn, LppLoanLocal> {
+        create_loan_with_rate_type(loan, interest_spec, RateType::Fixed)
+    }
+
+    fn create_loan_with_rate_type(
+        loan: LoanResponse<Lpn>,
+        interest_spec: InterestPaymentSpec,
+        rate_type: RateType,
     ) -> Loan<Lpn, LppLoanLocal> {
         Loan::new(
             LEASE_START,
             LppLoanLocal::new(loan),
             MARGIN_INTEREST_RATE,
             interest_spec,
+            rate_type,
         )
     }
 
@@ -1,13 +1,13 @@
 use currency::SymbolOwned;
 use finance::percent::Percent;
-use lease::api::{LoanForm, NewLeaseContract, NewLeaseForm};
+use lease::api::{LoanForm, NewLeaseContract, NewLeaseForm, RateType};
 use platform::batch::Batch;
 use platform::message::Response as MessageResponse;
 use sdk::cosmwasm_std::{Addr, Coin, DepsMut};
 
 use crate::{
     result::ContractResult,
-    state::{config::Config, leases::Leases},
+    state::{allowlist::Allowlist, config::Config, leases::Leases},
     ContractError,
 };
 
@@ -21,14 +21,25 @@ impl Borrow {
         admin: Addr,
         currency: SymbolOwned,
         max_ltd: Option<Percent>,
+        rate_type: RateType,
     ) -> Result<MessageResponse, ContractError> {
         let config = Config::load(deps.storage)?;
-        let instance_reply_id = Leases::next(deps.storage, customer.clone())?;
+        if config.protocol_pause.openings {
+            return Err(ContractError::OpeningsPaused {});
+        }
+        if config.allowlist {
+            Allowlist::check(deps.storage, &customer)?;
+        }
+        config
+            .exposure_caps
+            .check_customer(deps.storage, &customer)?;
+
+        let instance_reply_id = Leases::next(deps.storage, customer.clone(), currency.clone())?;
 
         let mut batch = Batch::default();
         batch.schedule_instantiate_wasm_on_success_reply(
             config.lease_code_id,
-            Self::open_lease_msg(customer, config, currency, max_ltd)?,
+            Self::open_lease_msg(customer, config, currency, max_ltd, rate_type)?,
             Some(amount),
             "lease",
             Some(admin), // allows lease migrations from this contract
@@ -42,7 +53,10 @@ impl Borrow {
         config: Config,
         currency: SymbolOwned,
         max_ltd: Option<Percent>,
+        rate_type: RateType,
     ) -> ContractResult<NewLeaseContract> {
+        let annual_margin_interest = config.annual_margin_interest(rate_type);
+        let liability = config.liability(&currency);
         config
             .dex
             .map(|dex| NewLeaseContract {
@@ -50,9 +64,15 @@ impl Borrow {
                     customer,
                     currency,
                     max_ltd,
-                    liability: config.liability,
+                    liability,
+                    min_liquidation: config.min_liquidation,
+                    min_asset: config.min_asset,
+                    max_slippage: config.max_slippage,
+                    liquidation_fee: config.liquidation_fee,
+                    liquidation_mode: config.liquidation_mode,
+                    rate_type,
                     loan: LoanForm {
-                        annual_margin_interest: config.lease_interest_rate_margin,
+                        annual_margin_interest,
                         lpp: config.lpp_addr,
                         interest_payment: config.lease_interest_payment,
                         profit: config.profit,
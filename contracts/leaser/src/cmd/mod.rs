@@ -10,7 +10,7 @@ pub mod quote;
 pub struct Quote<'r> {
     querier: QuerierWrapper<'r>,
     lease_asset: SymbolOwned,
-    downpayment: DownpaymentCoin,
+    downpayment: Vec<DownpaymentCoin>,
     oracle: OracleRef,
     liability: Liability,
     lease_interest_rate_margin: Percent,
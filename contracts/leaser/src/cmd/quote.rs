@@ -45,7 +45,7 @@ impl<'r> WithLppLender for Quote<'r> {
 impl<'r> Quote<'r> {
     pub fn new(
         querier: QuerierWrapper<'r>,
-        downpayment: DownpaymentCoin,
+        downpayment: Vec<DownpaymentCoin>,
         lease_asset: SymbolOwned,
         oracle: OracleRef,
         liability: Liability,
@@ -100,7 +100,7 @@ where
     Lpn: Currency,
     Lpp: LppLenderTrait<Lpn>,
 {
-    downpayment: DownpaymentCoin,
+    downpayment: Vec<DownpaymentCoin>,
     lease_asset: SymbolOwned,
     lpp_quote: LppQuote<Lpn, Lpp>,
     liability: Liability,
@@ -120,13 +120,28 @@ where
     where
         O: OracleTrait<Lpn>,
     {
-        let downpayment = self.downpayment.ticker().clone();
+        let downpayment_lpn = self.downpayment.into_iter().try_fold(
+            Coin::<Lpn>::default(),
+            |total_lpn, downpayment| {
+                let symbol = downpayment.ticker().clone();
+
+                PaymentGroup::maybe_visit_on_ticker(
+                    &symbol,
+                    DownpaymentLpn {
+                        downpayment,
+                        oracle: &oracle,
+                        lpn: PhantomData,
+                    },
+                )
+                .map_err(|_| ContractError::UnknownCurrency { symbol })?
+                .map(|downpayment_lpn| total_lpn + downpayment_lpn)
+            },
+        )?;
 
-        PaymentGroup::maybe_visit_on_ticker(
-            &downpayment,
+        LeaseGroup::maybe_visit_on_ticker(
+            &self.lease_asset,
             QuoteStage3 {
-                downpayment: self.downpayment,
-                lease_asset: self.lease_asset,
+                downpayment_lpn,
                 lpp_quote: self.lpp_quote,
                 oracle,
                 liability: self.liability,
@@ -134,67 +149,45 @@ where
                 max_ltd: self.max_ltd,
             },
         )
-        .map_err(|_| ContractError::UnknownCurrency {
-            symbol: downpayment,
+        .map_err({
+            let symbol = self.lease_asset;
+
+            |_| ContractError::UnknownCurrency { symbol }
         })?
     }
 }
 
-struct QuoteStage3<Lpn, Lpp, Oracle>
-where
-    Lpn: Currency,
-    Lpp: LppLenderTrait<Lpn>,
-    Oracle: OracleTrait<Lpn>,
-{
+struct DownpaymentLpn<'r, Lpn, Oracle> {
     downpayment: DownpaymentCoin,
-    lease_asset: SymbolOwned,
-    lpp_quote: LppQuote<Lpn, Lpp>,
-    oracle: Oracle,
-    liability: Liability,
-    lease_interest_rate_margin: Percent,
-    max_ltd: Option<Percent>,
+    oracle: &'r Oracle,
+    lpn: PhantomData<Lpn>,
 }
 
-impl<Lpn, Lpp, Oracle> AnyVisitor for QuoteStage3<Lpn, Lpp, Oracle>
+impl<'r, Lpn, Oracle> AnyVisitor for DownpaymentLpn<'r, Lpn, Oracle>
 where
     Lpn: Currency,
-    Lpp: LppLenderTrait<Lpn>,
     Oracle: OracleTrait<Lpn>,
 {
-    type Output = QuoteResponse;
+    type Output = Coin<Lpn>;
     type Error = ContractError;
 
     fn on<C>(self) -> AnyVisitorResult<Self>
     where
         C: 'static + Currency + Serialize + DeserializeOwned,
     {
-        LeaseGroup::maybe_visit_on_ticker(
-            &self.lease_asset,
-            QuoteStage4 {
-                downpayment: TryInto::<Coin<C>>::try_into(self.downpayment)?,
-                lpp_quote: self.lpp_quote,
-                oracle: self.oracle,
-                liability: self.liability,
-                lease_interest_rate_margin: self.lease_interest_rate_margin,
-                max_ltd: self.max_ltd,
-            },
-        )
-        .map_err({
-            let symbol = self.lease_asset;
+        let downpayment: Coin<C> = self.downpayment.try_into()?;
 
-            |_| ContractError::UnknownCurrency { symbol }
-        })?
+        Ok(total(downpayment, self.oracle.price_of()?))
     }
 }
 
-struct QuoteStage4<Lpn, Dpc, Lpp, Oracle>
+struct QuoteStage3<Lpn, Lpp, Oracle>
 where
     Lpn: Currency,
-    Dpc: Currency,
     Lpp: LppLenderTrait<Lpn>,
     Oracle: OracleTrait<Lpn>,
 {
-    downpayment: Coin<Dpc>,
+    downpayment_lpn: Coin<Lpn>,
     lpp_quote: LppQuote<Lpn, Lpp>,
     oracle: Oracle,
     liability: Liability,
@@ -202,10 +195,9 @@ where
     max_ltd: Option<Percent>,
 }
 
-impl<Lpn, Dpc, Lpp, Oracle> AnyVisitor for QuoteStage4<Lpn, Dpc, Lpp, Oracle>
+impl<Lpn, Lpp, Oracle> AnyVisitor for QuoteStage3<Lpn, Lpp, Oracle>
 where
     Lpn: Currency,
-    Dpc: Currency,
     Lpp: LppLenderTrait<Lpn>,
     Oracle: OracleTrait<Lpn>,
 {
@@ -216,7 +208,7 @@ where
     where
         Asset: 'static + Currency + Serialize + DeserializeOwned,
     {
-        let downpayment_lpn = total(self.downpayment, self.oracle.price_of()?);
+        let downpayment_lpn = self.downpayment_lpn;
 
         if downpayment_lpn.is_zero() {
             return Err(ContractError::ZeroDownpayment {});
@@ -2,6 +2,7 @@ use std::ops::{Deref, DerefMut};
 
 use access_control::ContractOwnerAccess;
 use cosmwasm_std::{Addr, Api, QuerierWrapper};
+use lpp::stub::LppRef;
 use platform::{batch::Batch, reply::from_instantiate, response};
 #[cfg(feature = "contract-with-bindings")]
 use sdk::cosmwasm_std::entry_point;
@@ -15,6 +16,7 @@ use crate::{
     cmd::Borrow,
     error::ContractError,
     leaser::{self, Leaser},
+    migrate,
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg},
     result::ContractResult,
     state::{config::Config, leases::Leases},
@@ -41,7 +43,8 @@ pub fn instantiate(
     ContractOwnerAccess::new(deps.storage.deref_mut()).grant_to(&info.sender)?;
 
     let lease_code = msg.lease_code_id;
-    Config::new(msg)?.store(deps.storage)?;
+    let lpp = LppRef::try_new(msg.lpp_ust_addr.clone(), &deps.querier)?;
+    Config::new(msg, lpp.currency())?.store(deps.storage)?;
 
     leaser::update_lpp(deps.storage, lease_code.u64(), Batch::default())
         .map(response::response_only_messages)
@@ -61,13 +64,18 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
-        ExecuteMsg::OpenLease { currency, max_ltd } => Borrow::with(
+        ExecuteMsg::OpenLease {
+            currency,
+            max_ltd,
+            rate_type,
+        } => Borrow::with(
             deps,
             info.funds,
             info.sender,
             env.contract.address,
             currency,
             max_ltd,
+            rate_type,
         ),
         ExecuteMsg::MigrateLeases {
             new_code_id,
@@ -76,7 +84,12 @@ pub fn execute(
             .check(&info.sender)
             .map_err(Into::into)
             .and_then(move |()| {
-                leaser::try_migrate_leases(deps.storage, new_code_id.u64(), max_leases)
+                leaser::try_migrate_leases(
+                    deps.storage,
+                    new_code_id.u64(),
+                    max_leases,
+                    env.block.time,
+                )
             }),
         ExecuteMsg::MigrateLeasesCont {
             key: next_customer,
@@ -84,10 +97,44 @@ pub fn execute(
         } => ContractOwnerAccess::new(deps.storage.deref())
             .check(&info.sender)
             .map_err(Into::into)
-            .and_then(|()| validate(next_customer, deps.api, &deps.querier))
+            .and_then(|()| {
+                next_customer
+                    .map(|next_customer| validate(next_customer, deps.api, &deps.querier))
+                    .transpose()
+            })
             .and_then(move |next_customer_validated| {
                 leaser::try_migrate_leases_cont(deps.storage, next_customer_validated, max_leases)
             }),
+        ExecuteMsg::BackfillExposure {
+            key: next_customer,
+            max_leases,
+        } => ContractOwnerAccess::new(deps.storage.deref())
+            .check(&info.sender)
+            .map_err(Into::into)
+            .and_then(|()| {
+                next_customer
+                    .map(|next_customer| validate(next_customer, deps.api, &deps.querier))
+                    .transpose()
+            })
+            .and_then(move |next_customer_validated| {
+                leaser::try_backfill_exposure(
+                    deps.storage,
+                    &deps.querier,
+                    next_customer_validated,
+                    max_leases,
+                )
+            }),
+        ExecuteMsg::ChangeLeaseCustomer {
+            customer,
+            new_customer,
+        } => leaser::try_change_lease_customer(deps.storage, info.sender, customer, new_customer),
+        ExecuteMsg::LeaseOpened { amount, loan } => {
+            leaser::try_account_opened_lease(deps.storage, info.sender, amount, loan)
+        }
+        ExecuteMsg::LeaseUpdated {} => leaser::try_update_lease(deps, info.sender),
+        ExecuteMsg::LeaseClosed { liquidated } => {
+            leaser::try_close_lease(deps.storage, info.sender, liquidated)
+        }
     }
     .map(response::response_only_messages)
 }
@@ -96,14 +143,43 @@ pub fn execute(
 pub fn sudo(deps: DepsMut<'_>, _env: Env, msg: SudoMsg) -> ContractResult<Response> {
     match msg {
         SudoMsg::SetupDex(params) => leaser::try_setup_dex(deps.storage, params),
+        SudoMsg::SwitchLiquidationMode(mode) => {
+            leaser::try_switch_liquidation_mode(deps.storage, mode)
+        }
+        SudoMsg::Pause(pause) => leaser::try_pause(deps.storage, pause),
+        SudoMsg::FixedRatePremium(premium) => {
+            leaser::try_set_fixed_rate_premium(deps.storage, premium)
+        }
+        SudoMsg::LiabilityOverride {
+            currency,
+            liability,
+        } => leaser::try_set_liability_override(deps.storage, currency, Some(liability)),
+        SudoMsg::RemoveLiabilityOverride { currency } => {
+            leaser::try_set_liability_override(deps.storage, currency, None)
+        }
+        SudoMsg::ExposureCaps(caps) => leaser::try_set_exposure_caps(deps.storage, caps),
+        SudoMsg::SwitchAllowlist(enabled) => leaser::try_switch_allowlist(deps.storage, enabled),
+        SudoMsg::AllowCustomers(customers) => {
+            leaser::try_allow_customers(deps.storage, deps.api, customers)
+        }
+        SudoMsg::DisallowCustomers(customers) => {
+            leaser::try_disallow_customers(deps.storage, customers)
+        }
         SudoMsg::Config {
             lease_interest_rate_margin,
             liability,
+            min_liquidation,
+            min_asset,
+            max_slippage,
+            liquidation_fee,
             lease_interest_payment,
         } => leaser::try_configure(
-            deps.storage,
+            deps,
             lease_interest_rate_margin,
             liability,
+            (min_liquidation, min_asset),
+            max_slippage,
+            liquidation_fee,
             lease_interest_payment,
         ),
     }
@@ -118,14 +194,38 @@ pub fn query(deps: Deps<'_>, _env: Env, msg: QueryMsg) -> ContractResult<Binary>
             downpayment,
             lease_asset,
             max_ltd,
-        } => to_binary(&Leaser::new(deps).quote(downpayment, lease_asset, max_ltd)?),
+            rate_type,
+        } => to_binary(&Leaser::new(deps).quote(
+            downpayment.into(),
+            lease_asset,
+            max_ltd,
+            rate_type,
+        )?),
         QueryMsg::Leases { owner } => to_binary(&Leaser::new(deps).customer_leases(owner)?),
+        QueryMsg::AllLeases {
+            start_after,
+            limit,
+            currency,
+        } => to_binary(&Leaser::new(deps).all_leases(start_after, limit, currency)?),
+        QueryMsg::Allowlist { customer } => to_binary(&Leaser::new(deps).allowlist(customer)?),
+        QueryMsg::MigrationStatus {} => to_binary(&Leaser::new(deps).migration_status()?),
+        QueryMsg::Stats {} => to_binary(&Leaser::new(deps).stats()?),
+        QueryMsg::ProtocolPause {} => to_binary(&Leaser::new(deps).protocol_pause()?),
     }
     .map_err(Into::into)
 }
 
 #[cfg_attr(feature = "contract-with-bindings", entry_point)]
-pub fn reply(deps: DepsMut<'_>, _env: Env, msg: Reply) -> ContractResult<Response> {
+pub fn reply(deps: DepsMut<'_>, env: Env, msg: Reply) -> ContractResult<Response> {
+    match msg.id {
+        migrate::MIGRATION_REPLY_ID => {
+            leaser::on_lease_migration_failed(deps.storage).map(|()| Response::new())
+        }
+        _ => on_lease_instantiated(deps, env, msg),
+    }
+}
+
+fn on_lease_instantiated(deps: DepsMut<'_>, env: Env, msg: Reply) -> ContractResult<Response> {
     let msg_id = msg.id;
     let contract_addr = from_instantiate::<()>(deps.api, msg)
         .map(|r| r.address)
@@ -133,7 +233,9 @@ pub fn reply(deps: DepsMut<'_>, _env: Env, msg: Reply) -> ContractResult<Respons
             err: err.to_string(),
         })?;
 
-    Leases::save(deps.storage, msg_id, contract_addr.clone())?;
+    let lease = Leases::save(deps.storage, msg_id, contract_addr.clone(), env.block.time)?;
+    leaser::try_open_lease(deps, lease)?;
+
     Ok(Response::new().add_attribute("lease_address", contract_addr))
 }
 
@@ -2,7 +2,9 @@ use std::num::TryFromIntError;
 
 use thiserror::Error;
 
-use sdk::cosmwasm_std::StdError;
+use currency::SymbolOwned;
+use lease::api::LpnCoin;
+use sdk::cosmwasm_std::{Addr, StdError};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
@@ -39,12 +41,42 @@ pub enum ContractError {
     #[error("[Leaser] No Liquidity")]
     NoLiquidity {},
 
+    #[error("[Leaser] Opening leases is paused")]
+    OpeningsPaused {},
+
+    #[error("[Leaser] The customer '{customer}' is not allowed to open or take over leases")]
+    NotAllowed { customer: Addr },
+
     #[error("[Leaser] No DEX connectivity setup")]
     NoDEXConnectivitySetup {},
 
     #[error("[Leaser] DEX connectivity already setup")]
     DEXConnectivityAlreadySetup {},
 
+    #[error("[Leaser] The lease '{lease}' does not belong to the customer '{customer}'")]
+    UnknownLease { lease: Addr, customer: Addr },
+
+    #[error("[Leaser] The minimum liquidation amount '{min_liquidation}' should not exceed the minimum asset amount '{min_asset}' and be of the same currency")]
+    InvalidMinAmounts {
+        min_liquidation: LpnCoin,
+        min_asset: LpnCoin,
+    },
+
+    #[error("[Leaser] The customer has reached the maximum of {max} open leases")]
+    CustomerLeasesCap { max: u32 },
+
+    #[error("[Leaser] The loan exceeds the maximum '{max}' lent to a lease")]
+    LeaseBorrowCap { max: LpnCoin },
+
+    #[error("[Leaser] The loan exceeds the maximum '{max}' lent to the leases in '{currency}'")]
+    CurrencyBorrowCap { currency: SymbolOwned, max: LpnCoin },
+
+    #[error("[Leaser] The lease '{lease}' is not opening")]
+    LeaseNotOpening { lease: Addr },
+
+    #[error("[Leaser] No lease migration in progress")]
+    NoMigrationInProgress {},
+
     #[error("[Leaser] Invalid continuation key, cause: {err} ")]
     InvalidContinuationKey { err: String },
 }
@@ -1,23 +1,41 @@
 use std::collections::HashSet;
 
-use currency::{native::Nls, SymbolOwned};
-use finance::{liability::Liability, percent::Percent};
-use lease::api::{ConnectionParams, DownpaymentCoin, InterestPaymentSpec};
+use currency::{lease::LeaseGroup, native::Nls, SymbolOwned};
+use finance::{
+    coin::Amount,
+    liability::Liability,
+    percent::{bound::BoundToHundredPercent, Percent},
+};
+use lease::api::{
+    ConnectionParams, DownpaymentCoin, InterestPaymentSpec, LeaseCoin, LiquidationFee,
+    LiquidationMode, LpnCoin, ProtocolPause, QueryMsg as LeaseQueryMsg, RateType, StateQuery,
+    StateResponse,
+};
 use lpp::{msg::ExecuteMsg, stub::LppRef};
 use oracle::stub::OracleRef;
 use platform::batch::{Batch, Emit, Emitter};
 use platform::message::Response as MessageResponse;
-use sdk::cosmwasm_std::{Addr, Deps, StdResult, Storage};
+use sdk::cosmwasm_std::{Addr, Api, Deps, DepsMut, QuerierWrapper, StdResult, Storage, Timestamp};
 
 use crate::{
     cmd::Quote,
     error::ContractError,
     migrate,
-    msg::{ConfigResponse, MaxLeases, QuoteResponse},
+    msg::{AllLeasesResponse, AllowlistResponse, ConfigResponse, MaxLeases, QuoteResponse},
     result::ContractResult,
-    state::{config::Config, leases::Leases},
+    state::{
+        allowlist::Allowlist,
+        config::Config,
+        exposure::{Exposure, ExposureCaps},
+        leases::{LeaseInfo, Leases},
+        migration::MigrationStatus,
+        stats::Stats,
+    },
 };
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
 pub struct Leaser<'a> {
     deps: Deps<'a>,
 }
@@ -31,17 +49,51 @@ impl<'a> Leaser<'a> {
         Ok(ConfigResponse { config })
     }
 
+    pub fn allowlist(&self, customer: Addr) -> StdResult<AllowlistResponse> {
+        Config::load(self.deps.storage).map(|config| AllowlistResponse {
+            enabled: config.allowlist,
+            allowed: Allowlist::contains(self.deps.storage, &customer),
+        })
+    }
+
+    pub fn migration_status(&self) -> StdResult<Option<MigrationStatus>> {
+        MigrationStatus::load(self.deps.storage)
+    }
+
+    pub fn stats(&self) -> StdResult<Stats> {
+        Stats::load(self.deps.storage)
+    }
+
+    pub fn protocol_pause(&self) -> StdResult<ProtocolPause> {
+        Config::load(self.deps.storage).map(|config| config.protocol_pause)
+    }
+
     pub fn customer_leases(&self, owner: Addr) -> StdResult<HashSet<Addr>> {
         Leases::get(self.deps.storage, owner)
     }
 
+    pub fn all_leases(
+        &self,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+        currency: Option<SymbolOwned>,
+    ) -> StdResult<AllLeasesResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+
+        Leases::all(self.deps.storage, start_after, limit as usize, currency)
+            .map(|leases| AllLeasesResponse { leases })
+    }
+
     pub fn quote(
         &self,
-        downpayment: DownpaymentCoin,
+        downpayment: Vec<DownpaymentCoin>,
         lease_asset: SymbolOwned,
         max_ltd: Option<Percent>,
+        rate_type: RateType,
     ) -> Result<QuoteResponse, ContractError> {
         let config = Config::load(self.deps.storage)?;
+        let liability = config.liability(&lease_asset);
+        let annual_margin_interest = config.annual_margin_interest(rate_type);
 
         let lpp = LppRef::try_new(config.lpp_addr, &self.deps.querier)?;
 
@@ -51,16 +103,19 @@ impl<'a> Leaser<'a> {
             Quote::new(
                 self.deps.querier,
                 downpayment,
-                lease_asset,
+                lease_asset.clone(),
                 oracle,
-                config.liability,
-                config.lease_interest_rate_margin,
+                liability,
+                annual_margin_interest,
                 max_ltd,
             ),
             &self.deps.querier,
         )?;
 
-        Ok(resp)
+        config
+            .exposure_caps
+            .check_borrow(self.deps.storage, &lease_asset, &resp.borrow)
+            .map(|()| resp)
     }
 }
 
@@ -73,16 +128,109 @@ pub(super) fn try_setup_dex(
     Ok(Default::default())
 }
 
-pub(super) fn try_configure(
+pub(super) fn try_switch_liquidation_mode(
     storage: &mut dyn Storage,
+    mode: LiquidationMode,
+) -> ContractResult<MessageResponse> {
+    Config::switch_liquidation_mode(storage, mode)?;
+
+    Ok(Default::default())
+}
+
+pub(super) fn try_pause(
+    storage: &mut dyn Storage,
+    pause: ProtocolPause,
+) -> ContractResult<MessageResponse> {
+    Config::pause(storage, pause)?;
+
+    Ok(Default::default())
+}
+
+pub(super) fn try_set_fixed_rate_premium(
+    storage: &mut dyn Storage,
+    premium: Percent,
+) -> ContractResult<MessageResponse> {
+    Config::set_fixed_rate_premium(storage, premium)?;
+
+    Ok(Default::default())
+}
+
+pub(super) fn try_set_liability_override(
+    storage: &mut dyn Storage,
+    currency: SymbolOwned,
+    liability: Option<Liability>,
+) -> ContractResult<MessageResponse> {
+    if liability.is_some() {
+        currency::validate::<LeaseGroup>(&currency).map_err(|_| {
+            ContractError::UnknownCurrency {
+                symbol: currency.clone(),
+            }
+        })?;
+    }
+    Config::set_liability_override(storage, currency, liability)?;
+
+    Ok(Default::default())
+}
+
+pub(super) fn try_set_exposure_caps(
+    storage: &mut dyn Storage,
+    caps: ExposureCaps,
+) -> ContractResult<MessageResponse> {
+    Config::set_exposure_caps(storage, caps)?;
+
+    Ok(Default::default())
+}
+
+pub(super) fn try_switch_allowlist(
+    storage: &mut dyn Storage,
+    enabled: bool,
+) -> ContractResult<MessageResponse> {
+    Config::switch_allowlist(storage, enabled)?;
+
+    Ok(Default::default())
+}
+
+pub(super) fn try_allow_customers(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    customers: Vec<Addr>,
+) -> ContractResult<MessageResponse> {
+    customers
+        .iter()
+        .try_for_each(|customer| api.addr_validate(customer.as_str()).map(|_| ()))?;
+    Allowlist::add(storage, &customers)?;
+
+    Ok(Default::default())
+}
+
+pub(super) fn try_disallow_customers(
+    storage: &mut dyn Storage,
+    customers: Vec<Addr>,
+) -> ContractResult<MessageResponse> {
+    Allowlist::remove(storage, &customers);
+
+    Ok(Default::default())
+}
+
+pub(super) fn try_configure(
+    deps: DepsMut<'_>,
     lease_interest_rate_margin: Percent,
     liability: Liability,
+    min_amounts: (LpnCoin, LpnCoin),
+    max_slippage: BoundToHundredPercent,
+    liquidation_fee: LiquidationFee,
     lease_interest_payment: InterestPaymentSpec,
 ) -> ContractResult<MessageResponse> {
+    let lpp = LppRef::try_new(Config::load(deps.storage)?.lpp_addr, &deps.querier)?;
+
     Config::update(
-        storage,
+        deps.storage,
+        lpp.currency(),
         lease_interest_rate_margin,
         liability,
+        min_amounts,
+        max_slippage,
+        liquidation_fee,
         lease_interest_payment,
     )?;
 
@@ -93,12 +241,21 @@ pub(super) fn try_migrate_leases(
     storage: &mut dyn Storage,
     new_code_id: u64,
     max_leases: MaxLeases,
+    now: Timestamp,
 ) -> ContractResult<MessageResponse> {
     Config::update_lease_code(storage, new_code_id)?;
 
     let leases = Leases::iter(storage, None);
-    migrate::migrate_leases(leases, new_code_id, max_leases)
-        .and_then(|result| result.try_add_msgs(|msgs| update_lpp_impl(storage, new_code_id, msgs)))
+    let result = migrate::migrate_leases(leases, new_code_id, max_leases)?;
+    MigrationStatus::start(
+        storage,
+        new_code_id,
+        result.next_customer.clone(),
+        result.migrated()?,
+        now,
+    )?;
+    result
+        .try_add_msgs(|msgs| update_lpp_impl(storage, new_code_id, msgs))
         .map(|result| {
             MessageResponse::messages_with_events(result.msgs, emit_status(result.next_customer))
         })
@@ -106,14 +263,158 @@ pub(super) fn try_migrate_leases(
 
 pub(super) fn try_migrate_leases_cont(
     storage: &mut dyn Storage,
-    next_customer: Addr,
+    next_customer: Option<Addr>,
     max_leases: MaxLeases,
 ) -> ContractResult<MessageResponse> {
     let lease_code_id = Config::load(storage)?.lease_code_id;
+    let next_customer = next_customer.map_or_else(|| MigrationStatus::resume_from(storage), Ok)?;
 
     let leases = Leases::iter(storage, Some(next_customer));
-    migrate::migrate_leases(leases, lease_code_id, max_leases).map(|result| {
-        MessageResponse::messages_with_events(result.msgs, emit_status(result.next_customer))
+    let result = migrate::migrate_leases(leases, lease_code_id, max_leases)?;
+    MigrationStatus::proceed(storage, result.next_customer.clone(), result.migrated()?)?;
+    Ok(MessageResponse::messages_with_events(
+        result.msgs,
+        emit_status(result.next_customer),
+    ))
+}
+
+pub(super) fn on_lease_migration_failed(storage: &mut dyn Storage) -> ContractResult<()> {
+    MigrationStatus::fail_one(storage)
+}
+
+pub(super) fn try_change_lease_customer(
+    storage: &mut dyn Storage,
+    lease: Addr,
+    customer: Addr,
+    new_customer: Addr,
+) -> ContractResult<MessageResponse> {
+    let config = Config::load(storage)?;
+    if config.allowlist {
+        Allowlist::check(storage, &new_customer)?;
+    }
+    if Exposure::tracked(storage, lease.clone()) {
+        config
+            .exposure_caps
+            .check_customer(storage, &new_customer)?;
+    }
+    Leases::change_customer(storage, lease.clone(), customer, new_customer.clone())?;
+    Exposure::change_customer(storage, lease, new_customer)?;
+
+    Ok(Default::default())
+}
+
+/// Account an open lease in the statistics
+///
+/// Only the leases known to this leaser are accounted, the messages of others are ignored.
+pub(super) fn try_account_opened_lease(
+    storage: &mut dyn Storage,
+    lease: Addr,
+    amount: LeaseCoin,
+    loan: LpnCoin,
+) -> ContractResult<MessageResponse> {
+    if Leases::indexed(storage, lease.clone()) {
+        Stats::opened(storage, lease, amount, loan)?;
+    }
+
+    Ok(Default::default())
+}
+
+/// Count a lease against the exposure caps and in the statistics with its current asset and loan
+///
+/// The leases neither tracked nor accounted are ignored without querying them.
+pub(super) fn try_update_lease(deps: DepsMut<'_>, lease: Addr) -> ContractResult<MessageResponse> {
+    if Exposure::tracked(deps.storage, lease.clone())
+        || Stats::accounted(deps.storage, lease.clone())
+    {
+        let (amount, borrow) = query_position(&deps.querier, &lease)?;
+        let caps = Config::load(deps.storage)?.exposure_caps;
+        Exposure::update(deps.storage, lease.clone(), borrow, &caps)?;
+        if let Some(amount) = amount {
+            Stats::updated(deps.storage, lease, amount.amount(), borrow)?;
+        }
+    }
+
+    Ok(Default::default())
+}
+
+pub(super) fn try_close_lease(
+    storage: &mut dyn Storage,
+    lease: Addr,
+    liquidated: bool,
+) -> ContractResult<MessageResponse> {
+    Exposure::close(storage, lease.clone())?;
+    Stats::closed(storage, lease, liquidated)?;
+
+    Ok(Default::default())
+}
+
+/// Count a newly instantiated lease against the exposure caps and in the statistics
+///
+/// The lease has already obtained its loan, hence the caps are checked against it.
+pub(super) fn try_open_lease(deps: DepsMut<'_>, lease: LeaseInfo) -> ContractResult<()> {
+    let borrow = query_loan(&deps.querier, &lease.lease)?;
+
+    Config::load(deps.storage)?.exposure_caps.check_borrow(
+        deps.storage,
+        &lease.currency,
+        &borrow,
+    )?;
+    Exposure::open(
+        deps.storage,
+        lease.lease.clone(),
+        lease.customer,
+        lease.currency,
+        borrow.amount(),
+    )?;
+    Stats::instantiated(deps.storage, lease.lease).map_err(Into::into)
+}
+
+/// Track against the exposure caps a batch of the leases opened before they were introduced
+///
+/// The leases already tracked are skipped, hence a batch is safe to run again.
+/// The closed leases, and the opening ones not indexed, are left out.
+pub(super) fn try_backfill_exposure(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper<'_>,
+    next_customer: Option<Addr>,
+    max_leases: MaxLeases,
+) -> ContractResult<MessageResponse> {
+    let (leases, next_customer) =
+        Leases::batch_with_customers(storage, next_customer, max_leases.try_into()?)?;
+    leases
+        .into_iter()
+        .try_for_each(|(customer, lease)| backfill_exposure(storage, querier, customer, lease))
+        .map(|()| {
+            MessageResponse::messages_with_events(
+                Default::default(),
+                emit_backfill_status(next_customer),
+            )
+        })
+}
+
+fn backfill_exposure(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper<'_>,
+    customer: Addr,
+    lease: Addr,
+) -> ContractResult<()> {
+    if Exposure::tracked(storage, lease.clone()) {
+        return Ok(());
+    }
+    let may_loan = match query_state(querier, &lease)? {
+        StateResponse::Opening { loan, .. } => {
+            Leases::currency(storage, lease.clone())?.map(|currency| (currency, loan.amount()))
+        }
+        StateResponse::Opened {
+            amount,
+            principal_due,
+            ..
+        } => Some((amount.ticker().clone(), principal_due.amount())),
+        StateResponse::Paid { amount, .. } => Some((amount.ticker().clone(), Amount::default())),
+        StateResponse::Closed() | StateResponse::Liquidated() => None,
+    };
+    may_loan.map_or(Ok(()), |(currency, borrow)| {
+        Exposure::open(storage, lease, customer, currency, borrow).map_err(Into::into)
     })
 }
 
@@ -139,6 +440,38 @@ fn update_lpp_impl(
         .map_err(Into::into)
 }
 
+fn query_loan(querier: &QuerierWrapper<'_>, lease: &Addr) -> ContractResult<LpnCoin> {
+    query_state(querier, lease).and_then(|state| match state {
+        StateResponse::Opening { loan, .. } => Ok(loan),
+        _ => Err(ContractError::LeaseNotOpening {
+            lease: lease.clone(),
+        }),
+    })
+}
+
+/// The asset, if bought, and the loan principal of a lease
+fn query_position(
+    querier: &QuerierWrapper<'_>,
+    lease: &Addr,
+) -> ContractResult<(Option<LeaseCoin>, Amount)> {
+    query_state(querier, lease).map(|state| match state {
+        StateResponse::Opening { loan, .. } => (None, loan.amount()),
+        StateResponse::Opened {
+            amount,
+            principal_due,
+            ..
+        } => (Some(amount), principal_due.amount()),
+        StateResponse::Paid { amount, .. } => (Some(amount), Amount::default()),
+        StateResponse::Closed() | StateResponse::Liquidated() => (None, Amount::default()),
+    })
+}
+
+fn query_state(querier: &QuerierWrapper<'_>, lease: &Addr) -> ContractResult<StateResponse> {
+    querier
+        .query_wasm_smart(lease.clone(), &LeaseQueryMsg::State(StateQuery {}))
+        .map_err(Into::into)
+}
+
 fn emit_status(next_customer: Option<Addr>) -> Emitter {
     let emitter = Emitter::of_type("migrate-leases");
     if let Some(next) = next_customer {
@@ -147,3 +480,12 @@ fn emit_status(next_customer: Option<Addr>) -> Emitter {
         emitter.emit("status", "done")
     }
 }
+
+fn emit_backfill_status(next_customer: Option<Addr>) -> Emitter {
+    let emitter = Emitter::of_type("backfill-exposure");
+    if let Some(next) = next_customer {
+        emitter.emit("continuation-key", next)
+    } else {
+        emitter.emit("status", "done")
+    }
+}
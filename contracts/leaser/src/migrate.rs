@@ -1,9 +1,15 @@
 use lease::api::MigrateMsg;
-use platform::batch::Batch;
+use platform::batch::{Batch, ReplyId};
 use sdk::cosmwasm_std::Addr;
 
 use crate::{msg::MaxLeases, result::ContractResult};
 
+/// The reply id of the lease migrations
+///
+/// They are replied only on failure. The id is reserved, the lease instantiations
+/// never get it.
+pub const MIGRATION_REPLY_ID: ReplyId = 0;
+
 pub struct Customer<LeaseIter> {
     customer: Addr,
     leases: LeaseIter,
@@ -53,6 +59,11 @@ where
 }
 
 impl MigrationResult {
+    /// The number of the lease migrations in the batch
+    pub fn migrated(&self) -> ContractResult<MaxLeases> {
+        self.msgs.len().try_into().map_err(Into::into)
+    }
+
     pub fn try_add_msgs<F>(mut self, add_fn: F) -> ContractResult<Self>
     where
         F: FnOnce(&mut Batch) -> ContractResult<()>,
@@ -88,7 +99,12 @@ impl MigrateBatch {
                     self.leases_left = left;
                     customer.leases.find_map(|lease| {
                         self.msgs
-                            .schedule_migrate_wasm_no_reply(&lease, MigrateMsg {}, self.new_code_id)
+                            .schedule_migrate_wasm_on_error_reply(
+                                &lease,
+                                MigrateMsg {},
+                                self.new_code_id,
+                                MIGRATION_REPLY_ID,
+                            )
                             .map(|()| None)
                             .map_err(Into::into)
                             .transpose()
@@ -281,7 +297,12 @@ mod test {
 
     fn add_expected(mut exp: MigrationResult, lease_addr: &Addr, new_code: u64) -> MigrationResult {
         exp.msgs
-            .schedule_migrate_wasm_no_reply(lease_addr, MigrateMsg {}, new_code)
+            .schedule_migrate_wasm_on_error_reply(
+                lease_addr,
+                MigrateMsg {},
+                new_code,
+                super::MIGRATION_REPLY_ID,
+            )
             .unwrap();
         exp
     }
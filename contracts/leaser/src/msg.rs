@@ -1,15 +1,21 @@
 use serde::{Deserialize, Serialize};
 
 use currency::SymbolOwned;
-use finance::{liability::Liability, percent::Percent};
-use lease::api::{ConnectionParams, DownpaymentCoin, InterestPaymentSpec, LeaseCoin};
+use finance::{
+    liability::Liability,
+    percent::{bound::BoundToHundredPercent, Percent},
+};
+use lease::api::{
+    ConnectionParams, DownpaymentCoins, InterestPaymentSpec, LeaseCoin, LiquidationFee,
+    LiquidationMode, ProtocolPause, RateType,
+};
 use lpp::msg::LpnCoin;
 use sdk::{
     cosmwasm_std::{Addr, Uint64},
     schemars::{self, JsonSchema},
 };
 
-use crate::state::config::Config;
+use crate::state::{config::Config, exposure::ExposureCaps, leases::LeaseInfo};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct InstantiateMsg {
@@ -17,6 +23,10 @@ pub struct InstantiateMsg {
     pub lpp_ust_addr: Addr,
     pub lease_interest_rate_margin: Percent,
     pub liability: Liability,
+    pub min_liquidation: LpnCoin,
+    pub min_asset: LpnCoin,
+    pub max_slippage: BoundToHundredPercent,
+    pub liquidation_fee: LiquidationFee,
     pub lease_interest_payment: InterestPaymentSpec,
     pub time_alarms: Addr,
     pub market_price_oracle: Addr,
@@ -31,10 +41,16 @@ pub type MaxLeases = u32;
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
+    /// Open a lease for the sender
+    ///
+    /// The downpayment may consist of coins in any of the payment currencies. All of them
+    /// are swapped, along with the loan, into the lease currency.
     OpenLease {
         currency: SymbolOwned,
         #[serde(default)]
         max_ltd: Option<Percent>,
+        #[serde(default)]
+        rate_type: RateType,
     },
     /// Start a Lease migration
     ///
@@ -46,6 +62,9 @@ pub enum ExecuteMsg {
     /// with the next `MigrateLeasesCont` message. It in turn emits
     /// a continuation key with the same event and the procedure continues until
     /// no key is provided and 'wasm-migrate-leases.status=done'.
+    ///
+    /// A lease that fails to migrate does not revert the batch. It stays on its current
+    /// code and is counted as failed in the `MigrationStatus`.
     MigrateLeases {
         new_code_id: Uint64,
         max_leases: MaxLeases,
@@ -53,17 +72,96 @@ pub enum ExecuteMsg {
     /// Continue a Lease migration
     ///
     /// It migrates the next batch of up to `max_leases` number of Lease instances
-    /// and emits the status as specified in `MigrateLeases`.
-    MigrateLeasesCont { key: Addr, max_leases: MaxLeases },
+    /// and emits the status as specified in `MigrateLeases`. If no key is provided,
+    /// the migration resumes from the one stored with the `MigrationStatus`.
+    MigrateLeasesCont {
+        #[serde(default)]
+        key: Option<Addr>,
+        max_leases: MaxLeases,
+    },
+    /// Track against the exposure caps the leases opened before they were introduced
+    ///
+    /// It processes up to `max_leases` number of Lease instances, starting with the ones
+    /// of the customer `key`, if provided, and emits a continuation key as an event
+    /// 'wasm-backfill-exposure.continuation-key=<key>'. That key should be provided
+    /// with the next `BackfillExposure` message, and so on, until no key is emitted
+    /// and 'wasm-backfill-exposure.status=done'. The leases already tracked are skipped,
+    /// hence repeating a batch is safe.
+    BackfillExposure {
+        #[serde(default)]
+        key: Option<Addr>,
+        max_leases: MaxLeases,
+    },
+    /// Move a lease from one customer to another
+    ///
+    /// Sent by a lease, the message sender, on a transfer of its ownership.
+    /// The message format should match `lease::api::ExecuteLeaserMsg::ChangeLeaseCustomer`.
+    ChangeLeaseCustomer { customer: Addr, new_customer: Addr },
+    /// Account an open lease in the statistics
+    ///
+    /// Sent by a lease, the message sender, once it has bought its asset.
+    /// The message format should match `lease::api::ExecuteLeaserMsg::LeaseOpened`.
+    LeaseOpened { amount: LeaseCoin, loan: LpnCoin },
+    /// Count a lease against the exposure caps and in the statistics with its current
+    /// asset and loan
+    ///
+    /// Sent by a lease, the message sender, on a change of its asset or loan. The leaser
+    /// queries the lease for them, hence a loan increase over the caps fails the change.
+    /// The message format should match `lease::api::ExecuteLeaserMsg::LeaseUpdated`.
+    LeaseUpdated {},
+    /// Stop counting a lease against the exposure caps and account it in the statistics
+    ///
+    /// Sent by a lease, the message sender, on its full repayment or liquidation.
+    /// The message format should match `lease::api::ExecuteLeaserMsg::LeaseClosed`.
+    LeaseClosed {
+        #[serde(default)]
+        liquidated: bool,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SudoMsg {
     SetupDex(ConnectionParams),
+    /// Switch how the leases opened from now on get liquidated
+    SwitchLiquidationMode(LiquidationMode),
+    /// Pause or resume lease operations protocol-wide
+    ///
+    /// The provided flags replace the current ones.
+    Pause(ProtocolPause),
+    /// Set the premium charged on top of the margin interest of the fixed-rate leases
+    ///
+    /// It applies to the leases opened from now on.
+    FixedRatePremium(Percent),
+    /// Set the liability of the leases in the given currency instead of the global one
+    ///
+    /// It applies to the leases opened from now on.
+    LiabilityOverride {
+        currency: SymbolOwned,
+        liability: Liability,
+    },
+    /// Remove the liability override of the given currency
+    ///
+    /// The leases in that currency opened from now on get the global liability.
+    RemoveLiabilityOverride {
+        currency: SymbolOwned,
+    },
+    /// Set the limits the leases opened from now on are checked against
+    ExposureCaps(ExposureCaps),
+    /// Restrict opening leases and taking them over to the customers on the allowlist,
+    /// or lift the restriction
+    SwitchAllowlist(bool),
+    /// Add the customers to the allowlist
+    AllowCustomers(Vec<Addr>),
+    /// Remove the customers from the allowlist
+    DisallowCustomers(Vec<Addr>),
     Config {
         lease_interest_rate_margin: Percent,
         liability: Liability,
+        min_liquidation: LpnCoin,
+        min_asset: LpnCoin,
+        max_slippage: BoundToHundredPercent,
+        liquidation_fee: LiquidationFee,
         lease_interest_payment: InterestPaymentSpec,
     },
 }
@@ -72,15 +170,52 @@ pub enum SudoMsg {
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     Config {},
+    /// Quote a lease opened with the given downpayment
+    ///
+    /// The downpayment may consist of coins in different currencies. A single coin
+    /// may be passed as is, not wrapped in a list.
     Quote {
-        downpayment: DownpaymentCoin,
+        downpayment: DownpaymentCoins,
         lease_asset: SymbolOwned,
         #[serde(default)]
         max_ltd: Option<Percent>,
+        #[serde(default)]
+        rate_type: RateType,
     },
     Leases {
         owner: Addr,
     },
+    /// Query the leases of all customers ordered by their address
+    ///
+    /// Only the leases opened since the introduction of this query are listed.
+    AllLeases {
+        /// Return the leases with addresses greater than this one, starts from the first if `None`
+        #[serde(default)]
+        start_after: Option<Addr>,
+        /// The maximum number of leases to return, defaults to 10 and is capped to 100
+        #[serde(default)]
+        limit: Option<u32>,
+        /// Return only the leases in this currency
+        #[serde(default)]
+        currency: Option<SymbolOwned>,
+    },
+    /// Query whether the customer is on the allowlist
+    Allowlist {
+        customer: Addr,
+    },
+    /// Query the progress of the last lease migration
+    ///
+    /// Responds with `None` if no migration has been started since the status introduction.
+    MigrationStatus {},
+    /// Query the protocol-wide figures of the leases
+    ///
+    /// Covers the leases instantiated since the statistics were introduced.
+    Stats {},
+    /// Query the lease operations paused protocol-wide
+    ///
+    /// Leases query it before starting the respective operations.
+    /// The message format should match `lease::api::QueryLeaserMsg::ProtocolPause`.
+    ProtocolPause {},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -88,6 +223,19 @@ pub struct ConfigResponse {
     pub config: Config,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AllowlistResponse {
+    /// Whether only the customers on the allowlist may open leases or take them over
+    pub enabled: bool,
+    /// Whether the customer is on the allowlist
+    pub allowed: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AllLeasesResponse {
+    pub leases: Vec<LeaseInfo>,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 #[cfg_attr(any(test, feature = "testing"), derive(Clone, Debug))]
 pub struct QuoteResponse {
@@ -0,0 +1,40 @@
+use access_control::MultiUserAccess;
+use sdk::cosmwasm_std::{Addr, Storage};
+
+use crate::{result::ContractResult, ContractError};
+
+/// The customers allowed to open leases, or take them over, while the opening is gated
+pub struct Allowlist {}
+
+impl Allowlist {
+    const NAMESPACE: &'static str = "opening_allowlist";
+
+    pub fn contains(storage: &dyn Storage, customer: &Addr) -> bool {
+        MultiUserAccess::new(storage, Self::NAMESPACE)
+            .check(customer)
+            .is_ok()
+    }
+
+    pub fn check(storage: &dyn Storage, customer: &Addr) -> ContractResult<()> {
+        MultiUserAccess::new(storage, Self::NAMESPACE)
+            .check(customer)
+            .map_err(|_| ContractError::NotAllowed {
+                customer: customer.clone(),
+            })
+    }
+
+    pub fn add(storage: &mut dyn Storage, customers: &[Addr]) -> ContractResult<()> {
+        let mut access = MultiUserAccess::new(storage, Self::NAMESPACE);
+        customers
+            .iter()
+            .try_for_each(|customer| access.grant_to(customer))
+            .map_err(Into::into)
+    }
+
+    pub fn remove(storage: &mut dyn Storage, customers: &[Addr]) {
+        let mut access = MultiUserAccess::new(storage, Self::NAMESPACE);
+        customers
+            .iter()
+            .for_each(|customer| access.revoke_from(customer))
+    }
+}
@@ -1,7 +1,17 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-use finance::{liability::Liability, percent::Percent};
-use lease::api::{ConnectionParams, InterestPaymentSpec};
+use currency::{lpn::Usdc, Symbol, SymbolOwned};
+use finance::{
+    coin::Coin,
+    liability::Liability,
+    percent::{bound::BoundToHundredPercent, Percent},
+};
+use lease::api::{
+    ConnectionParams, InterestPaymentSpec, LiquidationFee, LiquidationMode, LpnCoin, ProtocolPause,
+    RateType,
+};
 use sdk::{
     cosmwasm_std::{Addr, StdResult, Storage},
     cw_storage_plus::Item,
@@ -10,6 +20,8 @@ use sdk::{
 
 use crate::{msg::InstantiateMsg, result::ContractResult, ContractError};
 
+use super::exposure::ExposureCaps;
+
 type CodeId = u64;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -17,7 +29,36 @@ pub struct Config {
     pub lease_code_id: CodeId,
     pub lpp_addr: Addr,
     pub lease_interest_rate_margin: Percent,
+    /// Charged on top of `lease_interest_rate_margin` to the fixed-rate leases
+    #[serde(default)]
+    pub fixed_rate_premium: Percent,
     pub liability: Liability,
+    /// Per lease currency liabilities applied instead of `liability`
+    #[serde(default)]
+    pub liability_overrides: BTreeMap<SymbolOwned, Liability>,
+    /// The limits the leases opened from now on are checked against
+    #[serde(default)]
+    pub exposure_caps: ExposureCaps,
+    /// Configurations stored before the minimum amounts became configurable
+    /// apply the amounts the leases used to be hard-coded with
+    #[serde(default = "legacy_min_liquidation")]
+    pub min_liquidation: LpnCoin,
+    #[serde(default = "legacy_min_asset")]
+    pub min_asset: LpnCoin,
+    /// Configurations stored before the slippage protection accept any swap output
+    #[serde(default = "legacy_max_slippage")]
+    pub max_slippage: BoundToHundredPercent,
+    /// Configurations stored before the liquidation fee introduction charge none
+    #[serde(default)]
+    pub liquidation_fee: LiquidationFee,
+    /// Configurations stored before the auction introduction liquidate on the market
+    #[serde(default)]
+    pub liquidation_mode: LiquidationMode,
+    #[serde(default)]
+    pub protocol_pause: ProtocolPause,
+    /// Whether only the customers on the allowlist may open leases or take them over
+    #[serde(default)]
+    pub allowlist: bool,
     pub lease_interest_payment: InterestPaymentSpec,
     pub time_alarms: Addr,
     pub market_price_oracle: Addr,
@@ -28,12 +69,24 @@ pub struct Config {
 impl Config {
     const STORAGE: Item<'static, Self> = Item::new("config");
 
-    pub fn new(msg: InstantiateMsg) -> Result<Self, ContractError> {
+    pub fn new(msg: InstantiateMsg, lpn: Symbol<'_>) -> Result<Self, ContractError> {
+        Self::check_min_amounts(&msg.min_liquidation, &msg.min_asset, lpn)?;
+
         Ok(Config {
             lease_code_id: msg.lease_code_id.u64(),
             lpp_addr: msg.lpp_ust_addr,
             lease_interest_rate_margin: msg.lease_interest_rate_margin,
+            fixed_rate_premium: Percent::ZERO,
             liability: msg.liability,
+            liability_overrides: BTreeMap::new(),
+            exposure_caps: ExposureCaps::default(),
+            min_liquidation: msg.min_liquidation,
+            min_asset: msg.min_asset,
+            max_slippage: msg.max_slippage,
+            liquidation_fee: msg.liquidation_fee,
+            liquidation_mode: LiquidationMode::default(),
+            protocol_pause: ProtocolPause::default(),
+            allowlist: false,
             lease_interest_payment: msg.lease_interest_payment,
             time_alarms: msg.time_alarms,
             market_price_oracle: msg.market_price_oracle,
@@ -62,15 +115,88 @@ impl Config {
         Ok(())
     }
 
+    pub fn switch_liquidation_mode(
+        storage: &mut dyn Storage,
+        mode: LiquidationMode,
+    ) -> ContractResult<()> {
+        Self::STORAGE.update(storage, |mut c| -> ContractResult<Config> {
+            c.liquidation_mode = mode;
+            Ok(c)
+        })?;
+        Ok(())
+    }
+
+    pub fn pause(storage: &mut dyn Storage, pause: ProtocolPause) -> ContractResult<()> {
+        Self::STORAGE.update(storage, |mut c| -> ContractResult<Config> {
+            c.protocol_pause = pause;
+            Ok(c)
+        })?;
+        Ok(())
+    }
+
+    pub fn switch_allowlist(storage: &mut dyn Storage, enabled: bool) -> ContractResult<()> {
+        Self::STORAGE.update(storage, |mut c| -> ContractResult<Config> {
+            c.allowlist = enabled;
+            Ok(c)
+        })?;
+        Ok(())
+    }
+
+    pub fn set_fixed_rate_premium(
+        storage: &mut dyn Storage,
+        premium: Percent,
+    ) -> ContractResult<()> {
+        Self::STORAGE.update(storage, |mut c| -> ContractResult<Config> {
+            c.fixed_rate_premium = premium;
+            Ok(c)
+        })?;
+        Ok(())
+    }
+
+    pub fn set_liability_override(
+        storage: &mut dyn Storage,
+        currency: SymbolOwned,
+        liability: Option<Liability>,
+    ) -> ContractResult<()> {
+        Self::STORAGE.update(storage, |mut c| -> ContractResult<Config> {
+            if let Some(liability) = liability {
+                c.liability_overrides.insert(currency, liability);
+            } else {
+                c.liability_overrides.remove(&currency);
+            }
+            Ok(c)
+        })?;
+        Ok(())
+    }
+
+    pub fn set_exposure_caps(storage: &mut dyn Storage, caps: ExposureCaps) -> ContractResult<()> {
+        Self::STORAGE.update(storage, |mut c| -> ContractResult<Config> {
+            c.exposure_caps = caps;
+            Ok(c)
+        })?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn update(
         storage: &mut dyn Storage,
+        lpn: Symbol<'_>,
         lease_interest_rate_margin: Percent,
         liability: Liability,
+        (min_liquidation, min_asset): (LpnCoin, LpnCoin),
+        max_slippage: BoundToHundredPercent,
+        liquidation_fee: LiquidationFee,
         repayment: InterestPaymentSpec,
     ) -> Result<(), ContractError> {
+        Self::check_min_amounts(&min_liquidation, &min_asset, lpn)?;
+
         Self::STORAGE.update(storage, |mut c| -> ContractResult<Config> {
             c.lease_interest_rate_margin = lease_interest_rate_margin;
             c.liability = liability;
+            c.min_liquidation = min_liquidation;
+            c.min_asset = min_asset;
+            c.max_slippage = max_slippage;
+            c.liquidation_fee = liquidation_fee;
             c.lease_interest_payment = repayment;
             Ok(c)
         })?;
@@ -87,4 +213,138 @@ impl Config {
         })?;
         Ok(())
     }
+
+    /// The liability of the leases in the given currency
+    pub fn liability(&self, currency: Symbol<'_>) -> Liability {
+        self.liability_overrides
+            .get(currency)
+            .copied()
+            .unwrap_or(self.liability)
+    }
+
+    /// The margin interest a lease with the given rate type is charged
+    pub fn annual_margin_interest(&self, rate_type: RateType) -> Percent {
+        match rate_type {
+            RateType::Fixed => self.lease_interest_rate_margin + self.fixed_rate_premium,
+            RateType::Floating => self.lease_interest_rate_margin,
+        }
+    }
+
+    fn check_min_amounts(
+        min_liquidation: &LpnCoin,
+        min_asset: &LpnCoin,
+        lpn: Symbol<'_>,
+    ) -> ContractResult<()> {
+        if min_liquidation.ticker() == lpn
+            && min_asset.ticker() == lpn
+            && min_liquidation.amount() <= min_asset.amount()
+        {
+            Ok(())
+        } else {
+            Err(ContractError::InvalidMinAmounts {
+                min_liquidation: min_liquidation.clone(),
+                min_asset: min_asset.clone(),
+            })
+        }
+    }
+}
+
+fn legacy_min_liquidation() -> LpnCoin {
+    Coin::<Usdc>::new(10_000).into()
+}
+
+fn legacy_min_asset() -> LpnCoin {
+    Coin::<Usdc>::new(15_000_000).into()
+}
+
+fn legacy_max_slippage() -> BoundToHundredPercent {
+    BoundToHundredPercent::try_from_percent(Percent::HUNDRED)
+        .expect("the hundred percent should not cross its own bound")
+}
+
+#[cfg(test)]
+mod test {
+    use currency::{lpn::Usdc, Currency};
+    use finance::{coin::Coin, duration::Duration, liability::Liability, percent::Percent};
+    use lease::api::{InterestPaymentSpec, LpnCoin};
+    use sdk::cosmwasm_std::{from_slice, Addr};
+
+    use crate::ContractError;
+
+    use super::Config;
+
+    #[test]
+    fn load_legacy() {
+        let stored = br#"{
+            "lease_code_id":12,
+            "lpp_addr":"lpp",
+            "lease_interest_rate_margin":30,
+            "liability":{
+                "initial":650,
+                "healthy":700,
+                "first_liq_warn":720,
+                "second_liq_warn":750,
+                "third_liq_warn":780,
+                "max":800,
+                "recalc_time":3600000000000
+            },
+            "lease_interest_payment":{
+                "due_period":7776000000000000,
+                "grace_period":864000000000000
+            },
+            "time_alarms":"timealarms",
+            "market_price_oracle":"oracle",
+            "profit":"profit",
+            "dex":null
+        }"#;
+
+        let config: Config = from_slice(stored).unwrap();
+        assert_eq!(12, config.lease_code_id);
+        assert_eq!(Addr::unchecked("lpp"), config.lpp_addr);
+        assert_eq!(
+            Liability::new(
+                Percent::from_permille(650),
+                Percent::from_permille(50),
+                Percent::from_permille(100),
+                Percent::from_permille(30),
+                Percent::from_permille(30),
+                Percent::from_permille(20),
+                Duration::from_hours(1),
+            ),
+            config.liability
+        );
+        assert_eq!(
+            InterestPaymentSpec::new(Duration::from_days(90), Duration::from_days(10)),
+            config.lease_interest_payment
+        );
+        assert_eq!(lpn_coin(10_000), config.min_liquidation);
+        assert_eq!(lpn_coin(15_000_000), config.min_asset);
+        assert_eq!(Percent::HUNDRED, config.max_slippage.percent());
+    }
+
+    #[test]
+    fn check_min_amounts() {
+        assert_eq!(
+            Ok(()),
+            Config::check_min_amounts(&lpn_coin(10), &lpn_coin(20), Usdc::TICKER)
+        );
+        assert_eq!(
+            Err(ContractError::InvalidMinAmounts {
+                min_liquidation: lpn_coin(30),
+                min_asset: lpn_coin(20),
+            }),
+            Config::check_min_amounts(&lpn_coin(30), &lpn_coin(20), Usdc::TICKER)
+        );
+        assert_eq!(
+            Err(ContractError::InvalidMinAmounts {
+                min_liquidation: lpn_coin(10),
+                min_asset: lpn_coin(20),
+            }),
+            Config::check_min_amounts(&lpn_coin(10), &lpn_coin(20), "NLS")
+        );
+    }
+
+    fn lpn_coin(amount: u128) -> LpnCoin {
+        Coin::<Usdc>::new(amount).into()
+    }
 }
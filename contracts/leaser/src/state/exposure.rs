@@ -0,0 +1,396 @@
+use serde::{Deserialize, Serialize};
+
+use currency::{Symbol, SymbolOwned};
+use finance::coin::Amount;
+use lease::api::LpnCoin;
+use sdk::{
+    cosmwasm_std::{Addr, StdResult, Storage},
+    cw_storage_plus::Map,
+    schemars::{self, JsonSchema},
+};
+
+use crate::{result::ContractResult, ContractError};
+
+/// Limits on the leases the protocol is exposed to
+///
+/// A limit set to `None` is not enforced.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ExposureCaps {
+    /// The maximum number of open leases of a customer
+    pub max_customer_leases: Option<u32>,
+    /// The maximum total amount, in LPN, lent to the open leases in the same currency
+    pub max_currency_borrow: Option<LpnCoin>,
+    /// The maximum amount, in LPN, lent to a single lease
+    pub max_lease_borrow: Option<LpnCoin>,
+}
+
+impl ExposureCaps {
+    pub(crate) fn check_borrow(
+        &self,
+        storage: &dyn Storage,
+        currency: Symbol<'_>,
+        borrow: &LpnCoin,
+    ) -> ContractResult<()> {
+        self.check_borrow_change(storage, currency, Amount::default(), borrow.amount())
+    }
+
+    pub(crate) fn check_customer(
+        &self,
+        storage: &dyn Storage,
+        customer: &Addr,
+    ) -> ContractResult<()> {
+        if let Some(max) = self.max_customer_leases {
+            if Exposure::customer_leases(storage, customer.clone())? >= max {
+                return Err(ContractError::CustomerLeasesCap { max });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a loan of `borrow` replacing one of `current` in the same lease
+    fn check_borrow_change(
+        &self,
+        storage: &dyn Storage,
+        currency: Symbol<'_>,
+        current: Amount,
+        borrow: Amount,
+    ) -> ContractResult<()> {
+        if let Some(max) = self.max_lease_borrow.as_ref() {
+            if borrow > max.amount() {
+                return Err(ContractError::LeaseBorrowCap { max: max.clone() });
+            }
+        }
+        if let Some(max) = self.max_currency_borrow.as_ref() {
+            let total = Exposure::currency_borrow(storage, currency)?.saturating_sub(current);
+            if total.saturating_add(borrow) > max.amount() {
+                return Err(ContractError::CurrencyBorrowCap {
+                    currency: currency.into(),
+                    max: max.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct OpenLease {
+    customer: Addr,
+    currency: SymbolOwned,
+    borrow: Amount,
+}
+
+/// The open leases tracked against the exposure caps
+///
+/// Covers the leases opened since the caps were introduced, and the ones opened before
+/// and backfilled with `ExecuteMsg::BackfillExposure`.
+pub struct Exposure {}
+
+impl Exposure {
+    // open lease to its customer, currency and loan
+    const LEASES: Map<'static, Addr, OpenLease> = Map::new("exposure_leases");
+    // customer to the number of its open leases
+    const CUSTOMERS: Map<'static, Addr, u32> = Map::new("exposure_customers");
+    // lease currency to the total amount lent to its open leases
+    const CURRENCIES: Map<'static, SymbolOwned, Amount> = Map::new("exposure_currencies");
+
+    pub fn customer_leases(storage: &dyn Storage, customer: Addr) -> StdResult<u32> {
+        Self::CUSTOMERS
+            .may_load(storage, customer)
+            .map(Option::unwrap_or_default)
+    }
+
+    pub fn currency_borrow(storage: &dyn Storage, currency: Symbol<'_>) -> StdResult<Amount> {
+        Self::CURRENCIES
+            .may_load(storage, currency.into())
+            .map(Option::unwrap_or_default)
+    }
+
+    pub fn open(
+        storage: &mut dyn Storage,
+        lease: Addr,
+        customer: Addr,
+        currency: SymbolOwned,
+        borrow: Amount,
+    ) -> StdResult<()> {
+        Self::CUSTOMERS.update(storage, customer.clone(), |leases| -> StdResult<_> {
+            Ok(leases.unwrap_or_default() + 1)
+        })?;
+        Self::CURRENCIES.update(storage, currency.clone(), |total| -> StdResult<_> {
+            Ok(total.unwrap_or_default().saturating_add(borrow))
+        })?;
+        Self::LEASES.save(
+            storage,
+            lease,
+            &OpenLease {
+                customer,
+                currency,
+                borrow,
+            },
+        )
+    }
+
+    pub fn tracked(storage: &dyn Storage, lease: Addr) -> bool {
+        Self::LEASES.has(storage, lease)
+    }
+
+    /// Replace the loan of a lease, a no-op for leases not tracked
+    ///
+    /// Only a loan increase is checked against the caps, a repayment is never rejected.
+    pub fn update(
+        storage: &mut dyn Storage,
+        lease: Addr,
+        borrow: Amount,
+        caps: &ExposureCaps,
+    ) -> ContractResult<()> {
+        if let Some(mut open_lease) = Self::LEASES.may_load(storage, lease.clone())? {
+            if borrow > open_lease.borrow {
+                caps.check_borrow_change(storage, &open_lease.currency, open_lease.borrow, borrow)?;
+            }
+            Self::CURRENCIES.update(
+                storage,
+                open_lease.currency.clone(),
+                |total| -> StdResult<_> {
+                    Ok(total
+                        .unwrap_or_default()
+                        .saturating_sub(open_lease.borrow)
+                        .saturating_add(borrow))
+                },
+            )?;
+            open_lease.borrow = borrow;
+            Self::LEASES.save(storage, lease, &open_lease)?;
+        }
+        Ok(())
+    }
+
+    /// Stop tracking a lease, a no-op for leases not tracked
+    pub fn close(storage: &mut dyn Storage, lease: Addr) -> StdResult<()> {
+        if let Some(open_lease) = Self::LEASES.may_load(storage, lease.clone())? {
+            Self::LEASES.remove(storage, lease);
+            Self::remove_customer_lease(storage, open_lease.customer)?;
+            Self::CURRENCIES.update(storage, open_lease.currency, |total| -> StdResult<_> {
+                Ok(total.unwrap_or_default().saturating_sub(open_lease.borrow))
+            })?;
+        }
+        Ok(())
+    }
+
+    pub fn change_customer(
+        storage: &mut dyn Storage,
+        lease: Addr,
+        new_customer: Addr,
+    ) -> StdResult<()> {
+        if let Some(mut open_lease) = Self::LEASES.may_load(storage, lease.clone())? {
+            Self::remove_customer_lease(storage, open_lease.customer)?;
+            Self::CUSTOMERS.update(storage, new_customer.clone(), |leases| -> StdResult<_> {
+                Ok(leases.unwrap_or_default() + 1)
+            })?;
+            open_lease.customer = new_customer;
+            Self::LEASES.save(storage, lease, &open_lease)?;
+        }
+        Ok(())
+    }
+
+    fn remove_customer_lease(storage: &mut dyn Storage, customer: Addr) -> StdResult<()> {
+        match Self::CUSTOMERS.may_load(storage, customer.clone())? {
+            Some(leases) if leases > 1 => Self::CUSTOMERS.save(storage, customer, &(leases - 1)),
+            _ => {
+                Self::CUSTOMERS.remove(storage, customer);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use currency::test::Usdc;
+    use finance::coin::Coin;
+    use lease::api::LpnCoin;
+    use sdk::cosmwasm_std::{testing, Addr};
+
+    use crate::ContractError;
+
+    use super::{Exposure, ExposureCaps};
+
+    const CURRENCY: &str = "ATOM";
+    const OTHER_CURRENCY: &str = "OSMO";
+
+    fn lpn_coin(amount: u128) -> LpnCoin {
+        Coin::<Usdc>::new(amount).into()
+    }
+
+    #[test]
+    fn open_close() {
+        let mut deps = testing::mock_dependencies();
+        let customer = Addr::unchecked("customer");
+        let lease1 = Addr::unchecked("lease1");
+        let lease2 = Addr::unchecked("lease2");
+
+        Exposure::open(
+            &mut deps.storage,
+            lease1.clone(),
+            customer.clone(),
+            CURRENCY.into(),
+            100,
+        )
+        .unwrap();
+        Exposure::open(
+            &mut deps.storage,
+            lease2.clone(),
+            customer.clone(),
+            CURRENCY.into(),
+            50,
+        )
+        .unwrap();
+        assert_eq!(
+            2,
+            Exposure::customer_leases(&deps.storage, customer.clone()).unwrap()
+        );
+        assert_eq!(
+            150,
+            Exposure::currency_borrow(&deps.storage, CURRENCY).unwrap()
+        );
+        assert_eq!(
+            0,
+            Exposure::currency_borrow(&deps.storage, OTHER_CURRENCY).unwrap()
+        );
+
+        Exposure::close(&mut deps.storage, lease1.clone()).unwrap();
+        Exposure::close(&mut deps.storage, lease1).unwrap();
+        assert_eq!(
+            1,
+            Exposure::customer_leases(&deps.storage, customer.clone()).unwrap()
+        );
+        assert_eq!(
+            50,
+            Exposure::currency_borrow(&deps.storage, CURRENCY).unwrap()
+        );
+
+        let new_customer = Addr::unchecked("new_customer");
+        Exposure::change_customer(&mut deps.storage, lease2.clone(), new_customer.clone()).unwrap();
+        assert_eq!(
+            0,
+            Exposure::customer_leases(&deps.storage, customer).unwrap()
+        );
+        assert_eq!(
+            1,
+            Exposure::customer_leases(&deps.storage, new_customer.clone()).unwrap()
+        );
+
+        Exposure::close(&mut deps.storage, lease2).unwrap();
+        assert_eq!(
+            0,
+            Exposure::customer_leases(&deps.storage, new_customer).unwrap()
+        );
+        assert_eq!(
+            0,
+            Exposure::currency_borrow(&deps.storage, CURRENCY).unwrap()
+        );
+    }
+
+    #[test]
+    fn update() {
+        let mut deps = testing::mock_dependencies();
+        let lease = Addr::unchecked("lease");
+        Exposure::open(
+            &mut deps.storage,
+            lease.clone(),
+            Addr::unchecked("customer"),
+            CURRENCY.into(),
+            100,
+        )
+        .unwrap();
+
+        let caps = ExposureCaps {
+            max_customer_leases: None,
+            max_currency_borrow: Some(lpn_coin(150)),
+            max_lease_borrow: Some(lpn_coin(140)),
+        };
+        Exposure::update(&mut deps.storage, lease.clone(), 140, &caps).unwrap();
+        assert_eq!(
+            140,
+            Exposure::currency_borrow(&deps.storage, CURRENCY).unwrap()
+        );
+        assert_eq!(
+            Err(ContractError::LeaseBorrowCap { max: lpn_coin(140) }),
+            Exposure::update(&mut deps.storage, lease.clone(), 141, &caps)
+        );
+
+        let tight_caps = ExposureCaps {
+            max_lease_borrow: Some(lpn_coin(10)),
+            ..caps
+        };
+        Exposure::update(&mut deps.storage, lease.clone(), 30, &tight_caps).unwrap();
+        assert_eq!(
+            30,
+            Exposure::currency_borrow(&deps.storage, CURRENCY).unwrap()
+        );
+
+        Exposure::update(
+            &mut deps.storage,
+            Addr::unchecked("unknown"),
+            1_000,
+            &tight_caps,
+        )
+        .unwrap();
+        assert_eq!(
+            30,
+            Exposure::currency_borrow(&deps.storage, CURRENCY).unwrap()
+        );
+    }
+
+    #[test]
+    fn check_caps() {
+        let mut deps = testing::mock_dependencies();
+        let customer = Addr::unchecked("customer");
+        Exposure::open(
+            &mut deps.storage,
+            Addr::unchecked("lease"),
+            customer.clone(),
+            CURRENCY.into(),
+            100,
+        )
+        .unwrap();
+
+        let caps = ExposureCaps {
+            max_customer_leases: Some(1),
+            max_currency_borrow: Some(lpn_coin(150)),
+            max_lease_borrow: Some(lpn_coin(60)),
+        };
+        assert_eq!(
+            Err(ContractError::CustomerLeasesCap { max: 1 }),
+            caps.check_customer(&deps.storage, &customer)
+        );
+        assert_eq!(
+            Ok(()),
+            caps.check_customer(&deps.storage, &Addr::unchecked("other"))
+        );
+
+        assert_eq!(
+            Ok(()),
+            caps.check_borrow(&deps.storage, CURRENCY, &lpn_coin(50))
+        );
+        assert_eq!(
+            Err(ContractError::CurrencyBorrowCap {
+                currency: CURRENCY.into(),
+                max: lpn_coin(150)
+            }),
+            caps.check_borrow(&deps.storage, CURRENCY, &lpn_coin(51))
+        );
+        assert_eq!(
+            Ok(()),
+            caps.check_borrow(&deps.storage, OTHER_CURRENCY, &lpn_coin(60))
+        );
+        assert_eq!(
+            Err(ContractError::LeaseBorrowCap { max: lpn_coin(60) }),
+            caps.check_borrow(&deps.storage, OTHER_CURRENCY, &lpn_coin(61))
+        );
+
+        assert_eq!(
+            Ok(()),
+            ExposureCaps::default().check_borrow(&deps.storage, CURRENCY, &lpn_coin(1_000_000))
+        );
+    }
+}
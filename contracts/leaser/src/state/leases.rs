@@ -1,17 +1,22 @@
 use std::collections::{hash_set::IntoIter, HashSet};
 
+use serde::{Deserialize, Serialize};
+
+use currency::SymbolOwned;
 use sdk::{
-    cosmwasm_std::{Addr, StdResult, Storage},
+    cosmwasm_std::{Addr, Order, StdResult, Storage, Timestamp},
     cw_storage_plus::{Bound, Item, Map},
+    schemars::{self, JsonSchema},
 };
 
 use crate::{
     error::ContractError,
-    migrate::{Customer, MaybeCustomer},
+    migrate::{Customer, MaybeCustomer, MIGRATION_REPLY_ID},
 };
 
 const IDS: InstantiateReplyIdSeq<'static> = InstantiateReplyIdSeq::new("instantiate_reply_ids");
-const PENDING: Map<'static, InstantiateReplyId, Addr> = Map::new("pending_instance_creations");
+const PENDING: Map<'static, InstantiateReplyId, PendingLease> =
+    Map::new("pending_instance_creations");
 
 pub type InstantiateReplyId = u64;
 pub struct InstantiateReplyIdSeq<'a>(Item<'a, InstantiateReplyId>);
@@ -24,50 +29,150 @@ impl<'a> InstantiateReplyIdSeq<'a> {
     pub fn next(&self, store: &mut dyn Storage) -> Result<InstantiateReplyId, ContractError> {
         let mut next_seq = self.0.load(store).unwrap_or(0);
         next_seq = next_seq.wrapping_add(1);
+        if next_seq == MIGRATION_REPLY_ID {
+            next_seq = next_seq.wrapping_add(1);
+        }
         self.0.save(store, &next_seq)?;
         Ok(next_seq)
     }
 }
 
+#[derive(Serialize, Deserialize)]
+struct PendingLease {
+    customer: Addr,
+    currency: SymbolOwned,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct LeaseInfo {
+    pub lease: Addr,
+    pub customer: Addr,
+    pub currency: SymbolOwned,
+    pub opened_at: Timestamp,
+}
+
 pub struct Leases {}
 
 impl Leases {
     // customer to leases
     const STORAGE: Map<'static, Addr, HashSet<Addr>> = Map::new("loans");
+    // lease to its details, covers the leases opened since the index was introduced
+    const INDEX: Map<'static, Addr, LeaseInfo> = Map::new("leases_index");
+    // (lease currency, lease) to nothing, a secondary index of `INDEX`
+    const BY_CURRENCY: Map<'static, (SymbolOwned, Addr), ()> = Map::new("leases_by_currency");
 
     pub fn next(
         storage: &mut dyn Storage,
-        sender: Addr,
+        customer: Addr,
+        currency: SymbolOwned,
     ) -> Result<InstantiateReplyId, ContractError> {
         let instance_reply_id = IDS.next(storage)?;
 
-        PENDING.save(storage, instance_reply_id, &sender)?;
+        PENDING.save(
+            storage,
+            instance_reply_id,
+            &PendingLease { customer, currency },
+        )?;
 
         Ok(instance_reply_id)
     }
 
-    pub fn save(storage: &mut dyn Storage, msg_id: u64, lease_addr: Addr) -> StdResult<()> {
-        let owner_addr = PENDING.load(storage, msg_id)?;
+    pub fn save(
+        storage: &mut dyn Storage,
+        msg_id: u64,
+        lease_addr: Addr,
+        opened_at: Timestamp,
+    ) -> StdResult<LeaseInfo> {
+        let PendingLease { customer, currency } = PENDING.load(storage, msg_id)?;
 
-        // update function for new or existing keys
-        let update = |d: Option<HashSet<Addr>>| -> StdResult<HashSet<Addr>> {
-            match d {
-                Some(mut loans) => {
-                    loans.insert(lease_addr);
-                    Ok(loans)
-                }
-                None => {
-                    let mut loans = HashSet::new();
-                    loans.insert(lease_addr);
-                    Ok(loans)
-                }
-            }
+        Self::add(storage, customer.clone(), lease_addr.clone())?;
+        Self::BY_CURRENCY.save(storage, (currency.clone(), lease_addr.clone()), &())?;
+        let info = LeaseInfo {
+            lease: lease_addr,
+            customer,
+            currency,
+            opened_at,
         };
-
-        Self::STORAGE.update(storage, owner_addr, update)?;
+        Self::INDEX.save(storage, info.lease.clone(), &info)?;
         PENDING.remove(storage, msg_id);
 
-        Ok(())
+        Ok(info)
+    }
+
+    pub fn change_customer(
+        storage: &mut dyn Storage,
+        lease_addr: Addr,
+        customer: Addr,
+        new_customer: Addr,
+    ) -> Result<(), ContractError> {
+        let mut leases = Self::get(storage, customer.clone())?;
+        if !leases.remove(&lease_addr) {
+            return Err(ContractError::UnknownLease {
+                lease: lease_addr,
+                customer,
+            });
+        }
+
+        if leases.is_empty() {
+            Self::STORAGE.remove(storage, customer);
+        } else {
+            Self::STORAGE.save(storage, customer, &leases)?;
+        }
+
+        if let Some(mut info) = Self::INDEX.may_load(storage, lease_addr.clone())? {
+            info.customer = new_customer.clone();
+            Self::INDEX.save(storage, lease_addr.clone(), &info)?;
+        }
+
+        Self::add(storage, new_customer, lease_addr).map_err(Into::into)
+    }
+
+    /// Whether the lease has been opened since the index was introduced
+    pub fn indexed(storage: &dyn Storage, lease_addr: Addr) -> bool {
+        Self::INDEX.has(storage, lease_addr)
+    }
+
+    /// The currency of a lease opened since the index was introduced
+    pub fn currency(storage: &dyn Storage, lease_addr: Addr) -> StdResult<Option<SymbolOwned>> {
+        Self::INDEX
+            .may_load(storage, lease_addr)
+            .map(|may_info| may_info.map(|info| info.currency))
+    }
+
+    /// Up to `max_leases` leases, paired with their customers, ordered by customer
+    ///
+    /// The batch starts with the leases of `start_customer`, if provided, and never
+    /// splits the leases of a customer. The customer the next batch should start with
+    /// is returned too, if any.
+    pub fn batch_with_customers(
+        storage: &dyn Storage,
+        start_customer: Option<Addr>,
+        max_leases: usize,
+    ) -> StdResult<(Vec<(Addr, Addr)>, Option<Addr>)> {
+        let mut leases_left = max_leases;
+        let mut batch = vec![];
+        Self::STORAGE
+            .range(
+                storage,
+                start_customer.map(Bound::<Addr>::inclusive),
+                None,
+                Order::Ascending,
+            )
+            .find_map(|record| match record {
+                Ok((customer, leases)) => {
+                    if let Some(left) = leases_left.checked_sub(leases.len()) {
+                        leases_left = left;
+                        batch.extend(leases.into_iter().map(|lease| (customer.clone(), lease)));
+                        None
+                    } else {
+                        Some(Ok(customer))
+                    }
+                }
+                Err(err) => Some(Err(err)),
+            })
+            .transpose()
+            .map(|next_customer| (batch, next_customer))
     }
 
     pub fn get(storage: &dyn Storage, owner_addr: Addr) -> StdResult<HashSet<Addr>> {
@@ -77,6 +182,30 @@ impl Leases {
         })
     }
 
+    /// Leases ordered by address, optionally only those in the given currency
+    pub fn all(
+        storage: &dyn Storage,
+        start_after: Option<Addr>,
+        limit: usize,
+        currency: Option<SymbolOwned>,
+    ) -> StdResult<Vec<LeaseInfo>> {
+        let start_bound = start_after.map(Bound::<Addr>::exclusive);
+        if let Some(currency) = currency {
+            Self::BY_CURRENCY
+                .prefix(currency)
+                .keys(storage, start_bound, None, Order::Ascending)
+                .take(limit)
+                .map(|lease| lease.and_then(|lease| Self::INDEX.load(storage, lease)))
+                .collect()
+        } else {
+            Self::INDEX
+                .range(storage, start_bound, None, Order::Ascending)
+                .take(limit)
+                .map(|record| record.map(|(_lease, info)| info))
+                .collect()
+        }
+    }
+
     pub fn iter(
         storage: &dyn Storage,
         next_customer: Option<Addr>,
@@ -91,16 +220,43 @@ impl Leases {
                     .map_err(Into::into)
             })
     }
+
+    fn add(storage: &mut dyn Storage, customer: Addr, lease_addr: Addr) -> StdResult<()> {
+        // update function for new or existing keys
+        let update = |d: Option<HashSet<Addr>>| -> StdResult<HashSet<Addr>> {
+            match d {
+                Some(mut loans) => {
+                    loans.insert(lease_addr);
+                    Ok(loans)
+                }
+                None => {
+                    let mut loans = HashSet::new();
+                    loans.insert(lease_addr);
+                    Ok(loans)
+                }
+            }
+        };
+
+        Self::STORAGE.update(storage, customer, update).map(|_| ())
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashSet;
+
     use sdk::{
-        cosmwasm_std::{testing, Addr},
+        cosmwasm_std::{testing, Addr, Storage, Timestamp},
         cw_storage_plus::Item,
     };
 
-    use crate::state::leases::{InstantiateReplyId, Leases};
+    use crate::{
+        error::ContractError,
+        state::leases::{InstantiateReplyId, LeaseInfo, Leases},
+    };
+
+    const CURRENCY: &str = "ATOM";
+    const OTHER_CURRENCY: &str = "OSMO";
 
     #[test]
     fn test_id_overflow() {
@@ -110,11 +266,197 @@ mod test {
             .save(&mut deps.storage, &(InstantiateReplyId::MAX - 1))
             .unwrap();
 
-        let id = Leases::next(&mut deps.storage, Addr::unchecked("test")).unwrap();
+        let id = Leases::next(&mut deps.storage, Addr::unchecked("test"), CURRENCY.into()).unwrap();
         assert_eq!(id, InstantiateReplyId::MAX);
 
-        // overflow
-        let id = Leases::next(&mut deps.storage, Addr::unchecked("test")).unwrap();
-        assert_eq!(id, 0);
+        // overflow, skipping the id reserved for the migrations
+        let id = Leases::next(&mut deps.storage, Addr::unchecked("test"), CURRENCY.into()).unwrap();
+        assert_eq!(id, 1);
+    }
+
+    #[test]
+    fn change_customer() {
+        let mut deps = testing::mock_dependencies();
+        let customer = Addr::unchecked("customer");
+        let new_customer = Addr::unchecked("new_customer");
+        let lease1 = Addr::unchecked("lease1");
+        let lease2 = Addr::unchecked("lease2");
+
+        save_lease(&mut deps.storage, customer.clone(), lease1.clone());
+        save_lease(&mut deps.storage, customer.clone(), lease2.clone());
+
+        Leases::change_customer(
+            &mut deps.storage,
+            lease1.clone(),
+            customer.clone(),
+            new_customer.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            HashSet::from([lease2.clone()]),
+            Leases::get(&deps.storage, customer.clone()).unwrap()
+        );
+        assert_eq!(
+            HashSet::from([lease1]),
+            Leases::get(&deps.storage, new_customer.clone()).unwrap()
+        );
+
+        Leases::change_customer(
+            &mut deps.storage,
+            lease2.clone(),
+            customer.clone(),
+            new_customer.clone(),
+        )
+        .unwrap();
+        assert!(Leases::get(&deps.storage, customer.clone())
+            .unwrap()
+            .is_empty());
+        assert_eq!(1, Leases::iter(&deps.storage, None).count());
+        assert_eq!(
+            2,
+            Leases::get(&deps.storage, new_customer.clone())
+                .unwrap()
+                .len()
+        );
+        assert!(Leases::all(&deps.storage, None, 10, None)
+            .unwrap()
+            .iter()
+            .all(|info| info.customer == new_customer));
+    }
+
+    #[test]
+    fn all_leases() {
+        let mut deps = testing::mock_dependencies();
+        let customer = Addr::unchecked("customer");
+        let lease1 = Addr::unchecked("lease1");
+        let lease2 = Addr::unchecked("lease2");
+        let lease3 = Addr::unchecked("lease3");
+
+        save_lease_in(
+            &mut deps.storage,
+            customer.clone(),
+            lease3.clone(),
+            CURRENCY,
+        );
+        save_lease_in(
+            &mut deps.storage,
+            customer.clone(),
+            lease1.clone(),
+            CURRENCY,
+        );
+        save_lease_in(
+            &mut deps.storage,
+            customer.clone(),
+            lease2.clone(),
+            OTHER_CURRENCY,
+        );
+
+        let leases = |start_after: Option<&Addr>, limit, currency: Option<&str>| {
+            Leases::all(
+                &deps.storage,
+                start_after.cloned(),
+                limit,
+                currency.map(Into::into),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|info| info.lease)
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec![lease1.clone(), lease2.clone(), lease3.clone()],
+            leases(None, 10, None)
+        );
+        assert_eq!(vec![lease1.clone()], leases(None, 1, None));
+        assert_eq!(
+            vec![lease2.clone(), lease3.clone()],
+            leases(Some(&lease1), 10, None)
+        );
+        assert_eq!(
+            vec![lease1.clone(), lease3.clone()],
+            leases(None, 10, Some(CURRENCY))
+        );
+        assert_eq!(
+            vec![lease3.clone()],
+            leases(Some(&lease1), 10, Some(CURRENCY))
+        );
+        assert_eq!(vec![lease2.clone()], leases(None, 10, Some(OTHER_CURRENCY)));
+        assert!(leases(None, 10, Some("NLS")).is_empty());
+
+        assert_eq!(
+            vec![LeaseInfo {
+                lease: lease2,
+                customer,
+                currency: OTHER_CURRENCY.into(),
+                opened_at: Timestamp::from_seconds(100),
+            }],
+            Leases::all(&deps.storage, Some(lease1), 1, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn change_customer_unknown_lease() {
+        let mut deps = testing::mock_dependencies();
+        let customer = Addr::unchecked("customer");
+        let lease = Addr::unchecked("lease");
+
+        assert_eq!(
+            Err(ContractError::UnknownLease {
+                lease: lease.clone(),
+                customer: customer.clone(),
+            }),
+            Leases::change_customer(
+                &mut deps.storage,
+                lease,
+                customer,
+                Addr::unchecked("new_customer"),
+            )
+        );
+    }
+
+    #[test]
+    fn batch_with_customers() {
+        let mut deps = testing::mock_dependencies();
+        let customer1 = Addr::unchecked("customer1");
+        let customer2 = Addr::unchecked("customer2");
+        let lease1 = Addr::unchecked("lease1");
+        let lease2 = Addr::unchecked("lease2");
+        let lease3 = Addr::unchecked("lease3");
+
+        save_lease(&mut deps.storage, customer1.clone(), lease1.clone());
+        save_lease(&mut deps.storage, customer2.clone(), lease2.clone());
+        save_lease(&mut deps.storage, customer2.clone(), lease3.clone());
+
+        assert_eq!(
+            (
+                vec![(customer1.clone(), lease1.clone())],
+                Some(customer2.clone())
+            ),
+            Leases::batch_with_customers(&deps.storage, None, 2).unwrap()
+        );
+
+        let (mut batch, next_customer) =
+            Leases::batch_with_customers(&deps.storage, Some(customer2.clone()), 2).unwrap();
+        batch.sort();
+        assert_eq!(
+            vec![(customer2.clone(), lease2), (customer2.clone(), lease3)],
+            batch
+        );
+        assert_eq!(None, next_customer);
+
+        assert_eq!(
+            (vec![], Some(customer1)),
+            Leases::batch_with_customers(&deps.storage, None, 0).unwrap()
+        );
+    }
+
+    fn save_lease(storage: &mut dyn Storage, customer: Addr, lease: Addr) {
+        save_lease_in(storage, customer, lease, CURRENCY)
+    }
+
+    fn save_lease_in(storage: &mut dyn Storage, customer: Addr, lease: Addr, currency: &str) {
+        let id = Leases::next(storage, customer, currency.into()).unwrap();
+        Leases::save(storage, id, lease, Timestamp::from_seconds(100)).unwrap();
     }
 }
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use sdk::{
+    cosmwasm_std::{Addr, StdResult, Storage, Timestamp, Uint64},
+    cw_storage_plus::Item,
+    schemars::{self, JsonSchema},
+};
+
+use crate::{msg::MaxLeases, result::ContractResult, ContractError};
+
+/// The progress of the last lease migration
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MigrationStatus {
+    /// The lease code the leases are migrated to
+    pub new_code_id: Uint64,
+    /// The customer whose leases are migrated next, `None` once all leases are processed
+    pub next_customer: Option<Addr>,
+    /// The number of leases migrated successfully
+    pub migrated: MaxLeases,
+    /// The number of leases failed to migrate
+    pub failed: MaxLeases,
+    /// The time the migration started at
+    pub started_at: Timestamp,
+}
+
+impl MigrationStatus {
+    const STORAGE: Item<'static, Self> = Item::new("migration_status");
+
+    pub fn load(storage: &dyn Storage) -> StdResult<Option<Self>> {
+        Self::STORAGE.may_load(storage)
+    }
+
+    pub fn start(
+        storage: &mut dyn Storage,
+        new_code_id: u64,
+        next_customer: Option<Addr>,
+        migrated: MaxLeases,
+        started_at: Timestamp,
+    ) -> StdResult<()> {
+        Self::STORAGE.save(
+            storage,
+            &Self {
+                new_code_id: new_code_id.into(),
+                next_customer,
+                migrated,
+                failed: 0,
+                started_at,
+            },
+        )
+    }
+
+    /// The customer the migration in progress continues from
+    pub fn resume_from(storage: &dyn Storage) -> ContractResult<Addr> {
+        Self::load(storage)?
+            .and_then(|status| status.next_customer)
+            .ok_or(ContractError::NoMigrationInProgress {})
+    }
+
+    /// Record the progress of a migration, a no-op for migrations started before the status
+    /// introduction
+    pub fn proceed(
+        storage: &mut dyn Storage,
+        next_customer: Option<Addr>,
+        migrated: MaxLeases,
+    ) -> StdResult<()> {
+        Self::load(storage)?.map_or(Ok(()), |mut status| {
+            status.next_customer = next_customer;
+            status.migrated = status.migrated.saturating_add(migrated);
+            Self::STORAGE.save(storage, &status)
+        })
+    }
+
+    /// Account for a lease migration reported as failed
+    pub fn fail_one(storage: &mut dyn Storage) -> ContractResult<()> {
+        Self::STORAGE
+            .update(storage, |mut status| -> StdResult<Self> {
+                status.migrated = status.migrated.saturating_sub(1);
+                status.failed = status.failed.saturating_add(1);
+                Ok(status)
+            })
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+}
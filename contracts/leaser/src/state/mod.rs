@@ -1,2 +1,6 @@
+pub mod allowlist;
 pub mod config;
+pub mod exposure;
 pub mod leases;
+pub mod migration;
+pub mod stats;
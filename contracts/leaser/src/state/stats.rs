@@ -0,0 +1,292 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use currency::SymbolOwned;
+use finance::coin::Amount;
+use lease::api::{LeaseCoin, LpnCoin};
+use sdk::{
+    cosmwasm_std::{Addr, StdResult, Storage},
+    cw_storage_plus::{Item, Map},
+    schemars::{self, JsonSchema},
+};
+
+/// Protocol-wide figures of the leases opened by the leaser
+///
+/// Covers the leases instantiated since the statistics were introduced.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Stats {
+    /// The number of leases instantiated and buying their asset
+    pub opening: u32,
+    /// The number of open leases
+    pub opened: u32,
+    /// The number of leases fully repaid and closed
+    pub closed: u32,
+    /// The number of fully liquidated leases
+    pub liquidated: u32,
+    /// The asset of the open leases per lease currency
+    pub assets: BTreeMap<SymbolOwned, Amount>,
+    /// The total loan principal, in LPN, the open leases owe
+    pub borrowed: Amount,
+}
+
+#[derive(Serialize, Deserialize)]
+struct OpenLease {
+    currency: SymbolOwned,
+    amount: Amount,
+    #[serde(default)]
+    borrow: Amount,
+}
+
+impl Stats {
+    const STORAGE: Item<'static, Self> = Item::new("stats");
+    // opening lease to nothing
+    const OPENING: Map<'static, Addr, ()> = Map::new("stats_opening");
+    // open lease to its asset and loan
+    const LEASES: Map<'static, Addr, OpenLease> = Map::new("stats_leases");
+
+    pub fn load(storage: &dyn Storage) -> StdResult<Self> {
+        Self::STORAGE
+            .may_load(storage)
+            .map(Option::unwrap_or_default)
+    }
+
+    /// Whether the lease is accounted as open
+    pub fn accounted(storage: &dyn Storage, lease: Addr) -> bool {
+        Self::LEASES.has(storage, lease)
+    }
+
+    pub fn instantiated(storage: &mut dyn Storage, lease: Addr) -> StdResult<()> {
+        Self::OPENING.save(storage, lease, &())?;
+        Self::update(storage, |stats| stats.opening += 1)
+    }
+
+    /// Account an open lease, a no-op for leases already accounted
+    pub fn opened(
+        storage: &mut dyn Storage,
+        lease: Addr,
+        amount: LeaseCoin,
+        loan: LpnCoin,
+    ) -> StdResult<()> {
+        if Self::accounted(storage, lease.clone()) {
+            return Ok(());
+        }
+        let was_opening = Self::stop_opening(storage, lease.clone());
+        Self::update(storage, |stats| {
+            if was_opening {
+                stats.opening = stats.opening.saturating_sub(1);
+            }
+            stats.opened += 1;
+            stats.add_asset(amount.ticker(), amount.amount());
+            stats.borrowed = stats.borrowed.saturating_add(loan.amount());
+        })?;
+        Self::LEASES.save(
+            storage,
+            lease,
+            &OpenLease {
+                currency: amount.ticker().clone(),
+                amount: amount.amount(),
+                borrow: loan.amount(),
+            },
+        )
+    }
+
+    /// Account the current asset and loan of an open lease, a no-op for leases not accounted
+    pub fn updated(
+        storage: &mut dyn Storage,
+        lease: Addr,
+        amount: Amount,
+        borrow: Amount,
+    ) -> StdResult<()> {
+        if let Some(mut open_lease) = Self::LEASES.may_load(storage, lease.clone())? {
+            Self::update(storage, |stats| {
+                stats.sub_asset(&open_lease.currency, open_lease.amount);
+                stats.add_asset(&open_lease.currency, amount);
+                stats.borrowed = stats
+                    .borrowed
+                    .saturating_sub(open_lease.borrow)
+                    .saturating_add(borrow);
+            })?;
+            open_lease.amount = amount;
+            open_lease.borrow = borrow;
+            Self::LEASES.save(storage, lease, &open_lease)?;
+        }
+        Ok(())
+    }
+
+    /// Account a closed lease, a no-op for leases not accounted
+    ///
+    /// A lease closed while still opening is only taken out of the opening ones.
+    pub fn closed(storage: &mut dyn Storage, lease: Addr, liquidated: bool) -> StdResult<()> {
+        if Self::stop_opening(storage, lease.clone()) {
+            Self::update(storage, |stats| {
+                stats.opening = stats.opening.saturating_sub(1)
+            })?;
+        }
+        if let Some(open_lease) = Self::LEASES.may_load(storage, lease.clone())? {
+            Self::LEASES.remove(storage, lease);
+            Self::update(storage, |stats| {
+                stats.opened = stats.opened.saturating_sub(1);
+                stats.sub_asset(&open_lease.currency, open_lease.amount);
+                stats.borrowed = stats.borrowed.saturating_sub(open_lease.borrow);
+                if liquidated {
+                    stats.liquidated += 1;
+                } else {
+                    stats.closed += 1;
+                }
+            })?;
+        }
+        Ok(())
+    }
+
+    fn update<F>(storage: &mut dyn Storage, f: F) -> StdResult<()>
+    where
+        F: FnOnce(&mut Self),
+    {
+        let mut stats = Self::load(storage)?;
+        f(&mut stats);
+        Self::STORAGE.save(storage, &stats)
+    }
+
+    /// Stop tracking a lease as opening, return whether it has been tracked
+    fn stop_opening(storage: &mut dyn Storage, lease: Addr) -> bool {
+        let opening = Self::OPENING.has(storage, lease.clone());
+        if opening {
+            Self::OPENING.remove(storage, lease);
+        }
+        opening
+    }
+
+    fn add_asset(&mut self, currency: &SymbolOwned, amount: Amount) {
+        if amount != 0 {
+            let total = self.assets.entry(currency.clone()).or_default();
+            *total = total.saturating_add(amount);
+        }
+    }
+
+    fn sub_asset(&mut self, currency: &SymbolOwned, amount: Amount) {
+        if let Some(total) = self.assets.get_mut(currency) {
+            *total = total.saturating_sub(amount);
+            if *total == 0 {
+                self.assets.remove(currency);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use currency::{lease::Atom, test::Usdc, Currency};
+    use finance::coin::Coin;
+    use lease::api::{LeaseCoin, LpnCoin};
+    use sdk::cosmwasm_std::{testing, Addr};
+
+    use super::Stats;
+
+    fn lease_coin(amount: u128) -> LeaseCoin {
+        Coin::<Atom>::new(amount).into()
+    }
+
+    fn lpn_coin(amount: u128) -> LpnCoin {
+        Coin::<Usdc>::new(amount).into()
+    }
+
+    #[test]
+    fn open_close() {
+        let mut deps = testing::mock_dependencies();
+        let lease1 = Addr::unchecked("lease1");
+        let lease2 = Addr::unchecked("lease2");
+
+        Stats::instantiated(&mut deps.storage, lease1.clone()).unwrap();
+        Stats::instantiated(&mut deps.storage, lease2.clone()).unwrap();
+        Stats::opened(
+            &mut deps.storage,
+            lease1.clone(),
+            lease_coin(100),
+            lpn_coin(60),
+        )
+        .unwrap();
+        Stats::opened(
+            &mut deps.storage,
+            lease1.clone(),
+            lease_coin(100),
+            lpn_coin(60),
+        )
+        .unwrap();
+        Stats::opened(
+            &mut deps.storage,
+            lease2.clone(),
+            lease_coin(50),
+            lpn_coin(30),
+        )
+        .unwrap();
+
+        let stats = Stats::load(&deps.storage).unwrap();
+        assert_eq!(0, stats.opening);
+        assert_eq!(2, stats.opened);
+        assert_eq!(Some(&150), stats.assets.get(Atom::TICKER));
+        assert_eq!(90, stats.borrowed);
+
+        Stats::updated(&mut deps.storage, lease1.clone(), 70, 40).unwrap();
+        Stats::updated(&mut deps.storage, Addr::unchecked("unknown"), 30, 10).unwrap();
+        let stats = Stats::load(&deps.storage).unwrap();
+        assert_eq!(Some(&120), stats.assets.get(Atom::TICKER));
+        assert_eq!(70, stats.borrowed);
+
+        Stats::updated(&mut deps.storage, lease1.clone(), 90, 65).unwrap();
+        let stats = Stats::load(&deps.storage).unwrap();
+        assert_eq!(Some(&140), stats.assets.get(Atom::TICKER));
+        assert_eq!(95, stats.borrowed);
+
+        Stats::closed(&mut deps.storage, lease1.clone(), false).unwrap();
+        Stats::closed(&mut deps.storage, lease1, false).unwrap();
+        Stats::closed(&mut deps.storage, lease2, true).unwrap();
+
+        let stats = Stats::load(&deps.storage).unwrap();
+        assert_eq!(0, stats.opened);
+        assert_eq!(1, stats.closed);
+        assert_eq!(1, stats.liquidated);
+        assert!(stats.assets.is_empty());
+        assert_eq!(0, stats.borrowed);
+    }
+
+    #[test]
+    fn opening_only_once() {
+        let mut deps = testing::mock_dependencies();
+        let lease1 = Addr::unchecked("lease1");
+        let lease2 = Addr::unchecked("lease2");
+
+        Stats::instantiated(&mut deps.storage, lease1.clone()).unwrap();
+        Stats::instantiated(&mut deps.storage, lease2.clone()).unwrap();
+        // instantiated before the statistics
+        Stats::opened(
+            &mut deps.storage,
+            Addr::unchecked("lease0"),
+            lease_coin(100),
+            lpn_coin(60),
+        )
+        .unwrap();
+        assert_eq!(2, Stats::load(&deps.storage).unwrap().opening);
+
+        Stats::closed(&mut deps.storage, lease1.clone(), true).unwrap();
+        Stats::closed(&mut deps.storage, lease1, true).unwrap();
+        let stats = Stats::load(&deps.storage).unwrap();
+        assert_eq!(1, stats.opening);
+        assert_eq!(1, stats.opened);
+        assert_eq!(0, stats.liquidated);
+
+        Stats::opened(
+            &mut deps.storage,
+            lease2.clone(),
+            lease_coin(50),
+            lpn_coin(30),
+        )
+        .unwrap();
+        Stats::closed(&mut deps.storage, lease2, false).unwrap();
+        let stats = Stats::load(&deps.storage).unwrap();
+        assert_eq!(0, stats.opening);
+        assert_eq!(1, stats.opened);
+        assert_eq!(1, stats.closed);
+    }
+}
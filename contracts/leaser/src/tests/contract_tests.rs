@@ -1,26 +1,41 @@
 use serde::{Deserialize, Serialize};
 
-use currency::{test::Usdc, Currency};
-use finance::{duration::Duration, liability::Liability, percent::Percent};
-use lease::api::{ConnectionParams, Ics20Channel, InterestPaymentSpec};
+use currency::{lease::Atom, test::Usdc, Currency};
+use finance::{
+    coin::Coin,
+    duration::Duration,
+    liability::Liability,
+    percent::{bound::BoundToHundredPercent, Percent},
+};
+use lease::api::{
+    AuctionSpec, ConnectionParams, Ics20Channel, InterestPaymentSpec, LiquidationFee,
+    LiquidationMode, LpnCoin, ProtocolPause, RateType,
+};
+use lpp::{borrow::InterestRate, msg::QueryMsg as LppQueryMsg, state::Config as LppConfig};
 use sdk::{
     cosmwasm_ext::Response,
     cosmwasm_std::{
-        coins, from_binary,
-        testing::{mock_env, mock_info},
-        to_binary, Addr, CosmosMsg, Deps, DepsMut, MessageInfo, SubMsg, Uint64, WasmMsg,
+        coins, from_binary, from_slice,
+        testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+        to_binary, Addr, Binary, ContractResult as CwContractResult, CosmosMsg, Deps, DepsMut,
+        Event, MessageInfo, OwnedDeps, Reply, SubMsg, SubMsgResult, SystemError, SystemResult,
+        Uint64, WasmMsg, WasmQuery,
     },
     schemars::{self, JsonSchema},
-    testing::mock_deps_with_contracts,
 };
 
 use crate::{
     cmd::Borrow,
-    contract::{execute, instantiate, query, sudo},
+    contract::{execute, instantiate, query, reply, sudo},
     error::ContractError,
-    msg::{ConfigResponse, ExecuteMsg, QueryMsg, SudoMsg},
+    msg::{AllowlistResponse, ConfigResponse, ExecuteMsg, QueryMsg, SudoMsg},
     result::ContractResult,
-    state::config::Config,
+    state::{
+        config::Config,
+        exposure::{Exposure, ExposureCaps},
+        migration::MigrationStatus,
+        stats::Stats,
+    },
 };
 
 const CREATOR: &str = "creator";
@@ -34,6 +49,21 @@ type TheCurrency = Usdc;
 const DENOM: &str = TheCurrency::TICKER;
 const MARGIN_INTEREST_RATE: Percent = Percent::from_permille(30);
 
+fn lpn_coin(amount: u128) -> LpnCoin {
+    Coin::<TheCurrency>::new(amount).into()
+}
+
+fn max_slippage(percent: u16) -> BoundToHundredPercent {
+    Percent::from_percent(percent).try_into().unwrap()
+}
+
+fn liquidation_fee(penalty: u16, keeper_bounty: u16) -> LiquidationFee {
+    LiquidationFee {
+        penalty: Percent::from_percent(penalty).try_into().unwrap(),
+        keeper_bounty: Percent::from_percent(keeper_bounty).try_into().unwrap(),
+    }
+}
+
 fn leaser_instantiate_msg(lease_code_id: u64, lpp_addr: Addr) -> crate::msg::InstantiateMsg {
     crate::msg::InstantiateMsg {
         lease_code_id: Uint64::new(lease_code_id),
@@ -48,6 +78,10 @@ fn leaser_instantiate_msg(lease_code_id: u64, lpp_addr: Addr) -> crate::msg::Ins
             Percent::from_percent(2),
             Duration::from_hours(1),
         ),
+        min_liquidation: lpn_coin(10_000),
+        min_asset: lpn_coin(15_000_000),
+        max_slippage: max_slippage(3),
+        liquidation_fee: liquidation_fee(2, 10),
         lease_interest_payment: InterestPaymentSpec::new(
             Duration::from_days(90),
             Duration::from_days(10),
@@ -58,6 +92,51 @@ fn leaser_instantiate_msg(lease_code_id: u64, lpp_addr: Addr) -> crate::msg::Ins
     }
 }
 
+/// Mocks the contracts the leaser validates on instantiation and the LPP configuration query
+fn mock_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::ContractInfo { contract_addr }
+            if [LPP_ADDR, TIMEALARMS_ADDR, PROFIT_ADDR, ORACLE_ADDR]
+                .contains(&contract_addr.as_str()) =>
+        {
+            SystemResult::Ok(CwContractResult::Ok(Binary(Vec::from(
+                br#"{"code_id":0,"creator":"","admin":null,"pinned":false,"ibc_port":null}"#
+                    as &[u8],
+            ))))
+        }
+        WasmQuery::Smart { contract_addr, msg }
+            if contract_addr == LPP_ADDR
+                && matches!(from_binary::<LppQueryMsg>(msg), Ok(LppQueryMsg::Config())) =>
+        {
+            SystemResult::Ok(CwContractResult::Ok(to_binary(&lpp_config()).unwrap()))
+        }
+        WasmQuery::Smart { contract_addr, .. }
+        | WasmQuery::Raw { contract_addr, .. }
+        | WasmQuery::ContractInfo { contract_addr, .. } => {
+            SystemResult::Err(SystemError::NoSuchContract {
+                addr: contract_addr.clone(),
+            })
+        }
+        _ => unimplemented!(),
+    });
+    deps
+}
+
+fn lpp_config() -> LppConfig {
+    LppConfig::new(
+        TheCurrency::TICKER.into(),
+        Uint64::new(1),
+        InterestRate::new(
+            Percent::from_percent(7),
+            Percent::from_percent(70),
+            Percent::from_percent(2),
+        )
+        .unwrap(),
+        Percent::ZERO.try_into().unwrap(),
+    )
+}
+
 fn owner() -> MessageInfo {
     mock_info(CREATOR, &coins(2, DENOM))
 }
@@ -101,7 +180,7 @@ fn setup_dex(deps: DepsMut<'_>) -> ContractResult<Response> {
 
 #[test]
 fn proper_initialization() {
-    let mut deps = mock_deps_with_contracts([LPP_ADDR, TIMEALARMS_ADDR, PROFIT_ADDR, ORACLE_ADDR]);
+    let mut deps = mock_deps();
 
     let lpp_addr = Addr::unchecked(LPP_ADDR);
     let msg = leaser_instantiate_msg(1, lpp_addr.clone());
@@ -119,7 +198,7 @@ fn proper_initialization() {
 
 #[test]
 fn test_update_config() {
-    let mut deps = mock_deps_with_contracts([LPP_ADDR, TIMEALARMS_ADDR, PROFIT_ADDR, ORACLE_ADDR]);
+    let mut deps = mock_deps();
 
     let expected_liability = Liability::new(
         Percent::from_percent(55),
@@ -138,6 +217,10 @@ fn test_update_config() {
     let msg = SudoMsg::Config {
         lease_interest_rate_margin: Percent::from_percent(5),
         liability: expected_liability,
+        min_liquidation: lpn_coin(1_000),
+        min_asset: lpn_coin(25_000_000),
+        max_slippage: max_slippage(5),
+        liquidation_fee: liquidation_fee(3, 20),
         lease_interest_payment: expected_repaiment.clone(),
     };
 
@@ -145,13 +228,94 @@ fn test_update_config() {
 
     let config = query_config(deps.as_ref());
     assert_eq!(expected_liability, config.liability);
+    assert_eq!(lpn_coin(1_000), config.min_liquidation);
+    assert_eq!(lpn_coin(25_000_000), config.min_asset);
+    assert_eq!(max_slippage(5), config.max_slippage);
+    assert_eq!(liquidation_fee(3, 20), config.liquidation_fee);
     assert_eq!(expected_repaiment, config.lease_interest_payment);
 }
 
+#[test]
+fn test_switch_liquidation_mode() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+    let config = query_config(deps.as_ref());
+    assert_eq!(LiquidationMode::Market, config.liquidation_mode);
+
+    let auction = LiquidationMode::Auction(AuctionSpec {
+        premium: Percent::from_percent(5),
+        decay: Percent::from_percent(2).try_into().unwrap(),
+        floor: Percent::from_percent(90).try_into().unwrap(),
+        period: Duration::from_hours(1),
+    });
+    sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::SwitchLiquidationMode(auction),
+    )
+    .unwrap();
+
+    assert_eq!(
+        Config {
+            liquidation_mode: auction,
+            ..config
+        },
+        query_config(deps.as_ref())
+    );
+}
+
+#[test]
+fn instantiate_min_amounts_not_in_lpn() {
+    let mut deps = mock_deps();
+
+    let min_asset: LpnCoin = Coin::<Atom>::new(15_000_000).into();
+    let msg = crate::msg::InstantiateMsg {
+        min_asset: min_asset.clone(),
+        ..leaser_instantiate_msg(1, Addr::unchecked(LPP_ADDR))
+    };
+
+    assert_eq!(
+        instantiate(deps.as_mut(), mock_env(), owner(), msg),
+        Err(ContractError::InvalidMinAmounts {
+            min_liquidation: lpn_coin(10_000),
+            min_asset,
+        })
+    );
+}
+
+#[test]
+fn test_update_config_invalid_min_amounts() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+    let config = query_config(deps.as_ref());
+
+    let msg = SudoMsg::Config {
+        lease_interest_rate_margin: config.lease_interest_rate_margin,
+        liability: config.liability,
+        min_liquidation: lpn_coin(15_000_001),
+        min_asset: lpn_coin(15_000_000),
+        max_slippage: config.max_slippage,
+        liquidation_fee: config.liquidation_fee,
+        lease_interest_payment: config.lease_interest_payment.clone(),
+    };
+
+    let res = sudo(deps.as_mut(), mock_env(), msg);
+    assert_eq!(
+        res,
+        Err(ContractError::InvalidMinAmounts {
+            min_liquidation: lpn_coin(15_000_001),
+            min_asset: lpn_coin(15_000_000),
+        })
+    );
+    assert_eq!(config, query_config(deps.as_ref()));
+}
+
 #[test]
 #[should_panic(expected = "Healthy % should be < first liquidation %")]
 fn test_update_config_invalid_liability() {
-    let mut deps = mock_deps_with_contracts([LPP_ADDR, TIMEALARMS_ADDR, PROFIT_ADDR, ORACLE_ADDR]);
+    let mut deps = mock_deps();
 
     #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
     #[serde(rename_all = "snake_case")]
@@ -171,6 +335,10 @@ fn test_update_config_invalid_liability() {
         Config {
             lease_interest_rate_margin: Percent,
             liability: Liability,
+            min_liquidation: LpnCoin,
+            min_asset: LpnCoin,
+            max_slippage: Percent,
+            liquidation_fee: LiquidationFee,
             lease_interest_payment: InterestPaymentSpec,
         },
     }
@@ -187,6 +355,10 @@ fn test_update_config_invalid_liability() {
     let mock_msg = MockSudoMsg::Config {
         lease_interest_rate_margin: Percent::from_percent(5),
         liability,
+        min_liquidation: lpn_coin(10_000),
+        min_asset: lpn_coin(15_000_000),
+        max_slippage: Percent::from_percent(3),
+        liquidation_fee: liquidation_fee(2, 10),
         lease_interest_payment: InterestPaymentSpec::new(
             Duration::from_secs(20),
             Duration::from_secs(10),
@@ -202,7 +374,7 @@ fn test_update_config_invalid_liability() {
 
 #[test]
 fn test_no_dex_setup() {
-    let mut deps = mock_deps_with_contracts([LPP_ADDR, TIMEALARMS_ADDR, PROFIT_ADDR, ORACLE_ADDR]);
+    let mut deps = mock_deps();
 
     setup_test_case(deps.as_mut());
 
@@ -212,6 +384,7 @@ fn test_no_dex_setup() {
     let msg = ExecuteMsg::OpenLease {
         currency: DENOM.to_string(),
         max_ltd: None,
+        rate_type: RateType::Fixed,
     };
 
     let res = execute(deps.as_mut(), mock_env(), customer(), msg);
@@ -220,7 +393,7 @@ fn test_no_dex_setup() {
 
 #[test]
 fn test_setup_dex_again() {
-    let mut deps = mock_deps_with_contracts([LPP_ADDR, TIMEALARMS_ADDR, PROFIT_ADDR, ORACLE_ADDR]);
+    let mut deps = mock_deps();
 
     setup_test_case(deps.as_mut());
 
@@ -230,8 +403,47 @@ fn test_setup_dex_again() {
     assert_eq!(res, Err(ContractError::DEXConnectivityAlreadySetup {}));
 }
 
-fn open_lease_with(max_ltd: Option<Percent>) {
-    let mut deps = mock_deps_with_contracts([LPP_ADDR, TIMEALARMS_ADDR, PROFIT_ADDR, ORACLE_ADDR]);
+#[test]
+fn test_pause() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+    setup_dex_ok(deps.as_mut());
+    assert_eq!(ProtocolPause::default(), query_pause(deps.as_ref()));
+
+    let pause = ProtocolPause {
+        openings: true,
+        borrows: false,
+        liquidations: true,
+        repayments: false,
+    };
+    sudo(deps.as_mut(), mock_env(), SudoMsg::Pause(pause)).unwrap();
+    assert_eq!(pause, query_pause(deps.as_ref()));
+
+    let msg = ExecuteMsg::OpenLease {
+        currency: DENOM.to_string(),
+        max_ltd: None,
+        rate_type: RateType::Fixed,
+    };
+    let res = execute(deps.as_mut(), mock_env(), customer(), msg);
+    assert_eq!(res, Err(ContractError::OpeningsPaused {}));
+
+    sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::Pause(ProtocolPause::default()),
+    )
+    .unwrap();
+    assert_eq!(ProtocolPause::default(), query_pause(deps.as_ref()));
+}
+
+fn query_pause(deps: Deps<'_>) -> ProtocolPause {
+    let res = query(deps, mock_env(), QueryMsg::ProtocolPause {}).unwrap();
+    from_binary(&res).unwrap()
+}
+
+fn open_lease_with(max_ltd: Option<Percent>, rate_type: RateType) {
+    let mut deps = mock_deps();
 
     setup_test_case(deps.as_mut());
     setup_dex_ok(deps.as_mut());
@@ -241,13 +453,15 @@ fn open_lease_with(max_ltd: Option<Percent>) {
     let msg = ExecuteMsg::OpenLease {
         currency: DENOM.to_string(),
         max_ltd,
+        rate_type,
     };
     let info = customer();
     let env = mock_env();
     let admin = env.contract.address.clone();
     let res = execute(deps.as_mut(), env, info.clone(), msg).unwrap();
 
-    let msg = Borrow::open_lease_msg(info.sender, config, DENOM.to_string(), max_ltd).unwrap();
+    let msg =
+        Borrow::open_lease_msg(info.sender, config, DENOM.to_string(), max_ltd, rate_type).unwrap();
     assert_eq!(
         res.messages,
         vec![SubMsg::reply_on_success(
@@ -265,11 +479,459 @@ fn open_lease_with(max_ltd: Option<Percent>) {
 
 #[test]
 fn test_open_lease() {
-    open_lease_with(None);
+    open_lease_with(None, RateType::Fixed);
 }
 
 #[test]
 fn test_open_lease_with_max_ltd() {
-    open_lease_with(None);
-    open_lease_with(Some(Percent::from_percent(5)));
+    open_lease_with(None, RateType::Fixed);
+    open_lease_with(Some(Percent::from_percent(5)), RateType::Fixed);
+}
+
+#[test]
+fn test_open_lease_floating() {
+    open_lease_with(None, RateType::Floating);
+}
+
+#[test]
+fn test_fixed_rate_premium() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+    setup_dex_ok(deps.as_mut());
+    assert_eq!(
+        Percent::ZERO,
+        query_config(deps.as_ref()).fixed_rate_premium
+    );
+
+    let premium = Percent::from_permille(15);
+    sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::FixedRatePremium(premium),
+    )
+    .unwrap();
+
+    let config = query_config(deps.as_ref());
+    assert_eq!(premium, config.fixed_rate_premium);
+
+    let open_msg = |rate_type| {
+        Borrow::open_lease_msg(
+            customer().sender,
+            config.clone(),
+            DENOM.to_string(),
+            None,
+            rate_type,
+        )
+        .unwrap()
+    };
+    let fixed = open_msg(RateType::Fixed);
+    assert_eq!(RateType::Fixed, fixed.form.rate_type);
+    assert_eq!(
+        MARGIN_INTEREST_RATE + premium,
+        fixed.form.loan.annual_margin_interest
+    );
+
+    let floating = open_msg(RateType::Floating);
+    assert_eq!(RateType::Floating, floating.form.rate_type);
+    assert_eq!(
+        MARGIN_INTEREST_RATE,
+        floating.form.loan.annual_margin_interest
+    );
+}
+
+#[test]
+fn test_liability_override() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+    setup_dex_ok(deps.as_mut());
+    let global_liability = query_config(deps.as_ref()).liability;
+
+    let liability = Liability::new(
+        Percent::from_percent(50),
+        Percent::from_percent(5),
+        Percent::from_percent(5),
+        Percent::from_percent(1),
+        Percent::from_percent(2),
+        Percent::from_percent(1),
+        Duration::from_hours(1),
+    );
+    sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::LiabilityOverride {
+            currency: Atom::TICKER.into(),
+            liability,
+        },
+    )
+    .unwrap();
+
+    let config = query_config(deps.as_ref());
+    assert_eq!(global_liability, config.liability);
+    assert_eq!(
+        Some(&liability),
+        config.liability_overrides.get(Atom::TICKER)
+    );
+
+    let open_msg = |config: &Config, currency: &str| {
+        Borrow::open_lease_msg(
+            customer().sender,
+            config.clone(),
+            currency.into(),
+            None,
+            RateType::Fixed,
+        )
+        .unwrap()
+    };
+    assert_eq!(liability, open_msg(&config, Atom::TICKER).form.liability);
+    assert_eq!(global_liability, open_msg(&config, DENOM).form.liability);
+
+    sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::RemoveLiabilityOverride {
+            currency: Atom::TICKER.into(),
+        },
+    )
+    .unwrap();
+
+    let config = query_config(deps.as_ref());
+    assert!(config.liability_overrides.is_empty());
+    assert_eq!(
+        global_liability,
+        open_msg(&config, Atom::TICKER).form.liability
+    );
+}
+
+#[test]
+fn test_exposure_caps() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+    setup_dex_ok(deps.as_mut());
+    assert_eq!(
+        ExposureCaps::default(),
+        query_config(deps.as_ref()).exposure_caps
+    );
+
+    let caps = ExposureCaps {
+        max_customer_leases: Some(0),
+        max_currency_borrow: Some(lpn_coin(1_000_000)),
+        max_lease_borrow: Some(lpn_coin(10_000)),
+    };
+    sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::ExposureCaps(caps.clone()),
+    )
+    .unwrap();
+    assert_eq!(caps, query_config(deps.as_ref()).exposure_caps);
+
+    let msg = ExecuteMsg::OpenLease {
+        currency: DENOM.to_string(),
+        max_ltd: None,
+        rate_type: RateType::default(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), customer(), msg).unwrap_err();
+    assert_eq!(ContractError::CustomerLeasesCap { max: 0 }, err);
+
+    let lease = mock_info("lease", &[]);
+    Exposure::open(
+        deps.as_mut().storage,
+        lease.sender.clone(),
+        customer().sender,
+        Atom::TICKER.into(),
+        1_000,
+    )
+    .unwrap();
+    let msg = ExecuteMsg::ChangeLeaseCustomer {
+        customer: customer().sender,
+        new_customer: Addr::unchecked("new_customer"),
+    };
+    let err = execute(deps.as_mut(), mock_env(), lease, msg).unwrap_err();
+    assert_eq!(ContractError::CustomerLeasesCap { max: 0 }, err);
+
+    // not tracked, hence not queried
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other_lease", &[]),
+        ExecuteMsg::LeaseUpdated {},
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_backfill_exposure() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+
+    let msg = ExecuteMsg::BackfillExposure {
+        key: None,
+        max_leases: 10,
+    };
+    assert!(execute(deps.as_mut(), mock_env(), customer(), msg.clone()).is_err());
+
+    // no leases, hence none queried
+    let response = execute(deps.as_mut(), mock_env(), owner(), msg).unwrap();
+    assert_eq!(
+        vec![Event::new("backfill-exposure").add_attribute("status", "done")],
+        response.events
+    );
+}
+
+#[test]
+fn test_allowlist() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+    setup_dex_ok(deps.as_mut());
+
+    let info = customer();
+    let open_lease = |deps: DepsMut<'_>| {
+        let msg = ExecuteMsg::OpenLease {
+            currency: DENOM.to_string(),
+            max_ltd: None,
+            rate_type: RateType::default(),
+        };
+        execute(deps, mock_env(), customer(), msg)
+    };
+    let query_allowlist = |deps: Deps<'_>| -> AllowlistResponse {
+        let msg = QueryMsg::Allowlist {
+            customer: info.sender.clone(),
+        };
+        from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap()
+    };
+
+    sudo(deps.as_mut(), mock_env(), SudoMsg::SwitchAllowlist(true)).unwrap();
+    assert_eq!(
+        AllowlistResponse {
+            enabled: true,
+            allowed: false
+        },
+        query_allowlist(deps.as_ref())
+    );
+    assert_eq!(
+        ContractError::NotAllowed {
+            customer: info.sender.clone()
+        },
+        open_lease(deps.as_mut()).unwrap_err()
+    );
+    let new_customer = Addr::unchecked("new_customer");
+    assert_eq!(
+        ContractError::NotAllowed {
+            customer: new_customer.clone()
+        },
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("lease", &[]),
+            ExecuteMsg::ChangeLeaseCustomer {
+                customer: info.sender.clone(),
+                new_customer,
+            },
+        )
+        .unwrap_err()
+    );
+
+    sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::AllowCustomers(vec![info.sender.clone(), Addr::unchecked("addr0001")]),
+    )
+    .unwrap();
+    assert!(query_allowlist(deps.as_ref()).allowed);
+    open_lease(deps.as_mut()).unwrap();
+
+    sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::DisallowCustomers(vec![info.sender.clone()]),
+    )
+    .unwrap();
+    assert!(!query_allowlist(deps.as_ref()).allowed);
+    assert!(open_lease(deps.as_mut()).is_err());
+
+    sudo(deps.as_mut(), mock_env(), SudoMsg::SwitchAllowlist(false)).unwrap();
+    assert!(!query_allowlist(deps.as_ref()).enabled);
+    open_lease(deps.as_mut()).unwrap();
+}
+
+#[test]
+fn test_migration_status() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+
+    let query_status = |deps: Deps<'_>| -> Option<MigrationStatus> {
+        from_binary(&query(deps, mock_env(), QueryMsg::MigrationStatus {}).unwrap()).unwrap()
+    };
+    assert_eq!(None, query_status(deps.as_ref()));
+
+    let resume = ExecuteMsg::MigrateLeasesCont {
+        key: None,
+        max_leases: 10,
+    };
+    assert_eq!(
+        ContractError::NoMigrationInProgress {},
+        execute(deps.as_mut(), mock_env(), owner(), resume.clone()).unwrap_err()
+    );
+
+    let env = mock_env();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner(),
+        ExecuteMsg::MigrateLeases {
+            new_code_id: Uint64::new(2),
+            max_leases: 10,
+        },
+    )
+    .unwrap();
+    let status = MigrationStatus {
+        new_code_id: Uint64::new(2),
+        next_customer: None,
+        migrated: 0,
+        failed: 0,
+        started_at: env.block.time,
+    };
+    assert_eq!(Some(status.clone()), query_status(deps.as_ref()));
+    assert_eq!(
+        ContractError::NoMigrationInProgress {},
+        execute(deps.as_mut(), mock_env(), owner(), resume).unwrap_err()
+    );
+
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 0,
+            result: SubMsgResult::Err("migration failed".into()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        Some(MigrationStatus {
+            failed: 1,
+            ..status
+        }),
+        query_status(deps.as_ref())
+    );
+}
+
+#[test]
+fn test_liability_override_unknown_currency() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+
+    let liability = query_config(deps.as_ref()).liability;
+    let err = sudo(
+        deps.as_mut(),
+        mock_env(),
+        SudoMsg::LiabilityOverride {
+            currency: DENOM.into(),
+            liability,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        ContractError::UnknownCurrency {
+            symbol: DENOM.into()
+        },
+        err
+    );
+}
+
+#[test]
+fn test_change_lease_customer_msg() {
+    let customer = customer().sender;
+    let new_customer = Addr::unchecked("new_customer");
+
+    let lease_msg = to_binary(&lease::api::ExecuteLeaserMsg::ChangeLeaseCustomer {
+        customer: customer.clone(),
+        new_customer: new_customer.clone(),
+    })
+    .unwrap();
+    assert_eq!(
+        ExecuteMsg::ChangeLeaseCustomer {
+            customer,
+            new_customer,
+        },
+        from_binary(&lease_msg).unwrap()
+    );
+}
+
+#[test]
+fn test_lease_stats_msgs() {
+    let amount: lease::api::LeaseCoin = Coin::<Atom>::new(1000).into();
+    let loan = lpn_coin(600);
+
+    let lease_msg = to_binary(&lease::api::ExecuteLeaserMsg::LeaseOpened {
+        amount: amount.clone(),
+        loan: loan.clone(),
+    })
+    .unwrap();
+    assert_eq!(
+        ExecuteMsg::LeaseOpened { amount, loan },
+        from_binary(&lease_msg).unwrap()
+    );
+
+    let lease_msg = to_binary(&lease::api::ExecuteLeaserMsg::LeaseUpdated {}).unwrap();
+    assert_eq!(
+        ExecuteMsg::LeaseUpdated {},
+        from_binary(&lease_msg).unwrap()
+    );
+
+    let lease_msg =
+        to_binary(&lease::api::ExecuteLeaserMsg::LeaseClosed { liquidated: true }).unwrap();
+    assert_eq!(
+        ExecuteMsg::LeaseClosed { liquidated: true },
+        from_binary(&lease_msg).unwrap()
+    );
+    assert_eq!(
+        ExecuteMsg::LeaseClosed { liquidated: false },
+        from_slice(br#"{"lease_closed":{}}"#).unwrap()
+    );
+}
+
+#[test]
+fn test_stats_unknown_lease() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+
+    let msg = ExecuteMsg::LeaseOpened {
+        amount: Coin::<Atom>::new(1000).into(),
+        loan: lpn_coin(600),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("lease", &[]), msg).unwrap();
+
+    let stats: Stats =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Stats {}).unwrap()).unwrap();
+    assert_eq!(Stats::default(), stats);
+}
+
+#[test]
+fn test_change_lease_customer_unknown_lease() {
+    let mut deps = mock_deps();
+
+    setup_test_case(deps.as_mut());
+
+    let lease = mock_info("lease", &[]);
+    let msg = ExecuteMsg::ChangeLeaseCustomer {
+        customer: customer().sender,
+        new_customer: Addr::unchecked("new_customer"),
+    };
+
+    let res = execute(deps.as_mut(), mock_env(), lease.clone(), msg);
+    assert_eq!(
+        res,
+        Err(ContractError::UnknownLease {
+            lease: lease.sender,
+            customer: customer().sender,
+        })
+    );
 }
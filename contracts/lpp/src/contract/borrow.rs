@@ -29,10 +29,16 @@ where
     let mut lpp = LiquidityPool::<Lpn>::load(deps.storage)?;
     lpp.validate_lease_addr(&deps.as_ref(), &lease_addr)?;
 
-    let loan = lpp.try_open_loan(&mut deps, &env, lease_addr.clone(), amount)?;
+    let (loan, amount_to_send) = if Loan::<Lpn>::query(deps.storage, lease_addr.clone())?.is_some()
+    {
+        lpp.try_increase_loan(&mut deps, &env, lease_addr.clone(), amount)?
+    } else {
+        lpp.try_open_loan(&mut deps, &env, lease_addr.clone(), amount)
+            .map(|loan| (loan, amount))?
+    };
 
     let mut bank = bank::account(&env.contract.address, &deps.querier);
-    bank.send(amount, &lease_addr);
+    bank.send(amount_to_send, &lease_addr);
 
     let messages: Batch = bank.into();
 
@@ -64,6 +70,21 @@ where
     Ok((excess_received, batch.into()))
 }
 
+pub(super) fn try_requote_loan<Lpn>(
+    mut deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+) -> Result<LoanResponse<Lpn>>
+where
+    Lpn: 'static + Currency + Serialize + DeserializeOwned,
+{
+    let lease_addr = info.sender;
+
+    let mut lpp = LiquidityPool::<Lpn>::load(deps.storage)?;
+    lpp.validate_lease_addr(&deps.as_ref(), &lease_addr)?;
+    lpp.try_requote_loan(&mut deps, &env, lease_addr)
+}
+
 pub(super) fn query_quote<Lpn>(
     deps: &Deps<'_>,
     env: &Env,
@@ -150,6 +150,10 @@ impl<'a> ExecuteWithLpn<'a> {
                     message_response,
                 )
             }),
+            ExecuteMsg::RequoteLoan() => {
+                borrow::try_requote_loan::<Lpn>(self.deps, self.env, self.info)
+                    .and_then(|loan_resp| response::response::<_, ContractError>(&loan_resp))
+            }
             ExecuteMsg::Deposit() => lender::try_deposit::<Lpn>(self.deps, self.env, self.info)
                 .map(response::response_only_messages),
             ExecuteMsg::Burn { amount } => {
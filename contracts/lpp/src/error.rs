@@ -40,6 +40,9 @@ pub enum ContractError {
     #[error("[Lpp] Zero loan amount")]
     ZeroLoanAmount,
 
+    #[error("[Lpp] The loan increase does not exceed the interest due")]
+    InsufficientLoanIncrease,
+
     #[error("[Lpp] Zero deposit")]
     ZeroDepositFunds,
 
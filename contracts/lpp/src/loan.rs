@@ -8,6 +8,7 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use currency::Currency;
 use finance::{
     coin::Coin,
+    fraction::Fraction,
     interest::InterestPeriod,
     percent::{Percent, Units},
     period::Period,
@@ -66,6 +67,29 @@ where
         }
     }
 
+    /// Increase the principal settling the interest due by the given time
+    ///
+    /// The annual interest rate becomes the principal-weighted average of the current rate
+    /// and the rate of the increase. The settled interest is returned.
+    pub fn increase(
+        &mut self,
+        by: Timestamp,
+        amount: Coin<Lpn>,
+        annual_interest_rate: Percent,
+    ) -> Coin<Lpn> {
+        let interest = self.interest_due(by);
+
+        let principal_due = self.principal_due + amount;
+        self.annual_interest_rate = Percent::from_ratio(
+            self.annual_interest_rate.of(self.principal_due) + annual_interest_rate.of(amount),
+            principal_due,
+        );
+        self.principal_due = principal_due;
+        self.interest_paid = by.max(self.interest_paid);
+
+        interest
+    }
+
     fn due_period(&self, by: Timestamp) -> InterestPeriod<Units, Percent> {
         InterestPeriod::with_interest(self.annual_interest_rate).and_period(Period::from_till(
             self.interest_paid,
@@ -99,6 +123,7 @@ where
                 .update(storage, addr, |loaded_loan| {
                     let mut loaded_loan = loaded_loan.ok_or(ContractError::NoLoan {})?;
                     loaded_loan.principal_due = loan.principal_due;
+                    loaded_loan.annual_interest_rate = loan.annual_interest_rate;
                     loaded_loan.interest_paid = loan.interest_paid;
 
                     Ok::<_, ContractError>(loaded_loan)
@@ -233,6 +258,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn increase() {
+        let principal_start = Coin::<Usdc>::from(1000);
+        let mut l = Loan {
+            principal_due: principal_start,
+            annual_interest_rate: Percent::from_percent(20),
+            interest_paid: Timestamp::from_nanos(200),
+        };
+
+        let at_half_year = l.interest_paid.plus_nanos(Duration::YEAR.nanos() / 2);
+        let increase = Coin::<Usdc>::from(3000);
+        assert_eq!(
+            Coin::<Usdc>::from(100),
+            l.increase(at_half_year, increase, Percent::from_percent(10))
+        );
+        assert_eq!(
+            Loan {
+                principal_due: principal_start + increase,
+                annual_interest_rate: Percent::from_permille(125),
+                interest_paid: at_half_year
+            },
+            l
+        );
+        assert_eq!(Coin::ZERO, l.interest_due(at_half_year));
+    }
+
     mod persistence {
         use currency::test::Usdc;
         use finance::{coin::Coin, duration::Duration, percent::Percent, zero::Zero};
@@ -233,6 +233,75 @@ where
         Ok(loan)
     }
 
+    /// Increase an existing loan settling the interest due by now
+    ///
+    /// Return the updated loan and the amount of lpp currency to be sent to lease_addr,
+    /// that is the increase less the settled interest.
+    pub(super) fn try_increase_loan(
+        &mut self,
+        deps: &mut DepsMut<'_>,
+        env: &Env,
+        lease_addr: Addr,
+        amount: Coin<Lpn>,
+    ) -> Result<(Loan<Lpn>, Coin<Lpn>)> {
+        if amount.is_zero() {
+            return Err(ContractError::ZeroLoanAmount);
+        }
+
+        let now = env.block.time;
+
+        let annual_interest_rate =
+            match self.query_quote(amount, &env.contract.address, &deps.querier, now)? {
+                Some(rate) => Ok(rate),
+                None => Err(ContractError::NoLiquidity {}),
+            }?;
+
+        let mut loan = Loan::load(deps.storage, lease_addr.clone())?;
+        let loan_annual_interest_rate = loan.annual_interest_rate;
+        let interest = loan.increase(now, amount, annual_interest_rate);
+        if interest >= amount {
+            return Err(ContractError::InsufficientLoanIncrease);
+        }
+        Loan::save(deps.storage, lease_addr, loan.clone())?;
+
+        self.total
+            .repay(now, interest, Coin::ZERO, loan_annual_interest_rate);
+        self.total
+            .borrow(now, amount, annual_interest_rate)?
+            .store(deps.storage)?;
+
+        Ok((loan, amount - interest))
+    }
+
+    /// Move an existing loan to the rate a new loan would get now
+    ///
+    /// The new rate applies to the interest due since the loan interest has been paid by.
+    pub(super) fn try_requote_loan(
+        &mut self,
+        deps: &mut DepsMut<'_>,
+        env: &Env,
+        lease_addr: Addr,
+    ) -> Result<Loan<Lpn>> {
+        let now = env.block.time;
+
+        let annual_interest_rate = self
+            .query_quote(Coin::ZERO, &env.contract.address, &deps.querier, now)?
+            .ok_or(ContractError::NoLiquidity {})?;
+
+        let loan_before = Loan::load(deps.storage, lease_addr.clone())?;
+        let loan = Loan {
+            annual_interest_rate,
+            ..loan_before.clone()
+        };
+        Loan::save(deps.storage, lease_addr, loan.clone())?;
+
+        self.total
+            .requote(now, &loan_before, &loan)?
+            .store(deps.storage)?;
+
+        Ok(loan)
+    }
+
     /// return amount of lpp currency to pay back to lease_addr
     pub(super) fn try_repay_loan(
         &mut self,
@@ -689,6 +758,122 @@ mod test {
         assert_eq!(loan_before.interest_paid, loan_after.interest_paid);
     }
 
+    #[test]
+    fn requote_loan() {
+        let balance_mock = [coin_cw(10_000_000)];
+        let mut deps = testing::mock_dependencies_with_balance(&balance_mock);
+        let env = testing::mock_env();
+        let admin = Addr::unchecked("admin");
+        let loan = Addr::unchecked("loan");
+        let lease_code_id = Uint64::new(123);
+
+        grant_admin_access(deps.as_mut(), &admin);
+        Config::new(
+            TheCurrency::TICKER.into(),
+            lease_code_id,
+            InterestRate::new(
+                BASE_INTEREST_RATE,
+                UTILIZATION_OPTIMAL,
+                ADDON_OPTIMAL_INTEREST_RATE,
+            )
+            .expect("Couldn't construct interest rate value!"),
+            DEFAULT_MIN_UTILIZATION,
+        )
+        .store(deps.as_mut().storage)
+        .expect("Failed to store Config!");
+        Total::<TheCurrency>::new()
+            .store(deps.as_mut().storage)
+            .expect("can't initialize Total");
+
+        let mut lpp = LiquidityPool::<TheCurrency>::load(deps.as_mut().storage)
+            .expect("can't load LiquidityPool");
+
+        let loan_before = lpp
+            .try_open_loan(&mut deps.as_mut(), &env, loan.clone(), Coin::new(5_000))
+            .expect("can't open loan");
+        deps.querier
+            .update_balance(MOCK_CONTRACT_ADDR, vec![coin_cw(5_000)]);
+
+        let quote = lpp
+            .query_quote(
+                Coin::ZERO,
+                &env.contract.address,
+                &deps.as_ref().querier,
+                env.block.time,
+            )
+            .expect("can't query quote")
+            .expect("should return some interest_rate");
+        assert_ne!(loan_before.annual_interest_rate, quote);
+
+        let loan_after = lpp
+            .try_requote_loan(&mut deps.as_mut(), &env, loan.clone())
+            .expect("can't requote loan");
+        assert_eq!(quote, loan_after.annual_interest_rate);
+        assert_eq!(loan_before.principal_due, loan_after.principal_due);
+        assert_eq!(loan_before.interest_paid, loan_after.interest_paid);
+
+        let loan_stored = Loan::query(deps.as_ref().storage, loan).expect("can't query loan");
+        assert_eq!(Some(loan_after), loan_stored);
+    }
+
+    #[test]
+    fn open_loan_increase() {
+        let balance_mock = [coin_cw(10_000_000)];
+        let mut deps = testing::mock_dependencies_with_balance(&balance_mock);
+        let mut env = testing::mock_env();
+        let admin = Addr::unchecked("admin");
+        let loan = Addr::unchecked("loan");
+        let lease_code_id = Uint64::new(123);
+
+        grant_admin_access(deps.as_mut(), &admin);
+        Config::new(
+            TheCurrency::TICKER.into(),
+            lease_code_id,
+            InterestRate::new(
+                BASE_INTEREST_RATE,
+                UTILIZATION_OPTIMAL,
+                ADDON_OPTIMAL_INTEREST_RATE,
+            )
+            .expect("Couldn't construct interest rate value!"),
+            DEFAULT_MIN_UTILIZATION,
+        )
+        .store(deps.as_mut().storage)
+        .expect("Failed to store Config!");
+        Total::<TheCurrency>::new()
+            .store(deps.as_mut().storage)
+            .expect("can't initialize Total");
+
+        let mut lpp = LiquidityPool::<TheCurrency>::load(deps.as_mut().storage)
+            .expect("can't load LiquidityPool");
+
+        env.block.time = Timestamp::from_nanos(0);
+        let loan_before = lpp
+            .try_open_loan(&mut deps.as_mut(), &env, loan.clone(), Coin::new(5_000))
+            .expect("can't open loan");
+        deps.querier
+            .update_balance(MOCK_CONTRACT_ADDR, vec![coin_cw(9_995_000)]);
+
+        env.block.time = Timestamp::from_nanos(Duration::YEAR.nanos());
+        let interest_due = loan_before.interest_due(env.block.time);
+        assert!(!interest_due.is_zero());
+
+        let (loan_after, amount_to_send) = lpp
+            .try_increase_loan(&mut deps.as_mut(), &env, loan.clone(), Coin::new(5_000))
+            .expect("can't increase loan");
+        assert_eq!(Coin::new(5_000) - interest_due, amount_to_send);
+        assert_eq!(Coin::new(10_000), loan_after.principal_due);
+        assert_eq!(env.block.time, loan_after.interest_paid);
+        assert_eq!(Coin::ZERO, loan_after.interest_due(env.block.time));
+        assert_eq!(
+            Some(loan_after),
+            Loan::query(deps.as_ref().storage, loan.clone()).expect("can't query loan")
+        );
+        assert_eq!(Coin::new(10_000), lpp.total.total_principal_due());
+
+        let result = lpp.try_increase_loan(&mut deps.as_mut(), &env, loan, Coin::new(0));
+        assert_eq!(result, Err(ContractError::ZeroLoanAmount));
+    }
+
     #[test]
     fn try_open_and_close_loan_without_paying_interest() {
         let balance_mock = [coin_cw(10_000_000)];
@@ -41,6 +41,8 @@ pub enum ExecuteMsg {
 
     OpenLoan { amount: LpnCoin },
     RepayLoan(),
+    // re-quote the sender's loan rate at the current utilization, sent by floating-rate leases
+    RequoteLoan(),
 
     Deposit(),
     // CW20 interface, withdraw from lender deposit
@@ -11,7 +11,7 @@ use sdk::{
     schemars::{self, JsonSchema},
 };
 
-use crate::error::ContractError;
+use crate::{error::ContractError, loan::Loan};
 
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub struct Total<Lpn>
@@ -114,6 +114,31 @@ where
 
         self
     }
+
+    /// Move a loan to another annual interest rate
+    ///
+    /// The interest the loan has accrued since it has been paid by is re-calculated at the new rate.
+    pub fn requote(
+        &mut self,
+        ctime: Timestamp,
+        loan_before: &Loan<Lpn>,
+        loan_after: &Loan<Lpn>,
+    ) -> Result<&Self, ContractError> {
+        debug_assert_eq!(loan_before.principal_due, loan_after.principal_due);
+
+        self.repay(
+            ctime,
+            loan_before.interest_due(ctime),
+            loan_before.principal_due,
+            loan_before.annual_interest_rate,
+        );
+        self.total_interest_due += loan_after.interest_due(ctime);
+        self.borrow(
+            ctime,
+            loan_after.principal_due,
+            loan_after.annual_interest_rate,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -157,4 +182,41 @@ mod test {
         let interest_due = total.total_interest_due_by_now(env.block.time);
         assert_eq!(interest_due, 500u128.into());
     }
+
+    #[test]
+    fn requote() {
+        let start = Timestamp::from_nanos(0);
+        let loan_before = Loan {
+            principal_due: Coin::<Usdc>::new(10000),
+            annual_interest_rate: Percent::from_percent(20),
+            interest_paid: start,
+        };
+        let loan_after = Loan {
+            annual_interest_rate: Percent::from_percent(10),
+            ..loan_before.clone()
+        };
+
+        let mut total: Total<Usdc> = Total::default();
+        total
+            .borrow(
+                start,
+                loan_before.principal_due,
+                loan_before.annual_interest_rate,
+            )
+            .expect("should borrow");
+
+        let half_year_period = Duration::from_nanos(Duration::YEAR.nanos() / 2);
+        let half_year = start + half_year_period;
+        assert_eq!(Coin::new(1000), total.total_interest_due_by_now(half_year));
+
+        total
+            .requote(half_year, &loan_before, &loan_after)
+            .expect("should requote");
+        assert_eq!(total.total_principal_due(), Coin::new(10000));
+        assert_eq!(Coin::new(500), total.total_interest_due_by_now(half_year));
+        assert_eq!(
+            Coin::new(1000),
+            total.total_interest_due_by_now(half_year + half_year_period)
+        );
+    }
 }
@@ -27,6 +27,17 @@ where
     /// and then, if there is any remaining amount, to repay the principal.
     /// Amount 0 is acceptable although does not change the loan.
     fn repay(&mut self, by: Timestamp, repayment: Coin<Lpn>) -> RepayShares<Lpn>;
+    /// Increase the principal by the specified time
+    ///
+    /// The interest due by then is settled out of the 'amount' and returned.
+    /// The annual interest rate is kept until the loan is reloaded from the LPP
+    /// that determines the rate of the increase.
+    fn increase(&mut self, by: Timestamp, amount: Coin<Lpn>) -> Coin<Lpn>;
+    /// Re-quote the annual interest rate at the current utilization of the LPP
+    ///
+    /// The annual interest rate is kept until the loan is reloaded from the LPP
+    /// that determines the new rate.
+    fn requote(&mut self);
     fn annual_interest_rate(&self) -> Percent;
 }
 
@@ -48,6 +59,8 @@ where
     currency: PhantomData<Lpn>,
     loan: Loan<Lpn>,
     repayment: Coin<Lpn>,
+    increase: Coin<Lpn>,
+    requote: bool,
 }
 
 impl<Lpn> LppLoanImpl<Lpn>
@@ -60,6 +73,8 @@ where
             currency: PhantomData,
             loan,
             repayment: Default::default(),
+            increase: Default::default(),
+            requote: false,
         }
     }
 }
@@ -80,6 +95,16 @@ where
         self.loan.repay(by, repayment)
     }
 
+    fn increase(&mut self, by: Timestamp, amount: Coin<Lpn>) -> Coin<Lpn> {
+        self.increase += amount;
+        let annual_interest_rate = self.loan.annual_interest_rate;
+        self.loan.increase(by, amount, annual_interest_rate)
+    }
+
+    fn requote(&mut self) {
+        self.requote = true;
+    }
+
     fn annual_interest_rate(&self) -> Percent {
         self.loan.annual_interest_rate
     }
@@ -93,6 +118,15 @@ where
 
     fn try_from(stub: LppLoanImpl<Lpn>) -> StdResult<Self, Self::Error> {
         let mut batch = Batch::default();
+        if !stub.increase.is_zero() {
+            batch.schedule_execute_wasm_no_reply::<_, Lpn>(
+                &stub.lpp_ref.addr,
+                ExecuteMsg::OpenLoan {
+                    amount: stub.increase.into(),
+                },
+                None,
+            )?;
+        }
         if !stub.repayment.is_zero() {
             batch.schedule_execute_wasm_no_reply(
                 &stub.lpp_ref.addr,
@@ -100,6 +134,14 @@ where
                 Some(stub.repayment),
             )?;
         }
+        // a fully repaid loan is closed by the LPP so there is nothing to requote
+        if stub.requote && !stub.loan.principal_due.is_zero() {
+            batch.schedule_execute_wasm_no_reply::<_, Lpn>(
+                &stub.lpp_ref.addr,
+                ExecuteMsg::RequoteLoan(),
+                None,
+            )?;
+        }
         Ok(Self {
             lpp_ref: stub.lpp_ref,
             batch,
@@ -140,6 +182,39 @@ mod test {
         assert_eq!(Batch::default(), batch.batch);
     }
 
+    #[test]
+    fn try_from_increase() {
+        let lpp_ref = LppRef::unchecked::<_, Usdc>("lpp_address");
+        let start = Timestamp::from_seconds(0);
+        let mut loan = LppLoanImpl::new(
+            lpp_ref.clone(),
+            Loan {
+                principal_due: Coin::<Usdc>::new(100),
+                annual_interest_rate: Percent::from_percent(12),
+                interest_paid: start,
+            },
+        );
+        let increase = 50.into();
+        assert_eq!(
+            Coin::<Usdc>::new(12),
+            loan.increase(start + Duration::YEAR, increase)
+        );
+        assert_eq!(Coin::<Usdc>::new(150), loan.principal_due());
+        let batch: LppBatch<LppRef> = loan.try_into().unwrap();
+        {
+            let mut exp = Batch::default();
+            exp.schedule_execute_wasm_no_reply::<_, Usdc>(
+                lpp_ref.addr(),
+                ExecuteMsg::OpenLoan {
+                    amount: increase.into(),
+                },
+                None,
+            )
+            .unwrap();
+            assert_eq!(exp, batch.batch);
+        }
+    }
+
     #[test]
     fn try_from_a_few_payments() {
         let lpp_ref = LppRef::unchecked::<_, Usdc>("lpp_address");
@@ -169,4 +244,40 @@ mod test {
             assert_eq!(exp, batch.batch);
         }
     }
+
+    #[test]
+    fn try_from_repay_and_requote() {
+        let lpp_ref = LppRef::unchecked::<_, Usdc>("lpp_address");
+        let start = Timestamp::from_seconds(0);
+        let mut loan = LppLoanImpl::new(
+            lpp_ref.clone(),
+            Loan {
+                principal_due: Coin::<Usdc>::new(100),
+                annual_interest_rate: Percent::from_percent(12),
+                interest_paid: start,
+            },
+        );
+        let payment = 12.into();
+        loan.repay(start + Duration::YEAR, payment);
+        loan.requote();
+        loan.requote();
+        assert_eq!(Percent::from_percent(12), loan.annual_interest_rate());
+        let batch: LppBatch<LppRef> = loan.try_into().unwrap();
+        {
+            let mut exp = Batch::default();
+            exp.schedule_execute_wasm_no_reply(
+                lpp_ref.addr(),
+                ExecuteMsg::RepayLoan(),
+                Some(payment),
+            )
+            .unwrap();
+            exp.schedule_execute_wasm_no_reply::<_, Usdc>(
+                lpp_ref.addr(),
+                ExecuteMsg::RequoteLoan(),
+                None,
+            )
+            .unwrap();
+            assert_eq!(exp, batch.batch);
+        }
+    }
 }
@@ -3,7 +3,7 @@ use std::{convert::TryInto, marker::PhantomData, result::Result as StdResult};
 use serde::{Deserialize, Serialize};
 
 use currency::{self, Currency, SymbolOwned};
-use finance::price::Price;
+use finance::{coin, price::Price};
 use marketprice::SpotPrice;
 use platform::batch::Batch;
 use sdk::cosmwasm_std::{wasm_execute, Addr, QuerierWrapper};
@@ -123,6 +123,31 @@ impl OracleRef {
             .map_err(ContractError::StubSwapPathQuery)
     }
 
+    /// Query the price of a currency in terms of the oracle base currency
+    ///
+    /// The price of the base currency itself is the identity.
+    pub fn spot_price(
+        &self,
+        currency: SymbolOwned,
+        querier: &QuerierWrapper<'_>,
+    ) -> Result<SpotPrice> {
+        if currency == self.base_currency {
+            let identity = coin::from_amount_ticker(1, &self.base_currency)?;
+            return Ok(SpotPrice::new(identity.clone(), identity));
+        }
+
+        let msg = QueryMsg::Price {
+            currency: currency.clone(),
+        };
+        querier
+            .query_wasm_smart(self.addr.clone(), &msg)
+            .map_err(|error| ContractError::FailedToFetchPrice {
+                from: currency,
+                to: self.base_currency.clone(),
+                error,
+            })
+    }
+
     fn check_base<OracleBase, Err>(&self) -> StdResult<(), Err>
     where
         OracleBase: Currency,
@@ -53,6 +53,7 @@ pub fn instantiate(
 
     State::new(Config::new(
         msg.cadence_hours,
+        msg.max_slippage,
         msg.treasury,
         OracleRef::try_from(msg.oracle, &deps.querier)?,
         TimeAlarmsRef::new(msg.timealarms, &deps.querier)?,
@@ -140,7 +141,9 @@ fn try_handle_neutron_msg(
 ) -> ContractResult<DexResponse<State>> {
     match msg {
         NeutronSudoMsg::Response { data, .. } => Result::from(state.on_response(data, deps, env)),
-        NeutronSudoMsg::Error { .. } => state.on_error(deps, env).map_err(Into::into),
+        NeutronSudoMsg::Error { details, .. } => {
+            state.on_error(&details, deps, env).map_err(Into::into)
+        }
         NeutronSudoMsg::Timeout { .. } => state.on_timeout(deps, env).map_err(Into::into),
         NeutronSudoMsg::OpenAck {
             port_id: connection_id,
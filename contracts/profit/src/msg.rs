@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use finance::percent::bound::BoundToHundredPercent;
 use sdk::{
     cosmwasm_std::Addr,
     schemars::{self, JsonSchema},
@@ -10,6 +11,8 @@ use crate::typedefs::CadenceHours;
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct InstantiateMsg {
     pub cadence_hours: CadenceHours,
+    /// The maximum slippage of the buy-back swaps on the DEX
+    pub max_slippage: BoundToHundredPercent,
     pub treasury: Addr,
     pub oracle: Addr,
     pub timealarms: Addr,
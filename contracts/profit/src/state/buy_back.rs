@@ -11,7 +11,10 @@ use dex::{
     Account, CoinVisitor, Enterable, IterNext, IterState, Response as DexResponse, StateLocalOut,
     SwapTask,
 };
-use finance::coin::{Coin, CoinDTO};
+use finance::{
+    coin::{Coin, CoinDTO},
+    percent::bound::BoundToHundredPercent,
+};
 use oracle::stub::OracleRef;
 use platform::{
     bank::{self, BankAccountView},
@@ -94,6 +97,10 @@ impl SwapTask for BuyBack {
         Nls::TICKER
     }
 
+    fn max_slippage(&self) -> BoundToHundredPercent {
+        self.config.max_slippage()
+    }
+
     fn on_coins<Visitor>(&self, visitor: &mut Visitor) -> Result<IterState, Visitor::Error>
     where
         Visitor: CoinVisitor<Result = IterNext>,
@@ -176,7 +183,10 @@ mod tests {
         test::{Dai, Usdc},
     };
     use dex::{CoinVisitor, IterNext, IterState, SwapTask as _};
-    use finance::coin::{Coin, CoinDTO};
+    use finance::{
+        coin::{Coin, CoinDTO},
+        percent::bound::BoundToHundredPercent,
+    };
     use platform::never::Never;
 
     use super::BuyBack;
@@ -194,6 +204,7 @@ mod tests {
             Addr::unchecked("DEADCODE"),
             Config::new(
                 24,
+                BoundToHundredPercent::ZERO,
                 Addr::unchecked("DEADCODE"),
                 OracleRef::unchecked::<_, Usdc>("DEADCODE"),
                 TimeAlarmsRef::unchecked("DEADCODE"),
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use finance::percent::{bound::BoundToHundredPercent, Percent};
 use oracle::stub::OracleRef;
 use sdk::cosmwasm_std::Addr;
 use timealarms::stub::TimeAlarmsRef;
@@ -9,6 +10,8 @@ use crate::typedefs::CadenceHours;
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub(crate) struct Config {
     cadence_hours: CadenceHours,
+    #[serde(default = "legacy_max_slippage")]
+    max_slippage: BoundToHundredPercent,
     treasury: Addr,
     oracle: OracleRef,
     time_alarms: TimeAlarmsRef,
@@ -17,12 +20,14 @@ pub(crate) struct Config {
 impl Config {
     pub fn new(
         cadence_hours: CadenceHours,
+        max_slippage: BoundToHundredPercent,
         treasury: Addr,
         oracle: OracleRef,
         time_alarms: TimeAlarmsRef,
     ) -> Self {
         Self {
             cadence_hours,
+            max_slippage,
             treasury,
             oracle,
             time_alarms,
@@ -40,6 +45,10 @@ impl Config {
         self.cadence_hours
     }
 
+    pub fn max_slippage(&self) -> BoundToHundredPercent {
+        self.max_slippage
+    }
+
     pub fn treasury(&self) -> &Addr {
         &self.treasury
     }
@@ -52,3 +61,9 @@ impl Config {
         &self.time_alarms
     }
 }
+
+/// Configurations stored before the slippage protection accept any swap output
+fn legacy_max_slippage() -> BoundToHundredPercent {
+    BoundToHundredPercent::try_from_percent(Percent::HUNDRED)
+        .expect("the hundred percent should not cross its own bound")
+}
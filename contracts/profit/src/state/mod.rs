@@ -215,12 +215,14 @@ impl Handler for State {
         }
     }
 
-    fn on_error(self, deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
+    fn on_error(self, details: &str, deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
         match self.0 {
-            StateEnum::OpenTransferChannel(transfer) => transfer.on_error(deps, env),
-            StateEnum::OpenIca(ica) => ica.on_error(deps, env),
-            StateEnum::Idle(idle) => idle.on_error(deps, env),
-            StateEnum::BuyBack(buy_back) => buy_back.on_error(deps, env).map(state_machine::from),
+            StateEnum::OpenTransferChannel(transfer) => transfer.on_error(details, deps, env),
+            StateEnum::OpenIca(ica) => ica.on_error(details, deps, env),
+            StateEnum::Idle(idle) => idle.on_error(details, deps, env),
+            StateEnum::BuyBack(buy_back) => buy_back
+                .on_error(details, deps, env)
+                .map(state_machine::from),
         }
     }
 
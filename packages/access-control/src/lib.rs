@@ -5,11 +5,12 @@ use sdk::{
     cw_storage_plus::Item,
 };
 
-pub use self::contract_owner::ContractOwnerAccess;
 use self::error::{Error, Result};
+pub use self::{contract_owner::ContractOwnerAccess, multi_user::MultiUserAccess};
 
 mod contract_owner;
 pub mod error;
+mod multi_user;
 
 pub fn check(permitted_to: &Addr, accessed_by: &Addr) -> Result {
     if permitted_to == accessed_by {
@@ -0,0 +1,92 @@
+use std::ops::{Deref, DerefMut};
+
+use sdk::{
+    cosmwasm_std::{Addr, Storage},
+    cw_storage_plus::Map,
+};
+
+use crate::error::{Error, Result};
+
+/// Access granted to a set of users
+pub struct MultiUserAccess<'storage, 'namespace, S>
+where
+    S: Deref<Target = dyn Storage + 'storage>,
+{
+    storage: S,
+    storage_map: Map<'namespace, Addr, ()>,
+}
+
+impl<'storage, 'namespace, S> MultiUserAccess<'storage, 'namespace, S>
+where
+    S: Deref<Target = dyn Storage + 'storage>,
+{
+    pub const fn new(storage: S, storage_namespace: &'namespace str) -> Self {
+        Self {
+            storage,
+            storage_map: Map::new(storage_namespace),
+        }
+    }
+
+    pub fn check(&self, user: &Addr) -> Result {
+        if self.storage_map.has(self.storage.deref(), user.clone()) {
+            Ok(())
+        } else {
+            Err(Error::Unauthorized {})
+        }
+    }
+}
+
+impl<'storage, 'namespace, S> MultiUserAccess<'storage, 'namespace, S>
+where
+    S: Deref<Target = dyn Storage + 'storage> + DerefMut,
+{
+    pub fn grant_to(&mut self, user: &Addr) -> Result {
+        self.storage_map
+            .save(self.storage.deref_mut(), user.clone(), &())
+            .map_err(Into::into)
+    }
+
+    pub fn revoke_from(&mut self, user: &Addr) {
+        self.storage_map
+            .remove(self.storage.deref_mut(), user.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sdk::cosmwasm_std::{testing::MockStorage, Addr, Storage};
+
+    use crate::{error::Error, MultiUserAccess};
+
+    const NAMESPACE: &str = "my-nice-permissions";
+
+    #[test]
+    fn grant_check_revoke() {
+        let mut storage = MockStorage::new();
+        let storage_ref: &mut dyn Storage = &mut storage;
+        let mut access = MultiUserAccess::new(storage_ref, NAMESPACE);
+        let user1 = Addr::unchecked("cosmic address");
+        let user2 = Addr::unchecked("another cosmic address");
+
+        assert_eq!(Err(Error::Unauthorized {}), access.check(&user1));
+        access.grant_to(&user1).unwrap();
+        access.grant_to(&user2).unwrap();
+        access.check(&user1).unwrap();
+        access.check(&user2).unwrap();
+
+        access.revoke_from(&user1);
+        assert_eq!(Err(Error::Unauthorized {}), access.check(&user1));
+        access.check(&user2).unwrap();
+    }
+
+    #[test]
+    fn revoke_no_grant() {
+        let mut storage = MockStorage::new();
+        let storage_ref: &mut dyn Storage = &mut storage;
+        let mut access = MultiUserAccess::new(storage_ref, NAMESPACE);
+        let user = Addr::unchecked("hacker");
+
+        access.revoke_from(&user);
+        assert_eq!(Err(Error::Unauthorized {}), access.check(&user));
+    }
+}
@@ -7,7 +7,11 @@ use platform::batch::Batch;
 use sdk::cosmwasm_std::{Deps, Env, QuerierWrapper, Timestamp};
 use timealarms::stub::TimeAlarmsRef;
 
-use crate::{error::Result as DexResult, Contract, Handler, Result};
+use crate::{
+    error::Result as DexResult,
+    heal::{self, HealPolicy},
+    Contract, Handler, Result,
+};
 
 use super::{ica_connector::Enterable as EnterableT, Response};
 
@@ -28,8 +32,12 @@ impl<Enterable> EntryDelay<Enterable> {
     }
 
     pub(super) fn enter(&self, now: Timestamp) -> DexResult<Batch> {
+        self.enter_after(now, Self::RIGHT_AFTER_NOW)
+    }
+
+    pub(super) fn enter_after(&self, now: Timestamp, delay: Duration) -> DexResult<Batch> {
         self.time_alarms
-            .setup_alarm(now + Self::RIGHT_AFTER_NOW)
+            .setup_alarm(now + delay)
             .map_err(Into::into)
     }
 }
@@ -53,6 +61,12 @@ where
             .map(|batch| Response::<Self>::from(batch, self.enterable))
             .into()
     }
+
+    fn heal(self, since: Timestamp, deps: Deps<'_>, env: Env) -> Result<Self> {
+        heal::on_heal(self, HealPolicy::Reenter, since, env, |state, env| {
+            Handler::on_time_alarm(state, deps, env)
+        })
+    }
 }
 
 impl<Connectee> Contract for EntryDelay<Connectee>
@@ -2,6 +2,8 @@ use std::fmt::Display;
 
 use thiserror::Error;
 
+use sdk::cosmwasm_std::Timestamp;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum Error {
     #[error("[Dex] {0}")]
@@ -13,9 +15,15 @@ pub enum Error {
     #[error("[Dex] {0}")]
     Swap(#[from] swap::error::Error),
 
+    #[error("[Dex] The swap transaction at '{0}' has failed: {1}")]
+    SwapFailed(String, String),
+
     #[error("[Dex] The operation '{0}' is not supported in the current state '{1}'")]
     UnsupportedOperation(String, String),
 
+    #[error("[Dex] The transaction of the state '{0}' may still be in flight, heal it after {1}")]
+    HealTooEarly(String, Timestamp),
+
     #[error("[Dex] {0}")]
     OracleError(#[from] oracle::error::ContractError),
 
@@ -0,0 +1,251 @@
+use std::fmt::Display;
+
+use finance::duration::Duration;
+use platform::{
+    batch::{Emit, Emitter},
+    state_machine::Response as StateMachineResponse,
+};
+use sdk::cosmwasm_std::{Addr, Deps, Env, Timestamp};
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    connectable::DexConnectable,
+    entry_delay::EntryDelay,
+    error::{Error, Result},
+    ica_connector::{Enterable, IcaConnector},
+    ica_recover::InRecovery,
+    recovery,
+    response::{Handler, Result as HandlerResult},
+};
+
+/// The minimum time a state should have not changed before a heal may replay its transaction
+///
+/// It is well above the timeout of the IBC transactions so once it elapses the host
+/// would not accept the original one anymore.
+pub const HEAL_DELAY: Duration = Duration::from_minutes(5);
+
+pub(crate) fn on_heal_reenter<S, SEnum>(
+    current_state: S,
+    since: Timestamp,
+    deps: Deps<'_>,
+    env: Env,
+) -> Result<StateMachineResponse<SEnum>>
+where
+    S: Enterable + Display + Into<SEnum>,
+{
+    let emitter = start_heal(&current_state, HealPolicy::Reenter, since, &env)?;
+    recovery::reenter(current_state, emitter, deps, env)
+}
+
+pub(crate) fn on_heal_repair_channel<S, SEnum, SwapResult>(
+    current_state: S,
+    since: Timestamp,
+    time_alarms: TimeAlarmsRef,
+    env: Env,
+) -> Result<StateMachineResponse<SEnum>>
+where
+    S: Enterable + DexConnectable + Display + Into<SEnum>,
+    EntryDelay<IcaConnector<InRecovery<S, SEnum>, SwapResult>>: Into<SEnum>,
+    SEnum: From<IcaConnector<InRecovery<S, SEnum>, SwapResult>>,
+    EntryDelay<S>: Into<SEnum>,
+{
+    let emitter = start_heal(&current_state, HealPolicy::RepairICS27Channel, since, &env)?;
+    recovery::repair_channel(current_state, emitter, time_alarms, env)
+}
+
+/// Heal the current state with `heal_fn` reporting the policy with a 'heal' event
+pub(crate) fn on_heal<H, HealFn>(
+    current_state: H,
+    policy: HealPolicy,
+    since: Timestamp,
+    env: Env,
+    heal_fn: HealFn,
+) -> HandlerResult<H>
+where
+    H: Handler,
+    HealFn: FnOnce(H, Env) -> HandlerResult<H>,
+{
+    match start_heal(&current_state, policy, since, &env) {
+        Ok(emitter) => heal_fn(current_state, env).with_events(emitter),
+        Err(err) => HandlerResult::Continue(Err(err)),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum HealPolicy {
+    /// Enter the current state again
+    Reenter,
+    /// Open the ICA again and then enter the current state
+    RepairICS27Channel,
+    /// Check again if the expected amount has been received
+    QueryBalance,
+    /// Deliver again the response the current state has got
+    Redeliver,
+}
+
+impl HealPolicy {
+    /// Whether the policy might send again the transaction of the current state
+    const fn replays(&self) -> bool {
+        matches!(self, Self::Reenter | Self::RepairICS27Channel)
+    }
+}
+
+pub(crate) fn emit_heal<S>(current_state: &S, contract: Addr, policy: HealPolicy) -> Emitter
+where
+    S: Display,
+{
+    Emitter::of_type("heal")
+        .emit("id", contract)
+        .emit("state", current_state.to_string())
+        .emit("policy", format!("{:?}", policy))
+}
+
+fn start_heal<S>(
+    current_state: &S,
+    policy: HealPolicy,
+    since: Timestamp,
+    env: &Env,
+) -> Result<Emitter>
+where
+    S: Display,
+{
+    let heal_after = since + HEAL_DELAY;
+    if policy.replays() && env.block.time < heal_after {
+        Err(Error::HealTooEarly(current_state.to_string(), heal_after))
+    } else {
+        Ok(emit_heal(
+            current_state,
+            env.contract.address.clone(),
+            policy,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::{Display, Formatter, Result as FmtResult};
+
+    use platform::{batch::Batch, message::Response as MessageResponse};
+    use sdk::cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Env, QuerierWrapper, Timestamp,
+    };
+
+    use crate::{
+        error::{Error, Result},
+        ica_connector::Enterable,
+        response::{self, Handler, Result as HandlerResult},
+        trx::IBC_TIMEOUT,
+    };
+
+    use super::{HealPolicy, HEAL_DELAY};
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Stuck;
+
+    impl Display for Stuck {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.write_str("Stuck")
+        }
+    }
+
+    impl Enterable for Stuck {
+        fn enter(&self, _now: Timestamp, _querier: &QuerierWrapper<'_>) -> Result<Batch> {
+            Ok(Batch::default())
+        }
+    }
+
+    impl Handler for Stuck {
+        type Response = Self;
+        type SwapResult = ();
+    }
+
+    #[test]
+    fn delay_over_ibc_timeout() {
+        assert!(IBC_TIMEOUT < HEAL_DELAY);
+    }
+
+    #[test]
+    fn reenter_in_flight() {
+        let env = mock_env();
+        let since = env.block.time;
+        assert_eq!(
+            Err(Error::HealTooEarly(Stuck.to_string(), since + HEAL_DELAY)),
+            super::on_heal_reenter::<_, Stuck>(Stuck, since, mock_dependencies().as_ref(), env)
+                .map(|resp| resp.next_state)
+        );
+    }
+
+    #[test]
+    fn reenter_stale() {
+        let env = mock_env();
+        let since = env.block.time - HEAL_DELAY;
+        let exp_resp = MessageResponse::messages_with_events(
+            Batch::default(),
+            super::emit_heal(&Stuck, env.contract.address.clone(), HealPolicy::Reenter),
+        );
+        let resp =
+            super::on_heal_reenter::<_, Stuck>(Stuck, since, mock_dependencies().as_ref(), env)
+                .unwrap();
+        assert_eq!(exp_resp, resp.response);
+        assert_eq!(Stuck, resp.next_state);
+    }
+
+    #[test]
+    fn repair_channel_in_flight() {
+        let env = mock_env();
+        let since = env.block.time - HEAL_DELAY + IBC_TIMEOUT;
+        assert_eq!(
+            Err(Error::HealTooEarly(Stuck.to_string(), since + HEAL_DELAY)),
+            super::start_heal(&Stuck, HealPolicy::RepairICS27Channel, since, &env)
+        );
+    }
+
+    #[test]
+    fn repair_channel_stale() {
+        let env = mock_env();
+        let since = env.block.time - HEAL_DELAY;
+        assert_eq!(
+            Ok(super::emit_heal(
+                &Stuck,
+                env.contract.address.clone(),
+                HealPolicy::RepairICS27Channel
+            )),
+            super::start_heal(&Stuck, HealPolicy::RepairICS27Channel, since, &env)
+        );
+    }
+
+    #[test]
+    fn query_balance_in_flight() {
+        heal_in_flight(HealPolicy::QueryBalance);
+    }
+
+    #[test]
+    fn redeliver_in_flight() {
+        heal_in_flight(HealPolicy::Redeliver);
+    }
+
+    fn heal_in_flight(policy: HealPolicy) {
+        let env = mock_env();
+        let since = env.block.time;
+        let exp_resp = MessageResponse::from(super::emit_heal(
+            &Stuck,
+            env.contract.address.clone(),
+            policy,
+        ));
+        match super::on_heal(Stuck, policy, since, env, heal_to_self) {
+            HandlerResult::Continue(Ok(resp)) => {
+                assert_eq!(exp_resp, resp.response);
+                assert_eq!(Stuck, resp.next_state);
+            }
+            _ => unreachable!("the heal should continue with the same state"),
+        }
+    }
+
+    fn heal_to_self(state: Stuck, _env: Env) -> HandlerResult<Stuck> {
+        HandlerResult::Continue(response::res_continue::<_, _, Stuck>(
+            MessageResponse::default(),
+            state,
+        ))
+    }
+}
@@ -16,7 +16,8 @@ use crate::{
     account::Account,
     connectable::DexConnectable,
     error::Result,
-    response::{ContinueResult, Handler},
+    heal,
+    response::{ContinueResult, Handler, Result as HandlerResult},
     Contract, Response, TimeAlarm,
 };
 
@@ -94,6 +95,7 @@ where
 impl<Connectee, SwapResult> Handler for IcaConnector<Connectee, SwapResult>
 where
     Connectee: IcaConnectee + DexConnectable + Display,
+    Self: Into<Connectee::State>,
 {
     type Response = Connectee::State;
     type SwapResult = SwapResult;
@@ -115,6 +117,10 @@ where
             })
             .map(|cw_resp| Response::<Self>::from(cw_resp, next_state))
     }
+
+    fn heal(self, since: Timestamp, deps: Deps<'_>, env: Env) -> HandlerResult<Self> {
+        heal::on_heal_reenter(self, since, deps, env).into()
+    }
 }
 
 impl<Connectee, SwapResult> Contract for IcaConnector<Connectee, SwapResult>
@@ -8,6 +8,7 @@ pub use crate::{
     connectable::DexConnectable,
     connection::{ConnectionParams, Ics20Channel},
     error::{Error, Result as DexResult},
+    heal::HEAL_DELAY,
     ica_connector::{
         Enterable, IcaConnectee, IcaConnector, ICS27_MESSAGE_ENTERING_NEXT_STATE,
         NO_ICS27_MESSAGE_ENTERING_NEXT_STATE,
@@ -19,7 +20,7 @@ pub use crate::{
     },
     out_remote::{start as start_local_remote, StartLocalRemoteState, State as StateRemoteOut},
     response::{ContinueResult, Handler, Response, Result},
-    swap_coins::{on_coin, on_coins},
+    swap_coins::{on_coin, on_coins, on_many_coins},
     swap_exact_in::SwapExactIn,
     swap_task::{CoinVisitor, CoinsNb, IterNext, IterState, SwapTask},
     transfer_in_finish::TransferInFinish,
@@ -35,10 +36,12 @@ mod connection;
 mod entry_delay;
 mod error;
 mod filter;
+mod heal;
 mod ica_connector;
 mod ica_recover;
 mod out_local;
 mod out_remote;
+mod recovery;
 mod resp_delivery;
 mod response;
 mod swap_coins;
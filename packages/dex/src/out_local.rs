@@ -256,7 +256,7 @@ mod impl_into {
 }
 
 mod impl_handler {
-    use sdk::cosmwasm_std::{Binary, Deps, DepsMut, Env, Reply};
+    use sdk::cosmwasm_std::{Binary, Deps, DepsMut, Env, Reply, Timestamp};
 
     use crate::{
         response::{ContinueResult, Result},
@@ -391,27 +391,43 @@ mod impl_handler {
             }
         }
 
-        fn on_error(self, deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
+        fn on_error(self, details: &str, deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
             match self {
-                State::TransferOut(inner) => Handler::on_error(inner, deps, env),
-                State::TransferOutRespDelivery(inner) => Handler::on_error(inner, deps, env),
-                State::SwapExactIn(inner) => Handler::on_error(inner, deps, env),
-                State::SwapExactInRespDelivery(inner) => Handler::on_error(inner, deps, env),
+                State::TransferOut(inner) => Handler::on_error(inner, details, deps, env),
+                State::TransferOutRespDelivery(inner) => {
+                    Handler::on_error(inner, details, deps, env)
+                }
+                State::SwapExactIn(inner) => Handler::on_error(inner, details, deps, env),
+                State::SwapExactInRespDelivery(inner) => {
+                    Handler::on_error(inner, details, deps, env)
+                }
                 State::SwapExactInRecoverIcaRespDelivery(inner) => {
-                    Handler::on_error(inner, deps, env)
+                    Handler::on_error(inner, details, deps, env)
+                }
+                State::SwapExactInPreRecoverIca(inner) => {
+                    Handler::on_error(inner, details, deps, env)
+                }
+                State::SwapExactInRecoverIca(inner) => Handler::on_error(inner, details, deps, env),
+                State::SwapExactInPostRecoverIca(inner) => {
+                    Handler::on_error(inner, details, deps, env)
+                }
+                State::TransferInInit(inner) => Handler::on_error(inner, details, deps, env),
+                State::TransferInInitRespDelivery(inner) => {
+                    Handler::on_error(inner, details, deps, env)
                 }
-                State::SwapExactInPreRecoverIca(inner) => Handler::on_error(inner, deps, env),
-                State::SwapExactInRecoverIca(inner) => Handler::on_error(inner, deps, env),
-                State::SwapExactInPostRecoverIca(inner) => Handler::on_error(inner, deps, env),
-                State::TransferInInit(inner) => Handler::on_error(inner, deps, env),
-                State::TransferInInitRespDelivery(inner) => Handler::on_error(inner, deps, env),
                 State::TransferInInitRecoverIcaRespDelivery(inner) => {
-                    Handler::on_error(inner, deps, env)
+                    Handler::on_error(inner, details, deps, env)
+                }
+                State::TransferInInitPreRecoverIca(inner) => {
+                    Handler::on_error(inner, details, deps, env)
+                }
+                State::TransferInInitRecoverIca(inner) => {
+                    Handler::on_error(inner, details, deps, env)
                 }
-                State::TransferInInitPreRecoverIca(inner) => Handler::on_error(inner, deps, env),
-                State::TransferInInitRecoverIca(inner) => Handler::on_error(inner, deps, env),
-                State::TransferInInitPostRecoverIca(inner) => Handler::on_error(inner, deps, env),
-                State::TransferInFinish(inner) => Handler::on_error(inner, deps, env),
+                State::TransferInInitPostRecoverIca(inner) => {
+                    Handler::on_error(inner, details, deps, env)
+                }
+                State::TransferInFinish(inner) => Handler::on_error(inner, details, deps, env),
             }
         }
 
@@ -521,39 +537,45 @@ mod impl_handler {
             }
         }
 
-        fn heal(self, deps: Deps<'_>, env: Env) -> Result<Self> {
+        fn heal(self, since: Timestamp, deps: Deps<'_>, env: Env) -> Result<Self> {
             match self {
-                State::TransferOut(inner) => Handler::heal(inner, deps, env).map_into(),
-                State::TransferOutRespDelivery(inner) => Handler::heal(inner, deps, env).map_into(),
-                State::SwapExactIn(inner) => Handler::heal(inner, deps, env).map_into(),
-                State::SwapExactInRespDelivery(inner) => Handler::heal(inner, deps, env).map_into(),
+                State::TransferOut(inner) => Handler::heal(inner, since, deps, env).map_into(),
+                State::TransferOutRespDelivery(inner) => {
+                    Handler::heal(inner, since, deps, env).map_into()
+                }
+                State::SwapExactIn(inner) => Handler::heal(inner, since, deps, env).map_into(),
+                State::SwapExactInRespDelivery(inner) => {
+                    Handler::heal(inner, since, deps, env).map_into()
+                }
                 State::SwapExactInRecoverIcaRespDelivery(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
                 State::SwapExactInPreRecoverIca(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
+                }
+                State::SwapExactInRecoverIca(inner) => {
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
-                State::SwapExactInRecoverIca(inner) => Handler::heal(inner, deps, env).map_into(),
                 State::SwapExactInPostRecoverIca(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
-                State::TransferInInit(inner) => Handler::heal(inner, deps, env).map_into(),
+                State::TransferInInit(inner) => Handler::heal(inner, since, deps, env).map_into(),
                 State::TransferInInitRespDelivery(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
                 State::TransferInInitRecoverIcaRespDelivery(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
                 State::TransferInInitPreRecoverIca(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
                 State::TransferInInitRecoverIca(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
                 State::TransferInInitPostRecoverIca(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
-                State::TransferInFinish(inner) => Handler::heal(inner, deps, env).map_into(),
+                State::TransferInFinish(inner) => Handler::heal(inner, since, deps, env).map_into(),
             }
         }
 
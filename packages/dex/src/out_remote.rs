@@ -169,7 +169,7 @@ mod impl_into {
 mod impl_handler {
     use std::fmt::Display;
 
-    use sdk::cosmwasm_std::{Binary, Deps, DepsMut, Env, Reply};
+    use sdk::cosmwasm_std::{Binary, Deps, DepsMut, Env, Reply, Timestamp};
 
     use crate::{
         response::{ContinueResult, Result},
@@ -273,20 +273,28 @@ mod impl_handler {
             }
         }
 
-        fn on_error(self, deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
+        fn on_error(self, details: &str, deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
             match self {
-                State::OpenIca(inner) => Handler::on_error(inner, deps, env),
-                State::OpenIcaRespDelivery(inner) => Handler::on_error(inner, deps, env),
-                State::TransferOut(inner) => Handler::on_error(inner, deps, env),
-                State::TransferOutRespDelivery(inner) => Handler::on_error(inner, deps, env),
-                State::SwapExactIn(inner) => Handler::on_error(inner, deps, env),
-                State::SwapExactInRespDelivery(inner) => Handler::on_error(inner, deps, env),
+                State::OpenIca(inner) => Handler::on_error(inner, details, deps, env),
+                State::OpenIcaRespDelivery(inner) => Handler::on_error(inner, details, deps, env),
+                State::TransferOut(inner) => Handler::on_error(inner, details, deps, env),
+                State::TransferOutRespDelivery(inner) => {
+                    Handler::on_error(inner, details, deps, env)
+                }
+                State::SwapExactIn(inner) => Handler::on_error(inner, details, deps, env),
+                State::SwapExactInRespDelivery(inner) => {
+                    Handler::on_error(inner, details, deps, env)
+                }
                 State::SwapExactInRecoverIcaRespDelivery(inner) => {
-                    Handler::on_error(inner, deps, env)
+                    Handler::on_error(inner, details, deps, env)
+                }
+                State::SwapExactInPreRecoverIca(inner) => {
+                    Handler::on_error(inner, details, deps, env)
+                }
+                State::SwapExactInRecoverIca(inner) => Handler::on_error(inner, details, deps, env),
+                State::SwapExactInPostRecoverIca(inner) => {
+                    Handler::on_error(inner, details, deps, env)
                 }
-                State::SwapExactInPreRecoverIca(inner) => Handler::on_error(inner, deps, env),
-                State::SwapExactInRecoverIca(inner) => Handler::on_error(inner, deps, env),
-                State::SwapExactInPostRecoverIca(inner) => Handler::on_error(inner, deps, env),
             }
         }
 
@@ -359,23 +367,31 @@ mod impl_handler {
             }
         }
 
-        fn heal(self, deps: Deps<'_>, env: Env) -> Result<Self> {
+        fn heal(self, since: Timestamp, deps: Deps<'_>, env: Env) -> Result<Self> {
             match self {
-                State::OpenIca(inner) => Handler::heal(inner, deps, env).map_into(),
-                State::OpenIcaRespDelivery(inner) => Handler::heal(inner, deps, env).map_into(),
-                State::TransferOut(inner) => Handler::heal(inner, deps, env).map_into(),
-                State::TransferOutRespDelivery(inner) => Handler::heal(inner, deps, env).map_into(),
-                State::SwapExactIn(inner) => Handler::heal(inner, deps, env).map_into(),
-                State::SwapExactInRespDelivery(inner) => Handler::heal(inner, deps, env).map_into(),
+                State::OpenIca(inner) => Handler::heal(inner, since, deps, env).map_into(),
+                State::OpenIcaRespDelivery(inner) => {
+                    Handler::heal(inner, since, deps, env).map_into()
+                }
+                State::TransferOut(inner) => Handler::heal(inner, since, deps, env).map_into(),
+                State::TransferOutRespDelivery(inner) => {
+                    Handler::heal(inner, since, deps, env).map_into()
+                }
+                State::SwapExactIn(inner) => Handler::heal(inner, since, deps, env).map_into(),
+                State::SwapExactInRespDelivery(inner) => {
+                    Handler::heal(inner, since, deps, env).map_into()
+                }
                 State::SwapExactInRecoverIcaRespDelivery(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
                 State::SwapExactInPreRecoverIca(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
+                }
+                State::SwapExactInRecoverIca(inner) => {
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
-                State::SwapExactInRecoverIca(inner) => Handler::heal(inner, deps, env).map_into(),
                 State::SwapExactInPostRecoverIca(inner) => {
-                    Handler::heal(inner, deps, env).map_into()
+                    Handler::heal(inner, since, deps, env).map_into()
                 }
             }
         }
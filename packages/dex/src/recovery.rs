@@ -0,0 +1,54 @@
+use platform::{
+    batch::Emitter, message::Response as MessageResponse,
+    state_machine::Response as StateMachineResponse,
+};
+use sdk::cosmwasm_std::{Deps, Env};
+use timealarms::stub::TimeAlarmsRef;
+
+use crate::{
+    connectable::DexConnectable,
+    entry_delay::EntryDelay,
+    error::Result,
+    ica_connector::{Enterable, IcaConnector},
+    ica_recover::InRecovery,
+};
+
+/// Enter the current state again
+pub(crate) fn reenter<S, SEnum>(
+    current_state: S,
+    emitter: Emitter,
+    deps: Deps<'_>,
+    env: Env,
+) -> Result<StateMachineResponse<SEnum>>
+where
+    S: Enterable + Into<SEnum>,
+{
+    let batch = current_state.enter(env.block.time, &deps.querier)?;
+    Ok(StateMachineResponse::from(
+        MessageResponse::messages_with_events(batch, emitter),
+        current_state,
+    ))
+}
+
+/// Open the ICA again and then enter the current state on the next block
+pub(crate) fn repair_channel<S, SEnum, SwapResult>(
+    current_state: S,
+    emitter: Emitter,
+    time_alarms: TimeAlarmsRef,
+    env: Env,
+) -> Result<StateMachineResponse<SEnum>>
+where
+    S: Enterable + DexConnectable + Into<SEnum>,
+    EntryDelay<IcaConnector<InRecovery<S, SEnum>, SwapResult>>: Into<SEnum>,
+    SEnum: From<IcaConnector<InRecovery<S, SEnum>, SwapResult>>,
+    EntryDelay<S>: Into<SEnum>,
+{
+    let pre_recover_ica = EntryDelay::new(
+        IcaConnector::new(InRecovery::new(current_state, time_alarms.clone())),
+        time_alarms,
+    );
+    pre_recover_ica
+        .enter(env.block.time)
+        .map(|batch| MessageResponse::messages_with_events(batch, emitter))
+        .map(|resp| StateMachineResponse::from(resp, pre_recover_ica))
+}
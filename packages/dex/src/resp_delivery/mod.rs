@@ -13,8 +13,10 @@ use sdk::cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, QuerierWrapper, Reply,
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    error::Result as DexResult, response::Result, ContinueResult, Contract, ForwardToInner,
-    Handler, TimeAlarm,
+    error::Result as DexResult,
+    heal::{self, HealPolicy},
+    response::Result,
+    ContinueResult, Contract, ForwardToInner, Handler, TimeAlarm,
 };
 
 use self::adapter::{DeliveryAdapter, ICAOpenDeliveryAdapter, ResponseDeliveryAdapter};
@@ -130,6 +132,12 @@ where
         // we leave the error to escape since the time alarms delivery is reliable
         self.do_deliver(deps, env)
     }
+
+    fn heal(self, since: Timestamp, deps: Deps<'_>, env: Env) -> Result<Self> {
+        heal::on_heal(self, HealPolicy::Redeliver, since, env, |state, env| {
+            state.do_deliver(deps, env)
+        })
+    }
 }
 
 impl<H, ForwardToInnerMsg, R, Delivery> Contract
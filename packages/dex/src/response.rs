@@ -1,10 +1,11 @@
 use std::{fmt::Display, result::Result as StdResult};
 
 use platform::{
+    batch::Emitter,
     message::Response as MessageResponse,
     state_machine::{self, Response as StateMachineResponse},
 };
-use sdk::cosmwasm_std::{Api, Binary, Deps, DepsMut, Env, Reply};
+use sdk::cosmwasm_std::{Api, Binary, Deps, DepsMut, Env, Reply, Timestamp};
 
 use crate::error::{Error, Result as DexResult};
 
@@ -56,7 +57,7 @@ where
     }
 
     /// The entry point of an error delivery
-    fn on_error(self, deps: Deps<'_>, _env: Env) -> ContinueResult<Self> {
+    fn on_error(self, _details: &str, deps: Deps<'_>, _env: Env) -> ContinueResult<Self> {
         Err(err(self, "handle transaction error", deps.api))
     }
 
@@ -79,7 +80,11 @@ where
         Err(err(self, "handle inner to 'Continue' response", deps.api))
     }
 
-    fn heal(self, deps: Deps<'_>, _env: Env) -> Result<Self> {
+    /// Attempt to recover from a stuck state
+    ///
+    /// `since` is the time the current state has not changed from. The paths that may replay
+    /// a transaction are taken only after [`HEAL_DELAY`](crate::HEAL_DELAY) has elapsed.
+    fn heal(self, _since: Timestamp, deps: Deps<'_>, _env: Env) -> Result<Self> {
         Err(err(self, "handle heal", deps.api)).into()
     }
 
@@ -106,6 +111,18 @@ where
             Result::Finished(finish_res) => Result::Finished(finish_res),
         }
     }
+
+    /// Add the events to the response if the handler is to continue
+    ///
+    /// A finished handler reports with the events of its swap result.
+    pub(crate) fn with_events(self, events: Emitter) -> Self {
+        match self {
+            Result::Continue(cont_res) => Result::Continue(cont_res.map(|resp| {
+                StateMachineResponse::from(resp.response.merge_with(events.into()), resp.next_state)
+            })),
+            Result::Finished(finish_res) => Result::Finished(finish_res),
+        }
+    }
 }
 
 impl<H, StateTo, Err> From<Result<H>> for StdResult<StateMachineResponse<StateTo>, Err>
@@ -27,10 +27,27 @@ where
     G2: Group,
     Visitor: CoinVisitor<Result = IterNext>,
 {
-    visitor.visit(coin1).and_then(|next| match next {
-        IterNext::Continue => on_coin(coin2, visitor),
-        IterNext::Stop => Ok(IterState::Incomplete),
-    })
+    on_many_coins(std::iter::once(coin1), coin2, visitor)
+}
+
+/// Call back the visitor with each of the first coins and then with the last one
+pub fn on_many_coins<'c, G1, G2, Coins, Visitor>(
+    coins: Coins,
+    last: &CoinDTO<G2>,
+    visitor: &mut Visitor,
+) -> Result<IterState, Visitor::Error>
+where
+    G1: Group + 'c,
+    G2: Group,
+    Coins: IntoIterator<Item = &'c CoinDTO<G1>>,
+    Visitor: CoinVisitor<Result = IterNext>,
+{
+    for coin in coins {
+        if let IterNext::Stop = visitor.visit(coin)? {
+            return Ok(IterState::Incomplete);
+        }
+    }
+    on_coin(last, visitor)
 }
 
 #[cfg(test)]
@@ -139,4 +156,18 @@ mod test {
         assert!(v.first_visited(coin1().amount()));
         assert!(v.second_visited(coin2().amount()));
     }
+
+    #[test]
+    fn visit_many_none_first() {
+        let mut v = TestVisitor::<IterNext>::new(IterNext::Continue, IterNext::Continue);
+
+        let iter_res = never::safe_unwrap(super::on_many_coins::<TestCurrencies, _, _, _>(
+            [],
+            &coin2(),
+            &mut v,
+        ));
+        assert_eq!(iter_res, IterState::Complete);
+        assert!(v.first_visited(coin2().amount()));
+        assert!(v.second_not_visited());
+    }
 }
@@ -1,6 +1,7 @@
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     marker::PhantomData,
+    result::Result as StdResult,
 };
 
 use serde::{Deserialize, Serialize};
@@ -8,9 +9,17 @@ use serde::{Deserialize, Serialize};
 use currency::{Group, Symbol};
 use finance::{
     coin::{self, Amount, CoinDTO},
+    duration::Duration,
+    percent::bound::BoundToHundredPercent,
     zero::Zero,
 };
-use platform::{batch::Batch, trx};
+use platform::{
+    batch::{Batch, Emit, Emitter},
+    message::Response as MessageResponse,
+    never::{self, Never},
+    state_machine::Response as StateMachineResponse,
+    trx,
+};
 use sdk::{
     cosmos_sdk_proto::cosmos::base::abci::v1beta1::MsgData,
     cosmwasm_std::{Binary, Deps, Env, QuerierWrapper, Timestamp},
@@ -22,8 +31,10 @@ use crate::swap_task::IterState;
 use crate::{
     connectable::DexConnectable,
     connection::ConnectionParams,
+    entry_delay::EntryDelay,
     error::{Error, Result},
     filter::CurrencyFilter,
+    heal,
     ica_connector::Enterable,
     response::{self, ContinueResult, Handler, Result as HandlerResult},
     swap_task::{CoinVisitor, IterNext, SwapTask as SwapTaskT},
@@ -38,6 +49,9 @@ use super::{Contract, SwapState};
 #[derive(Serialize, Deserialize)]
 pub struct SwapExactIn<SwapTask, SEnum> {
     spec: SwapTask,
+    /// How many times the swap has been requoted after an output below the minimum
+    #[serde(default)]
+    requotes: u8,
     #[serde(skip)]
     _state_enum: PhantomData<SEnum>,
 }
@@ -49,6 +63,7 @@ where
     pub(super) fn new(spec: SwapTask) -> Self {
         Self {
             spec,
+            requotes: 0,
             _state_enum: PhantomData,
         }
     }
@@ -65,7 +80,7 @@ where
     ) -> Result<Batch> {
         let swap_trx = self.spec.dex_account().swap(self.spec.oracle(), querier);
         // TODO apply nls_swap_fee on the downpayment only!
-        struct SwapWorker<'a>(SwapTrx<'a>, Symbol<'a>);
+        struct SwapWorker<'a>(SwapTrx<'a>, Symbol<'a>, BoundToHundredPercent, Amount);
         impl<'a> CoinVisitor for SwapWorker<'a> {
             type Result = IterNext;
             type Error = Error;
@@ -74,12 +89,21 @@ where
             where
                 G: Group,
             {
-                self.0.swap_exact_in(coin, self.1)?;
+                self.0.swap_exact_in(coin, self.1, self.2, self.3)?;
                 Ok(IterNext::Continue)
             }
         }
 
-        let mut swapper = SwapWorker(swap_trx, self.spec.out_currency());
+        let min_out = self
+            .spec
+            .min_out()
+            .map_or(Amount::ZERO, |min_out| min_out.amount());
+        let mut swapper = SwapWorker(
+            swap_trx,
+            self.spec.out_currency(),
+            self.spec.max_slippage(),
+            min_out,
+        );
         let mut filtered_swapper = CurrencyFilter::new(&mut swapper, self.spec.out_currency());
         let _res = self.spec.on_coins(&mut filtered_swapper)?;
 
@@ -89,6 +113,30 @@ where
         Ok(swapper.0.into())
     }
 
+    /// Whether all coins are already in the output currency
+    ///
+    /// No swap transaction is sent then, and the output amount is their total.
+    pub(super) fn nothing_to_swap(&self) -> bool {
+        struct SwapDetector(bool);
+        impl CoinVisitor for SwapDetector {
+            type Result = IterNext;
+            type Error = Never;
+
+            fn visit<G>(&mut self, _coin: &CoinDTO<G>) -> StdResult<Self::Result, Self::Error>
+            where
+                G: Group,
+            {
+                self.0 = true;
+                Ok(IterNext::Stop)
+            }
+        }
+
+        let mut detector = SwapDetector(false);
+        let mut filtered_detector = CurrencyFilter::new(&mut detector, self.spec.out_currency());
+        let _res = never::safe_unwrap(self.spec.on_coins(&mut filtered_detector));
+        !detector.0
+    }
+
     fn decode_response(&self, resp: &[u8], spec: &SwapTask) -> Result<CoinDTO<SwapTask::OutG>> {
         struct ExactInResponse<I>(I, Amount);
         impl<I> CoinVisitor for ExactInResponse<I>
@@ -111,12 +159,50 @@ where
         let _res = self.spec.on_coins(&mut filtered_resp)?;
 
         #[cfg(debug_assertions)]
-        self.debug_check(&filtered_resp, _res);
+        debug_assert_eq!(_res, IterState::Complete);
 
         coin::from_amount_ticker(filtered_resp.filtered() + resp.1, spec.out_currency())
             .map_err(Into::into)
     }
 
+    /// Retry the swap with a fresh quote after a price movement beyond the maximum slippage
+    ///
+    /// The minimum output amount gets recalculated on each attempt. The attempts are
+    /// limited and spaced exponentially. Any other error, as well as the one after the last
+    /// attempt, fails the error delivery and leaves the swap to be healed.
+    fn retry_on_error<SEnumTo>(
+        mut self,
+        details: &str,
+        env: Env,
+    ) -> Result<StateMachineResponse<SEnumTo>>
+    where
+        EntryDelay<Self>: Into<SEnumTo>,
+    {
+        if !may_requote(details, self.requotes) {
+            return Err(Error::SwapFailed(self.spec.label().into(), details.into()));
+        }
+
+        let delay = requote_delay(self.requotes);
+        self.requotes += 1;
+
+        let emitter = Emitter::of_type(self.spec.label())
+            .emit("id", env.contract.address)
+            .emit("error", "Requote")
+            .emit_to_string_value("attempt", self.requotes);
+        let time_alarms = self.spec.time_alarm().clone();
+        let requote = EntryDelay::new(self, time_alarms);
+        requote
+            .enter_after(env.block.time, delay)
+            .map(|batch| MessageResponse::messages_with_events(batch, emitter))
+            .map(|resp| StateMachineResponse::from(resp, requote))
+    }
+
+    /// Start over the requotes on a heal
+    fn reset_requotes(mut self) -> Self {
+        self.requotes = 0;
+        self
+    }
+
     #[cfg(debug_assertions)]
     fn debug_check<V>(&self, filter: &CurrencyFilter<'_, V>, res: IterState)
     where
@@ -131,6 +217,27 @@ where
     }
 }
 
+const MAX_REQUOTES: u8 = 5;
+const REQUOTE_DELAY: Duration = Duration::from_secs(30);
+
+/// Whether a failed swap should be retried with a fresh quote
+///
+/// The error acknowledgements carry only the ABCI code, not the codespace of the module
+/// that has failed the transaction. Any failure with the code the DEX rejects a swap below
+/// the minimum output with is therefore taken for such a rejection. An unrelated failure
+/// with the same code is retried up to `MAX_REQUOTES` times, as any price movement, and then
+/// left to be healed.
+fn may_requote(error_details: &str, requotes: u8) -> bool {
+    swap_trx::is_below_min_amount_out(error_details) && requotes < MAX_REQUOTES
+}
+
+/// The delay before the requote that follows the given number of requotes
+fn requote_delay(requotes: u8) -> Duration {
+    REQUOTE_DELAY
+        .checked_mul(1 << requotes)
+        .expect("the requote delays should not overflow")
+}
+
 impl<SwapTask, SEnum> Enterable for SwapExactIn<SwapTask, SEnum>
 where
     SwapTask: SwapTaskT,
@@ -173,11 +280,20 @@ where
             .into()
     }
 
+    fn on_error(self, details: &str, _deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
+        self.retry_on_error(details, env)
+    }
+
     fn on_timeout(self, _deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
         let state_label = self.spec.label();
         let timealarms = self.spec.time_alarm().clone();
         timeout::on_timeout_repair_channel(self, state_label, timealarms, env)
     }
+
+    fn heal(self, since: Timestamp, _deps: Deps<'_>, env: Env) -> HandlerResult<Self> {
+        let timealarms = self.spec.time_alarm().clone();
+        heal::on_heal_repair_channel(self.reset_requotes(), since, timealarms, env).into()
+    }
 }
 
 impl<OpenIca, SwapTask, ForwardToInnerMsg, ForwardToInnerContinueMsg> Handler
@@ -203,11 +319,20 @@ where
             )
     }
 
+    fn on_error(self, details: &str, _deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
+        self.retry_on_error(details, env)
+    }
+
     fn on_timeout(self, _deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
         let state_label = self.spec.label();
         let timealarms = self.spec.time_alarm().clone();
         timeout::on_timeout_repair_channel(self, state_label, timealarms, env)
     }
+
+    fn heal(self, since: Timestamp, _deps: Deps<'_>, env: Env) -> HandlerResult<Self> {
+        let timealarms = self.spec.time_alarm().clone();
+        heal::on_heal_repair_channel(self.reset_requotes(), since, timealarms, env).into()
+    }
 }
 
 impl<SwapTask, SEnum> Contract for SwapExactIn<SwapTask, SEnum>
@@ -238,3 +363,34 @@ where
         self.spec.time_alarm().setup_alarm(forr).map_err(Into::into)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use finance::duration::Duration;
+
+    use super::MAX_REQUOTES;
+
+    // the SDK reports an invalid address, for example, with the same code
+    const UNRELATED_ERROR: &str = "ABCI code: 7: error handling packet: see events for details";
+
+    #[test]
+    fn requote_unrelated_error() {
+        let requotes = (0..=u8::MAX)
+            .take_while(|&requotes| super::may_requote(UNRELATED_ERROR, requotes))
+            .count();
+        assert_eq!(usize::from(MAX_REQUOTES), requotes);
+
+        let retrying = (0..MAX_REQUOTES)
+            .map(super::requote_delay)
+            .fold(Duration::default(), |total, delay| total + delay);
+        assert!(retrying < Duration::HOUR);
+    }
+
+    #[test]
+    fn no_requote_other_error() {
+        assert!(!super::may_requote(
+            "ABCI code: 5: error handling packet: see events for details",
+            0
+        ));
+    }
+}
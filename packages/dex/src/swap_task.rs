@@ -1,5 +1,5 @@
 use currency::{Group, Symbol};
-use finance::coin::CoinDTO;
+use finance::{coin::CoinDTO, percent::bound::BoundToHundredPercent};
 use oracle::stub::OracleRef;
 use sdk::cosmwasm_std::{Env, QuerierWrapper};
 use timealarms::stub::TimeAlarmsRef;
@@ -23,6 +23,26 @@ pub trait SwapTask {
     fn time_alarm(&self) -> &TimeAlarmsRef;
     fn out_currency(&self) -> Symbol<'_>;
 
+    /// The maximum acceptable deviation of a swap output from the one valued at the oracle prices
+    ///
+    /// A swap yielding less than that is rejected by the DEX and retried with a fresh quote.
+    fn max_slippage(&self) -> BoundToHundredPercent;
+
+    /// The amount of the output currency kept on the contract account apart from the swap
+    ///
+    /// It is not taken for a swap output when checking whether the output has been transferred in.
+    fn out_reserve(&self) -> Option<CoinDTO<Self::OutG>> {
+        None
+    }
+
+    /// The output a swapped coin should yield at least, regardless of the maximum slippage
+    ///
+    /// A swap yielding less than that is rejected by the DEX and retried with a fresh quote.
+    /// It applies to each swapped coin, so it is meant for tasks swapping a single coin.
+    fn min_out(&self) -> Option<CoinDTO<Self::OutG>> {
+        None
+    }
+
     /// Call back the worker with each coin this swap is about.
     /// The iteration is done over the coins always in the same order.
     /// It continues either until there are no more coins or the worker has responded
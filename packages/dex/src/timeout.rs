@@ -1,6 +1,5 @@
 use platform::{
     batch::{Emit, Emitter},
-    message::Response as MessageResponse,
     state_machine::Response as StateMachineResponse,
 };
 use sdk::cosmwasm_std::{Addr, Deps, Env};
@@ -12,6 +11,7 @@ use crate::{
     error::Result,
     ica_connector::{Enterable, IcaConnector},
     ica_recover::InRecovery,
+    recovery,
 };
 
 pub(crate) fn on_timeout_retry<S, SEnum, L>(
@@ -29,11 +29,7 @@ where
         env.contract.address.clone(),
         TimeoutPolicy::Retry,
     );
-    let batch = current_state.enter(env.block.time, &deps.querier)?;
-    Ok(StateMachineResponse::from(
-        MessageResponse::messages_with_events(batch, emitter),
-        current_state,
-    ))
+    recovery::reenter(current_state, emitter, deps, env)
 }
 
 pub(crate) fn on_timeout_repair_channel<S, L, SEnum, SwapResult>(
@@ -51,17 +47,10 @@ where
 {
     let emitter = emit_timeout(
         state_label,
-        env.contract.address,
+        env.contract.address.clone(),
         TimeoutPolicy::RepairICS27Channel,
     );
-    let pre_recover_ica = EntryDelay::new(
-        IcaConnector::new(InRecovery::new(current_state, time_alarms.clone())),
-        time_alarms,
-    );
-    pre_recover_ica
-        .enter(env.block.time)
-        .map(|batch| MessageResponse::messages_with_events(batch, emitter))
-        .map(|resp| StateMachineResponse::from(resp, pre_recover_ica))
+    recovery::repair_channel(current_state, emitter, time_alarms, env)
 }
 
 #[derive(Debug)]
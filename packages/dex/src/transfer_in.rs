@@ -13,17 +13,19 @@ const POLLING_INTERVAL: Duration = Duration::from_secs(5);
 
 pub(super) fn check_received<G>(
     payment: &CoinDTO<G>,
+    reserve: Option<&CoinDTO<G>>,
     account: &Addr,
     querier: &QuerierWrapper<'_>,
 ) -> Result<bool>
 where
     G: Group,
 {
-    struct CheckBalance<'a> {
+    struct CheckBalance<'a, G> {
+        reserve: Option<&'a CoinDTO<G>>,
         account: &'a Addr,
         querier: &'a QuerierWrapper<'a>,
     }
-    impl<'a> WithCoin for CheckBalance<'a> {
+    impl<'a, G> WithCoin for CheckBalance<'a, G> {
         type Output = bool;
         type Error = Error;
 
@@ -31,12 +33,19 @@ where
         where
             C: Currency,
         {
-            let received = bank::balance(self.account, self.querier)? >= expected_payment;
+            let reserve = self
+                .reserve
+                .map_or_else(|| Ok(Coin::default()), TryInto::try_into)?;
+            let received = bank::balance(self.account, self.querier)? >= expected_payment + reserve;
             Ok(received)
         }
     }
 
-    payment.with_coin(CheckBalance { account, querier })
+    payment.with_coin(CheckBalance {
+        reserve,
+        account,
+        querier,
+    })
 }
 
 pub(super) fn setup_alarm(time_alarms: &TimeAlarmsRef, now: Timestamp) -> Result<Batch> {
@@ -11,6 +11,7 @@ use platform::{
 use sdk::cosmwasm_std::{Deps, Env, QuerierWrapper, Timestamp};
 
 use crate::{
+    heal::{self, HealPolicy},
     response::{self, Handler, Result as HandlerResult},
     swap_task::SwapTask as SwapTaskT,
     transfer_in, Contract, ContractInSwap, Enterable, TransferInFinishState,
@@ -56,14 +57,19 @@ where
     TransferInInit<SwapTask, SEnum>: Into<SEnum>,
 {
     pub(super) fn try_complete(self, deps: Deps<'_>, env: Env) -> HandlerResult<Self> {
-        transfer_in::check_received(&self.amount_in, &env.contract.address, &deps.querier)
-            .map_or_else(Into::into, |received| {
-                if received {
-                    self.complete(&env, &deps.querier)
-                } else {
-                    self.try_again(env, deps)
-                }
-            })
+        transfer_in::check_received(
+            &self.amount_in,
+            self.spec.out_reserve().as_ref(),
+            &env.contract.address,
+            &deps.querier,
+        )
+        .map_or_else(Into::into, |received| {
+            if received {
+                self.complete(&env, &deps.querier)
+            } else {
+                self.try_again(env, deps)
+            }
+        })
     }
 
     fn complete(self, env: &Env, querier: &QuerierWrapper<'_>) -> HandlerResult<Self> {
@@ -105,6 +111,12 @@ where
     type Response = SEnum;
     type SwapResult = SwapTask::Result;
 
+    fn heal(self, since: Timestamp, deps: Deps<'_>, env: Env) -> HandlerResult<Self> {
+        heal::on_heal(self, HealPolicy::QueryBalance, since, env, |state, env| {
+            state.try_complete(deps, env)
+        })
+    }
+
     fn on_time_alarm(self, deps: Deps<'_>, env: Env) -> HandlerResult<Self> {
         self.try_complete(deps, env)
     }
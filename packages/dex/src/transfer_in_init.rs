@@ -9,6 +9,7 @@ use sdk::cosmwasm_std::{Binary, Deps, Env, QuerierWrapper, Timestamp};
 
 use crate::{
     error::Result,
+    heal::{self, HealPolicy},
     response::{ContinueResult, Handler, Result as HandlerResult},
     swap_task::SwapTask as SwapTaskT,
     timeout,
@@ -114,8 +115,11 @@ where
         let timealarms = self.spec.time_alarm().clone();
         timeout::on_timeout_repair_channel(self, state_label, timealarms, env)
     }
-    fn heal(self, deps: Deps<'_>, env: Env) -> HandlerResult<Self> {
-        self.on_response(deps, env)
+
+    fn heal(self, since: Timestamp, deps: Deps<'_>, env: Env) -> HandlerResult<Self> {
+        heal::on_heal(self, HealPolicy::QueryBalance, since, env, |state, env| {
+            state.on_response(deps, env)
+        })
     }
 }
 
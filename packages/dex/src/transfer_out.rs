@@ -17,6 +17,7 @@ use sdk::cosmwasm_std::{Binary, Deps, Env, QuerierWrapper, Timestamp};
 
 use crate::{
     error::{Error, Result},
+    heal,
     ica_connector::Enterable,
     response::{self, ContinueResult, Handler, Result as HandlerResult},
     swap_task::{CoinVisitor, IterNext},
@@ -146,7 +147,8 @@ impl<SwapTask, SEnum> Handler for TransferOut<SwapTask, SEnum>
 where
     SwapTask: SwapTaskT,
     Self: Into<SEnum>,
-    SwapExactIn<SwapTask, SEnum>: Into<SEnum>,
+    SwapExactIn<SwapTask, SEnum>:
+        Handler<Response = SEnum, SwapResult = SwapTask::Result> + Into<SEnum>,
 {
     type Response = SEnum;
     type SwapResult = SwapTask::Result;
@@ -155,17 +157,26 @@ where
         let label = self.spec.label();
         let now = env.block.time;
         if self.last_coin() {
-            Self::on_response(SwapExactIn::new(self.spec), label, now, &deps.querier)
+            let swap = SwapExactIn::new(self.spec);
+            if swap.nothing_to_swap() {
+                // an empty swap transaction responds with no messages
+                swap.on_response(Binary::default(), deps, env).map_into()
+            } else {
+                Self::on_response(swap, label, now, &deps.querier).into()
+            }
         } else {
-            Self::on_response(self.next(), label, now, &deps.querier)
+            Self::on_response(self.next(), label, now, &deps.querier).into()
         }
-        .into()
     }
 
     fn on_timeout(self, deps: Deps<'_>, env: Env) -> ContinueResult<Self> {
         let state_label = self.spec.label();
         timeout::on_timeout_retry(self, state_label, deps, env)
     }
+
+    fn heal(self, since: Timestamp, deps: Deps<'_>, env: Env) -> HandlerResult<Self> {
+        heal::on_heal_reenter(self, since, deps, env).into()
+    }
 }
 
 impl<SwapTask, SEnum> Contract for TransferOut<SwapTask, SEnum>
@@ -1,7 +1,8 @@
 use currency::{native::Nls, Group, Symbol};
 use finance::{
-    coin::{Coin, CoinDTO},
+    coin::{Amount, Coin, CoinDTO},
     duration::Duration,
+    percent::{bound::BoundToHundredPercent, Percent},
 };
 use oracle::stub::OracleRef;
 use platform::{
@@ -10,10 +11,10 @@ use platform::{
     ica::{self, HostAccount},
     trx::Transaction,
 };
-use sdk::cosmwasm_std::{Addr, QuerierWrapper, Timestamp};
+use sdk::cosmwasm_std::{Addr, QuerierWrapper, StdError, Timestamp, Uint128, Uint512};
 use swap::trx;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 
 pub(super) const IBC_TIMEOUT: Duration = Duration::from_secs(60);
 
@@ -25,6 +26,9 @@ const ICA_TRANSFER_TIMEOUT_TIP: Coin<Nls> = ICA_TRANSFER_ACK_TIP;
 const ICA_SWAP_ACK_TIP: Coin<Nls> = Coin::new(1);
 const ICA_SWAP_TIMEOUT_TIP: Coin<Nls> = ICA_SWAP_ACK_TIP;
 
+// the DEX rejects swaps with a zero minimum output
+const MIN_AMOUNT_OUT: Amount = 1;
+
 pub(super) struct TransferOutTrx<'a> {
     sender: LocalSender<'a>,
 }
@@ -89,16 +93,82 @@ impl<'a> SwapTrx<'a> {
         }
     }
 
-    pub fn swap_exact_in<G>(&mut self, amount: &CoinDTO<G>, currency_out: Symbol<'_>) -> Result<()>
+    pub fn swap_exact_in<G>(
+        &mut self,
+        amount: &CoinDTO<G>,
+        currency_out: Symbol<'_>,
+        max_slippage: BoundToHundredPercent,
+        min_out: Amount,
+    ) -> Result<()>
     where
         G: Group,
     {
         let swap_path =
             self.oracle
                 .swap_path(amount.ticker().into(), currency_out.into(), self.querier)?;
-        trx::exact_amount_in(&mut self.trx, self.ica_account.clone(), amount, &swap_path)?;
+        let min_amount_out = self
+            .min_amount_out(amount, currency_out, max_slippage)?
+            .max(min_out);
+        trx::exact_amount_in(
+            &mut self.trx,
+            self.ica_account.clone(),
+            amount,
+            &swap_path,
+            min_amount_out,
+        )?;
         Ok(())
     }
+
+    /// Calculate the minimum acceptable output of a swap
+    ///
+    /// The expected output is valued at the oracle prices of the input and output currencies,
+    /// and then decreased with the maximum slippage.
+    fn min_amount_out<G>(
+        &self,
+        amount: &CoinDTO<G>,
+        currency_out: Symbol<'_>,
+        max_slippage: BoundToHundredPercent,
+    ) -> Result<Amount>
+    where
+        G: Group,
+    {
+        let price_in = self
+            .oracle
+            .spot_price(amount.ticker().into(), self.querier)?;
+        let price_out = self.oracle.spot_price(currency_out.into(), self.querier)?;
+
+        let min_amount_out = min_amount_out(
+            amount.amount(),
+            (price_in.base().amount(), price_in.quote().amount()),
+            (price_out.base().amount(), price_out.quote().amount()),
+            max_slippage.percent(),
+        )?;
+        Ok(min_amount_out.max(MIN_AMOUNT_OUT))
+    }
+}
+
+/// Value `amount_in` at `price_in` and convert the result at `price_out`
+/// decreasing it with `max_slippage`
+///
+/// The prices are expressed as pairs of the amount of the currency and
+/// the amount of the common quote currency.
+fn min_amount_out(
+    amount_in: Amount,
+    price_in: (Amount, Amount),
+    price_out: (Amount, Amount),
+    max_slippage: Percent,
+) -> Result<Amount> {
+    // none of the products could overflow since they multiply up to three u128 values and a u32
+    let nominator = Uint512::from(amount_in)
+        * Uint512::from(price_in.1)
+        * Uint512::from(price_out.0)
+        * Uint512::from((Percent::HUNDRED - max_slippage).units());
+    let denominator = Uint512::from(price_in.0)
+        * Uint512::from(price_out.1)
+        * Uint512::from(Percent::HUNDRED.units());
+    Uint128::try_from(nominator / denominator)
+        .map(Into::into)
+        .map_err(|err| Error::Finance(StdError::from(err).into()))
 }
 
 impl From<SwapTrx<'_>> for LocalBatch {
@@ -152,3 +222,42 @@ impl<'r> From<TransferInTrx<'r>> for LocalBatch {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use finance::percent::Percent;
+
+    #[test]
+    fn min_amount_out_identity() {
+        assert_eq!(
+            Ok(1000),
+            super::min_amount_out(1000, (1, 1), (1, 1), Percent::ZERO)
+        );
+        assert_eq!(
+            Ok(970),
+            super::min_amount_out(1000, (1, 1), (1, 1), Percent::from_percent(3))
+        );
+        assert_eq!(
+            Ok(0),
+            super::min_amount_out(1000, (1, 1), (1, 1), Percent::HUNDRED)
+        );
+    }
+
+    #[test]
+    fn min_amount_out_cross() {
+        // 1000 * 3 / 2 = 1500 quote, 1500 * 4 / 5 = 1200 out
+        assert_eq!(
+            Ok(1200),
+            super::min_amount_out(1000, (2, 3), (4, 5), Percent::ZERO)
+        );
+        assert_eq!(
+            Ok(1140),
+            super::min_amount_out(1000, (2, 3), (4, 5), Percent::from_percent(5))
+        );
+    }
+
+    #[test]
+    fn min_amount_out_overflow() {
+        assert!(super::min_amount_out(u128::MAX, (1, u128::MAX), (1, 1), Percent::ZERO).is_err());
+    }
+}
@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{coin::Coin, percent::Percent, zero::Zero};
+use crate::{
+    coin::Coin,
+    fraction::Fraction,
+    percent::{Percent, Units},
+    ratio::Rational,
+    zero::Zero,
+};
 use currency::Currency;
 
 use super::{Liability, Zone};
@@ -82,17 +88,46 @@ pub fn check_liability<Asset>(
     min_liquidation: Coin<Asset>,
     min_asset: Coin<Asset>,
 ) -> Status<Asset>
+where
+    Asset: Currency,
+{
+    check_liability_with_penalty(
+        spec,
+        asset,
+        total_due,
+        overdue,
+        Percent::ZERO,
+        min_liquidation,
+        min_asset,
+    )
+}
+
+/// Check the liability as [`check_liability`] does if a `penalty` share of the liquidation
+/// proceeds does not go to repay the loan
+///
+/// The liquidation amounts are increased to cover the penalty.
+pub fn check_liability_with_penalty<Asset>(
+    spec: &Liability,
+    asset: Coin<Asset>,
+    total_due: Coin<Asset>,
+    overdue: Coin<Asset>,
+    penalty: Percent,
+    min_liquidation: Coin<Asset>,
+    min_asset: Coin<Asset>,
+) -> Status<Asset>
 where
     Asset: Currency,
 {
     debug_assert!(asset != Coin::ZERO);
     debug_assert!(total_due <= asset);
     debug_assert!(overdue <= total_due);
+    debug_assert!(penalty <= Percent::HUNDRED);
     let ltv = Percent::from_ratio(total_due, asset);
-    may_ask_liquidation_liability(spec, asset, total_due, min_liquidation, min_asset)
+    may_ask_liquidation_liability(spec, asset, total_due, penalty, min_liquidation, min_asset)
         .max(may_ask_liquidation_overdue(
             asset,
             overdue,
+            penalty,
             min_liquidation,
             min_asset,
         ))
@@ -115,13 +150,14 @@ fn may_ask_liquidation_liability<Asset>(
     spec: &Liability,
     asset: Coin<Asset>,
     total_due: Coin<Asset>,
+    penalty: Percent,
     min_liquidation: Coin<Asset>,
     min_asset: Coin<Asset>,
 ) -> Option<Status<Asset>>
 where
     Asset: Currency,
 {
-    let liquidation_amount = spec.amount_to_liquidate(asset, total_due);
+    let liquidation_amount = spec.amount_to_liquidate_with_penalty(asset, total_due, penalty);
     may_ask_liquidation(
         asset,
         Cause::Liability {
@@ -137,13 +173,29 @@ where
 fn may_ask_liquidation_overdue<Asset>(
     asset: Coin<Asset>,
     overdue: Coin<Asset>,
+    penalty: Percent,
     min_liquidation: Coin<Asset>,
     min_asset: Coin<Asset>,
 ) -> Option<Status<Asset>>
 where
     Asset: Currency,
 {
-    may_ask_liquidation(asset, Cause::Overdue(), overdue, min_liquidation, min_asset)
+    // 'overdue = (100% - penalty%) of liquidation'
+    let liquidation = if overdue.is_zero() {
+        overdue
+    } else if penalty < Percent::HUNDRED {
+        let multiplier = Rational::new(Percent::HUNDRED, Percent::HUNDRED - penalty);
+        Fraction::<Units>::of(&multiplier, overdue).min(asset)
+    } else {
+        asset
+    };
+    may_ask_liquidation(
+        asset,
+        Cause::Overdue(),
+        liquidation,
+        min_liquidation,
+        min_asset,
+    )
 }
 
 fn may_ask_liquidation<Asset>(
@@ -171,10 +223,11 @@ mod tests {
         coin::{Amount, Coin},
         duration::Duration,
         percent::Percent,
+        zero::Zero,
     };
     use currency::test::Nls;
 
-    use super::{check_liability, Cause, Liability, Status, Zone};
+    use super::{check_liability, check_liability_with_penalty, Cause, Liability, Status, Zone};
 
     const MIN_DUE_AMOUNT: Coin<Nls> = Coin::new(100);
     const LEASE_AMOUNT: Coin<Nls> = Coin::new(1000);
@@ -889,6 +942,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn liquidate_with_penalty() {
+        let spec = liability_with_max(Percent::from_permille(881));
+
+        assert_eq!(
+            check_liability_with_penalty::<Nls>(
+                &spec,
+                LEASE_AMOUNT,
+                800.into(),
+                MIN_DUE_AMOUNT,
+                Percent::from_percent(20),
+                MIN_DUE_AMOUNT,
+                0.into()
+            ),
+            Status::partial(125.into(), Cause::Overdue()),
+        );
+        assert_eq!(
+            check_liability_with_penalty::<Nls>(
+                &spec,
+                LEASE_AMOUNT,
+                800.into(),
+                Coin::ZERO,
+                Percent::HUNDRED,
+                MIN_DUE_AMOUNT,
+                0.into()
+            ),
+            Status::No(Zone::no_warnings(spec.first_liq_warn())),
+        );
+        assert_eq!(
+            check_liability_with_penalty::<Nls>(
+                &spec,
+                LEASE_AMOUNT,
+                800.into(),
+                MIN_DUE_AMOUNT,
+                Percent::HUNDRED,
+                MIN_DUE_AMOUNT,
+                0.into()
+            ),
+            Status::full(Cause::Overdue()),
+        );
+    }
+
     const STEP: Percent = Percent::from_permille(10);
 
     fn liability_with_first(warn: Percent) -> Liability {
@@ -15,10 +15,10 @@ use crate::{
 };
 
 pub use self::level::Level;
-pub use self::liquidation::check_liability;
 pub use self::liquidation::Cause;
 pub use self::liquidation::Liquidation;
 pub use self::liquidation::Status;
+pub use self::liquidation::{check_liability, check_liability_with_penalty};
 pub use self::zone::Zone;
 
 mod level;
@@ -81,6 +81,10 @@ impl Liability {
         obj
     }
 
+    pub const fn initial_percent(&self) -> Percent {
+        self.initial
+    }
+
     pub const fn healthy_percent(&self) -> Percent {
         self.healthy
     }
@@ -137,6 +141,22 @@ impl Liability {
     /// Post-assert: (total_due - amount_to_liquidate) / (lease_amount - amount_to_liquidate) ~= self.healthy_percent(), if total_due < lease_amount.
     /// Otherwise, amount_to_liquidate == total_due
     pub fn amount_to_liquidate<P>(&self, lease_amount: P, total_due: P) -> P
+    where
+        P: Percentable + Copy + Ord + Sub<Output = P> + Zero,
+    {
+        self.amount_to_liquidate_with_penalty(lease_amount, total_due, Percent::ZERO)
+    }
+
+    /// The amount to liquidate if a `penalty` share of the proceeds does not go to repay the loan
+    ///
+    /// Post-assert: (total_due - (100% - penalty) of amount) / (lease_amount - amount) ~= self.healthy_percent(),
+    /// if the healthy liability is reachable. Otherwise, amount == lease_amount
+    pub fn amount_to_liquidate_with_penalty<P>(
+        &self,
+        lease_amount: P,
+        total_due: P,
+        penalty: Percent,
+    ) -> P
     where
         P: Percentable + Copy + Ord + Sub<Output = P> + Zero,
     {
@@ -146,13 +166,17 @@ impl Liability {
         if lease_amount <= total_due {
             return lease_amount;
         }
+        let unhealthy = Percent::HUNDRED - self.healthy_percent();
+        if unhealthy <= penalty {
+            return lease_amount;
+        }
 
-        // from 'due - liquidation = healthy% of (lease - liquidation)' follows
-        // liquidation = 100% / (100% - healthy%) of (due - healthy% of lease)
-        let multiplier = Rational::new(Percent::HUNDRED, Percent::HUNDRED - self.healthy_percent());
+        // from 'due - (100% - penalty%) of liquidation = healthy% of (lease - liquidation)' follows
+        // liquidation = 100% / (100% - healthy% - penalty%) of (due - healthy% of lease)
+        let multiplier = Rational::new(Percent::HUNDRED, unhealthy - penalty);
         let extra_liability_lpn =
             total_due - total_due.min(self.healthy_percent().of(lease_amount));
-        Fraction::<Units>::of(&multiplier, extra_liability_lpn)
+        Fraction::<Units>::of(&multiplier, extra_liability_lpn).min(lease_amount)
     }
 
     fn invariant_held(&self) -> Result<()> {
@@ -386,6 +410,47 @@ mod test {
     }
 
     #[track_caller]
+    #[test]
+    fn amount_to_liquidate_with_penalty() {
+        let liability = Liability {
+            initial: Percent::from_percent(60),
+            healthy: Percent::from_percent(85),
+            max: Percent::from_percent(90),
+            first_liq_warn: Percent::from_permille(860),
+            second_liq_warn: Percent::from_permille(865),
+            third_liq_warn: Percent::from_permille(870),
+            recalc_time: Duration::from_secs(20000),
+        };
+        let lease_amount: Amount = 1000;
+        let penalty = Percent::from_percent(5);
+
+        assert_eq!(
+            Amount::ZERO,
+            liability.amount_to_liquidate_with_penalty(lease_amount, 899, penalty)
+        );
+        // 900 - 95% of 500 = 85% of (1000 - 500)
+        assert_eq!(
+            500,
+            liability.amount_to_liquidate_with_penalty(lease_amount, 900, penalty)
+        );
+        assert_eq!(
+            liability.amount_to_liquidate(lease_amount, 900),
+            liability.amount_to_liquidate_with_penalty(lease_amount, 900, Percent::ZERO)
+        );
+        assert_eq!(
+            lease_amount,
+            liability.amount_to_liquidate_with_penalty(lease_amount, 950, penalty)
+        );
+        assert_eq!(
+            lease_amount,
+            liability.amount_to_liquidate_with_penalty(
+                lease_amount,
+                900,
+                Percent::from_percent(15)
+            )
+        );
+    }
+
     fn amount_to_liquidate_int(liability: Liability, lease: Amount, due: Amount, exp: Amount) {
         let liq = liability.amount_to_liquidate(lease, due);
         assert_eq!(exp, liq);
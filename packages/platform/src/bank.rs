@@ -76,6 +76,21 @@ where
     may_res
 }
 
+/// Run a command on each coin of the specified group and aggregate the outputs
+///
+/// Coins not in the group are ignored. Return `None` if there is no such coin.
+pub fn may_received_all<G, Cmd>(cw_amount: Vec<CwCoin>, cmd: Cmd) -> Option<WithCoinResult<Cmd>>
+where
+    G: Group,
+    Cmd: WithCoin,
+    Cmd::Output: Aggregate,
+{
+    cw_amount
+        .into_iter()
+        .filter_map(|cw_coin| maybe_from_cosmwasm_any_impl::<G, _>(cw_coin, &cmd))
+        .reduce_results(Aggregate::aggregate)
+}
+
 pub struct BankView<'a> {
     account: &'a Addr,
     querier: &'a QuerierWrapper<'a>,
@@ -357,7 +372,7 @@ mod test {
         payment::PaymentGroup,
     };
     use currency::{
-        test::{Dai, TestCurrencies, Usdc},
+        test::{Dai, Nls as TestNls, TestCurrencies, Usdc},
         Currency, Group, SymbolStatic,
     };
     use finance::{
@@ -371,7 +386,9 @@ mod test {
 
     use crate::{coin_legacy, error::Error};
 
-    use super::{may_received, BankAccountView as _, BankView, ReduceResults as _};
+    use super::{
+        may_received, may_received_all, BankAccountView as _, BankView, ReduceResults as _,
+    };
 
     type TheCurrency = Usdc;
     type ExtraCurrency = Dai;
@@ -519,6 +536,37 @@ mod test {
         );
     }
 
+    struct Symbols;
+
+    impl WithCoin for Symbols {
+        type Output = Vec<SymbolStatic>;
+        type Error = Error;
+
+        fn on<C>(&self, _: Coin<C>) -> WithCoinResult<Self>
+        where
+            C: Currency,
+        {
+            Ok(vec![C::BANK_SYMBOL])
+        }
+    }
+
+    #[test]
+    fn may_received_all_no_input() {
+        assert_eq!(None, may_received_all::<TestCurrencies, _>(vec![], Symbols));
+    }
+
+    #[test]
+    fn may_received_all_in_group_others_arround() {
+        let in_coin_1 = coin_legacy::to_cosmwasm(Coin::<ExtraCurrency>::new(AMOUNT));
+        let in_coin_2 = coin_legacy::to_cosmwasm(Coin::<TheCurrency>::new(AMOUNT));
+        let in_coin_3 = coin_legacy::to_cosmwasm(Coin::<TestNls>::new(AMOUNT));
+
+        assert_eq!(
+            Some(Ok(vec![TheCurrency::BANK_SYMBOL, TestNls::BANK_SYMBOL])),
+            may_received_all::<TestCurrencies, _>(vec![in_coin_1, in_coin_2, in_coin_3], Symbols)
+        );
+    }
+
     struct Cmd<'r> {
         expected: &'r [&'static str],
     }
@@ -144,6 +144,23 @@ impl Batch {
         Ok(())
     }
 
+    pub fn schedule_migrate_wasm_on_error_reply<M>(
+        &mut self,
+        addr: &Addr,
+        msg: M,
+        new_code_id: u64,
+        reply_id: ReplyId,
+    ) -> Result<()>
+    where
+        M: Serialize,
+    {
+        let wasm_msg = Self::wasm_migrate_msg(addr, msg, new_code_id)?;
+        let msg_cw = SubMsg::reply_on_error(wasm_msg, reply_id);
+
+        self.msgs.push(msg_cw);
+        Ok(())
+    }
+
     pub fn merge(self, mut other: Batch) -> Self {
         let mut res = self;
         res.msgs.append(&mut other.msgs);
@@ -23,6 +23,11 @@ pub fn validate_code_id(
     })
 }
 
+/// Query the address that instantiated a contract
+pub fn query_creator(querier: &QuerierWrapper<'_>, contract_address: &Addr) -> Result<Addr> {
+    query_info(querier, contract_address).map(|info| Addr::unchecked(info.creator))
+}
+
 fn query_info(
     querier: &QuerierWrapper<'_>,
     contract_address: &Addr,
@@ -38,7 +43,7 @@ fn query_info(
 pub mod tests {
     use sdk::cosmwasm_std::{testing::MockQuerier, Addr, QuerierWrapper};
 
-    use crate::contract::testing::{self, CODE_ID};
+    use crate::contract::testing::{self, CODE_ID, CREATOR};
 
     use super::validate_addr;
 
@@ -69,6 +74,19 @@ pub mod tests {
         let address = Addr::unchecked("some address");
         assert!(super::validate_code_id(&querier, &address, CODE_ID).is_ok());
     }
+
+    #[test]
+    fn query_creator() {
+        let mut mock_querier = MockQuerier::default();
+        mock_querier.update_wasm(testing::valid_contract_handler);
+        let querier = QuerierWrapper::new(&mock_querier);
+
+        let address = Addr::unchecked("some address");
+        assert_eq!(
+            Ok(Addr::unchecked(CREATOR)),
+            super::query_creator(&querier, &address)
+        );
+    }
 }
 
 #[cfg(any(feature = "testing", test))]
@@ -78,6 +96,7 @@ pub mod testing {
     };
 
     pub const CODE_ID: u64 = 20;
+    pub const CREATOR: &str = "some data";
 
     pub fn valid_contract_handler(_query: &WasmQuery) -> QuerierResult {
         SystemResult::Ok(ContractResult::Ok(
@@ -85,7 +104,7 @@ pub mod testing {
                 let mut response = ContractInfoResponse::default();
 
                 response.code_id = CODE_ID;
-                response.creator = "some data".into();
+                response.creator = CREATOR.into();
 
                 response
             })
@@ -19,25 +19,21 @@ use crate::{
 };
 
 const REQUEST_MSG_TYPE: &str = "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn";
+const BELOW_MIN_AMOUNT_OUT_ERROR: &str = "ABCI code: 7:";
 
 pub fn exact_amount_in<G>(
     trx: &mut Transaction,
     sender: HostAccount,
     token_in: &CoinDTO<G>,
     swap_path: &SwapPath,
+    token_out_min_amount: Amount,
 ) -> Result<()>
 where
     G: Group,
 {
-    // TODO bring the token balances, weights and swapFee-s from the DEX pools
-    // into the oracle in order to calculate the tokenOut as per the formula at
-    // https://docs.osmosis.zone/osmosis-core/modules/gamm/#swap.
-    // Then apply the parameterized maximum slippage to get the minimum amount.
-    // For the first version, we accept whatever price impact and slippage.
-    const MIN_OUT_AMOUNT: &str = "1";
     let routes = to_route(swap_path)?;
     let token_in = Some(to_cwcoin(token_in)?);
-    let token_out_min_amount = MIN_OUT_AMOUNT.into();
+    let token_out_min_amount = token_out_min_amount.to_string();
     let msg = MsgSwapExactAmountIn {
         sender: sender.into(),
         routes,
@@ -64,6 +60,17 @@ where
     Amount::from_str(&amount).map_err(|_| Error::InvalidAmount(amount))
 }
 
+/// Whether a failed swap transaction has been rejected due to an output below the minimum
+///
+/// The interchain accounts host redacts the error acknowledgements down to the ABCI code,
+/// "ABCI code: {code}: error handling packet: see events for details". The Osmosis `gamm`
+/// module reports `ErrLimitMinAmount` with code 7. The codespace is redacted as well, so
+/// a failure of another module with the same code cannot be told apart and is reported
+/// as a rejection too.
+pub fn is_below_min_amount_out(error_details: &str) -> bool {
+    error_details.starts_with(BELOW_MIN_AMOUNT_OUT_ERROR)
+}
+
 #[cfg(any(test, feature = "testing"))]
 pub fn build_exact_amount_in_resp(amount_out: Amount) -> MsgData {
     use sdk::cosmos_sdk_proto::traits::Message as _;
@@ -173,4 +180,17 @@ mod test {
         assert_eq!(amount, parsed);
         assert_eq!(None, resp.next());
     }
+
+    #[test]
+    fn below_min_amount_out() {
+        assert!(super::is_below_min_amount_out(
+            "ABCI code: 7: error handling packet: see events for details"
+        ));
+        assert!(!super::is_below_min_amount_out(
+            "ABCI code: 5: error handling packet: see events for details"
+        ));
+        assert!(!super::is_below_min_amount_out(
+            "ABCI code: 70: error handling packet: see events for details"
+        ));
+    }
 }
@@ -1,15 +1,15 @@
-use currency::{self, Currency};
+use currency::{self, lpn::Usdc, Currency};
 use finance::{
     coin::{Amount, Coin},
     duration::Duration,
     liability::Liability,
-    percent::Percent,
+    percent::{bound::BoundToHundredPercent, Percent},
     price::{self, Price},
 };
 use lease::{
     api::{
-        ConnectionParams, Ics20Channel, InterestPaymentSpec, LoanForm, NewLeaseContract,
-        NewLeaseForm, StateQuery, StateResponse,
+        ConnectionParams, Ics20Channel, InterestPaymentSpec, LiquidationFee, LiquidationMode,
+        LoanForm, LpnCoin, NewLeaseContract, NewLeaseForm, RateType, StateQuery, StateResponse,
     },
     contract::{execute, instantiate, query, reply, sudo},
 };
@@ -23,6 +23,7 @@ use swap::trx as swap_trx;
 
 use super::{
     cwcoin,
+    leaser::Instantiator as LeaserInstantiator,
     test_case::{
         app::App,
         response::{RemoteChain as _, ResponseWithInterChainMsgs},
@@ -97,6 +98,12 @@ impl Instantiator {
                     config.liability_minus_delta_to_third_liq_warn,
                     config.liability_recalc_time,
                 ),
+                min_liquidation: config.min_liquidation,
+                min_asset: config.min_asset,
+                max_slippage: config.max_slippage,
+                liquidation_fee: config.liquidation_fee,
+                liquidation_mode: config.liquidation_mode,
+                rate_type: config.rate_type,
                 loan: LoanForm {
                     annual_margin_interest: config.annual_margin_interest,
                     lpp: addresses.lpp,
@@ -144,6 +151,12 @@ pub(crate) struct InstantiatorConfig {
     pub liability_minus_delta_to_second_liq_warn: Percent,
     pub liability_minus_delta_to_third_liq_warn: Percent,
     pub liability_recalc_time: Duration,
+    pub min_liquidation: LpnCoin,
+    pub min_asset: LpnCoin,
+    pub max_slippage: BoundToHundredPercent,
+    pub liquidation_fee: LiquidationFee,
+    pub liquidation_mode: LiquidationMode,
+    pub rate_type: RateType,
     // LoanForm
     pub annual_margin_interest: Percent,
     pub interest_payment: InterestPaymentSpec,
@@ -162,6 +175,12 @@ impl Default for InstantiatorConfig {
             liability_minus_delta_to_second_liq_warn: Percent::from_percent(3),
             liability_minus_delta_to_third_liq_warn: Percent::from_percent(2),
             liability_recalc_time: Duration::from_days(20),
+            min_liquidation: Coin::<Usdc>::new(LeaserInstantiator::MIN_LIQUIDATION).into(),
+            min_asset: Coin::<Usdc>::new(LeaserInstantiator::MIN_ASSET).into(),
+            max_slippage: LeaserInstantiator::max_slippage(),
+            liquidation_fee: LiquidationFee::default(),
+            liquidation_mode: LiquidationMode::default(),
+            rate_type: RateType::default(),
 
             annual_margin_interest: Percent::from_percent(0), // 3.1%
             interest_payment: InterestPaymentSpec::new(
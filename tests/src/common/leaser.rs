@@ -1,6 +1,12 @@
 use currency::Currency;
-use finance::{coin::Coin, duration::Duration, liability::Liability, percent::Percent, test};
-use lease::api::InterestPaymentSpec;
+use finance::{
+    coin::{Amount, Coin},
+    duration::Duration,
+    liability::Liability,
+    percent::{bound::BoundToHundredPercent, Percent},
+    test,
+};
+use lease::api::{DownpaymentCoin, InterestPaymentSpec, LiquidationFee, RateType};
 use leaser::{
     contract::{execute, instantiate, query, reply, sudo},
     msg::{InstantiateMsg, QueryMsg, QuoteResponse},
@@ -18,6 +24,14 @@ impl Instantiator {
 
     pub const GRACE_PERIOD: Duration = Duration::from_days(10);
 
+    pub const MIN_LIQUIDATION: Amount = 10_000;
+
+    pub const MIN_ASSET: Amount = 15_000_000;
+
+    pub fn max_slippage() -> BoundToHundredPercent {
+        Percent::from_percent(3).try_into().unwrap()
+    }
+
     pub fn liability() -> Liability {
         Liability::new(
             Percent::from_percent(65),
@@ -31,14 +45,17 @@ impl Instantiator {
     }
 
     #[track_caller]
-    pub fn instantiate(
+    pub fn instantiate<Lpn>(
         app: &mut App,
         lease_code_id: u64,
         lpp_addr: Addr,
         time_alarms: Addr,
         market_price_oracle: Addr,
         profit: Addr,
-    ) -> Addr {
+    ) -> Addr
+    where
+        Lpn: Currency,
+    {
         // TODO [Rust 1.70] Convert to static item with OnceCell
         let endpoints = CwContractWrapper::new(execute, instantiate, query)
             .with_reply(reply)
@@ -51,6 +68,10 @@ impl Instantiator {
             lpp_ust_addr: lpp_addr,
             lease_interest_rate_margin: Self::INTEREST_RATE_MARGIN,
             liability: Self::liability(),
+            min_liquidation: Coin::<Lpn>::new(Self::MIN_LIQUIDATION).into(),
+            min_asset: Coin::<Lpn>::new(Self::MIN_ASSET).into(),
+            max_slippage: Self::max_slippage(),
+            liquidation_fee: LiquidationFee::default(),
             lease_interest_payment: InterestPaymentSpec::new(
                 Self::REPAYMENT_PERIOD,
                 Self::GRACE_PERIOD,
@@ -75,14 +96,32 @@ pub(crate) fn query_quote<DownpaymentC, LeaseC>(
 where
     DownpaymentC: Currency,
     LeaseC: Currency,
+{
+    query_quote_multi::<LeaseC>(
+        app,
+        leaser,
+        vec![test::funds::<_, DownpaymentC>(downpayment.into())],
+        max_ltd,
+    )
+}
+
+pub(crate) fn query_quote_multi<LeaseC>(
+    app: &mut App,
+    leaser: Addr,
+    downpayment: Vec<DownpaymentCoin>,
+    max_ltd: Option<Percent>,
+) -> QuoteResponse
+where
+    LeaseC: Currency,
 {
     app.query()
         .query_wasm_smart(
             leaser,
             &QueryMsg::Quote {
-                downpayment: test::funds::<_, DownpaymentC>(downpayment.into()),
+                downpayment: downpayment.into(),
                 lease_asset: LeaseC::TICKER.into(),
                 max_ltd,
+                rate_type: RateType::Fixed,
             },
         )
         .unwrap()
@@ -1,3 +1,4 @@
+use finance::percent::{bound::BoundToHundredPercent, Percent};
 use profit::{
     contract::{execute, instantiate, query, sudo},
     msg::InstantiateMsg,
@@ -10,6 +11,10 @@ use super::{test_case::app::App, CwContractWrapper, ADMIN};
 pub(crate) struct Instantiator;
 
 impl Instantiator {
+    pub fn max_slippage() -> BoundToHundredPercent {
+        Percent::from_percent(3).try_into().unwrap()
+    }
+
     #[track_caller]
     pub fn instantiate(
         app: &mut App,
@@ -25,6 +30,7 @@ impl Instantiator {
 
         let msg = InstantiateMsg {
             cadence_hours,
+            max_slippage: Self::max_slippage(),
             treasury,
             oracle,
             timealarms,
@@ -264,7 +264,7 @@ where
             _lpn,
         } = self;
 
-        let leaser_addr = LeaserInstantiator::instantiate(
+        let leaser_addr = LeaserInstantiator::instantiate::<Lpn>(
             &mut test_case.app,
             test_case.address_book.lease_code_id(),
             test_case.address_book.lpp().clone(),
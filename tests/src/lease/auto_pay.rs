@@ -0,0 +1,223 @@
+use ::lease::api::{
+    AutoPayQuery, AutoPayResponse, ExecuteMsg, ProtocolPause, QueryMsg, StateResponse,
+};
+use currency::Currency;
+use finance::coin::Amount;
+use sdk::{
+    cosmwasm_std::{Addr, Event},
+    cw_multi_test::AppResponse,
+};
+
+use crate::{
+    common::{
+        cwcoin,
+        leaser::Instantiator as LeaserInstantiator,
+        test_case::{response::ResponseWithInterChainMsgs, TestCase},
+        ADMIN, USER,
+    },
+    lease,
+};
+
+use super::{Lpn, LpnCoin, PaymentCurrency, DOWNPAYMENT};
+
+#[test]
+fn auto_pay_overdue() {
+    let mut test_case = lease::create_test_case::<PaymentCurrency>();
+    let downpayment = lease::create_payment_coin(DOWNPAYMENT);
+    let lease_address = lease::open_lease(&mut test_case, downpayment, None);
+
+    let reserve = LpnCoin::new(DOWNPAYMENT);
+    fund_auto_pay(&mut test_case, lease_address.clone(), reserve);
+    assert_eq!(
+        Some(reserve.into()),
+        auto_pay_query(&test_case, lease_address.clone()).reserve
+    );
+
+    test_case
+        .app
+        .time_shift(LeaserInstantiator::REPAYMENT_PERIOD + LeaserInstantiator::GRACE_PERIOD);
+
+    lease::feed_price(&mut test_case);
+
+    let response: ResponseWithInterChainMsgs<'_, AppResponse> = test_case
+        .app
+        .execute(
+            test_case.address_book.time_alarms().clone(),
+            lease_address.clone(),
+            &ExecuteMsg::TimeAlarm {},
+            &[],
+        )
+        .unwrap();
+    let response: AppResponse = response.unwrap_response();
+
+    assert!(response.has_event(&Event::new("wasm-ls-auto-pay")));
+    assert!(!response.has_event(&Event::new("wasm-ls-auto-pay-low")));
+    assert!(!response.has_event(&Event::new("wasm-ls-liquidation-start")));
+
+    let StateResponse::Opened {
+        previous_margin_due,
+        previous_interest_due,
+        ..
+    } = lease::state_query(&test_case, lease_address.as_str())
+    else {
+        unreachable!()
+    };
+    assert!(previous_margin_due.is_zero());
+    assert!(previous_interest_due.is_zero());
+
+    let reserve_left: LpnCoin = auto_pay_query(&test_case, lease_address.clone())
+        .reserve
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert!(reserve_left < reserve);
+    assert_eq!(reserve_left, lpn_balance(&test_case, lease_address));
+}
+
+#[test]
+fn auto_pay_paused_overdue() {
+    let mut test_case = lease::create_test_case::<PaymentCurrency>();
+    let downpayment = lease::create_payment_coin(DOWNPAYMENT);
+    let lease_address = lease::open_lease(&mut test_case, downpayment, None);
+
+    let reserve = LpnCoin::new(DOWNPAYMENT);
+    fund_auto_pay(&mut test_case, lease_address.clone(), reserve);
+
+    () = test_case
+        .app
+        .sudo(
+            test_case.address_book.leaser().clone(),
+            &leaser::msg::SudoMsg::Pause(ProtocolPause {
+                repayments: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+
+    test_case
+        .app
+        .time_shift(LeaserInstantiator::REPAYMENT_PERIOD + LeaserInstantiator::GRACE_PERIOD);
+
+    lease::feed_price(&mut test_case);
+
+    let mut response: ResponseWithInterChainMsgs<'_, AppResponse> = test_case
+        .app
+        .execute(
+            test_case.address_book.time_alarms().clone(),
+            lease_address.clone(),
+            &ExecuteMsg::TimeAlarm {},
+            &[],
+        )
+        .unwrap();
+    response.expect_submit_tx(TestCase::LEASER_CONNECTION_ID, "0", 1);
+    let response: AppResponse = response.unwrap_response();
+
+    assert!(!response.has_event(&Event::new("wasm-ls-auto-pay")));
+    assert!(response.has_event(&Event::new("wasm-ls-liquidation-start")));
+
+    assert_eq!(reserve, lpn_balance(&test_case, lease_address));
+}
+
+#[test]
+fn auto_pay_withdraw() {
+    let mut test_case = lease::create_test_case::<PaymentCurrency>();
+    let downpayment = lease::create_payment_coin(DOWNPAYMENT);
+    let lease_address = lease::open_lease(&mut test_case, downpayment, None);
+
+    let reserve = LpnCoin::new(DOWNPAYMENT);
+    fund_auto_pay(&mut test_case, lease_address.clone(), reserve);
+
+    let user_balance = lpn_balance(&test_case, Addr::unchecked(USER));
+
+    let response: AppResponse = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_address.clone(),
+            &ExecuteMsg::WithdrawAutoPay(),
+            &[],
+        )
+        .unwrap()
+        .unwrap_response();
+    assert!(response.has_event(&Event::new("wasm-ls-auto-pay-withdraw")));
+
+    assert_eq!(
+        None,
+        auto_pay_query(&test_case, lease_address.clone()).reserve
+    );
+    assert_eq!(
+        user_balance + reserve,
+        lpn_balance(&test_case, Addr::unchecked(USER))
+    );
+    assert!(lpn_balance(&test_case, lease_address).is_zero());
+}
+
+#[test]
+fn auto_pay_not_customer() {
+    let mut test_case = lease::create_test_case::<PaymentCurrency>();
+    let downpayment = lease::create_payment_coin(DOWNPAYMENT);
+    let lease_address = lease::open_lease(&mut test_case, downpayment, None);
+
+    let payment = LpnCoin::new(DOWNPAYMENT);
+    let _err = test_case
+        .app
+        .execute(
+            Addr::unchecked(ADMIN),
+            lease_address.clone(),
+            &ExecuteMsg::FundAutoPay(),
+            &[cwcoin(payment)],
+        )
+        .unwrap_err();
+
+    assert_eq!(None, auto_pay_query(&test_case, lease_address).reserve);
+}
+
+fn fund_auto_pay<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, TimeAlarms>(
+    test_case: &mut TestCase<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, TimeAlarms>,
+    lease_address: Addr,
+    payment: LpnCoin,
+) {
+    test_case.send_funds_from_admin(Addr::unchecked(USER), &[cwcoin(payment)]);
+
+    let response: AppResponse = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_address,
+            &ExecuteMsg::FundAutoPay(),
+            &[cwcoin(payment)],
+        )
+        .unwrap()
+        .unwrap_response();
+    assert!(response.has_event(&Event::new("wasm-ls-auto-pay-fund")));
+}
+
+fn auto_pay_query<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, TimeAlarms>(
+    test_case: &TestCase<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, TimeAlarms>,
+    lease_address: Addr,
+) -> AutoPayResponse {
+    test_case
+        .app
+        .query()
+        .query_wasm_smart(
+            lease_address,
+            &QueryMsg::AutoPay {
+                auto_pay: AutoPayQuery {},
+            },
+        )
+        .unwrap()
+}
+
+fn lpn_balance<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, TimeAlarms>(
+    test_case: &TestCase<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, TimeAlarms>,
+    account: Addr,
+) -> LpnCoin {
+    test_case
+        .app
+        .query()
+        .query_balance(account, Lpn::BANK_SYMBOL)
+        .map(|balance| LpnCoin::new(Amount::from(balance.amount)))
+        .unwrap()
+}
@@ -0,0 +1,136 @@
+use ::lease::{
+    api::{ClosePolicy, ExecuteMsg, StateResponse},
+    error::ContractError,
+};
+use finance::price;
+use sdk::cosmwasm_std::Addr;
+
+use crate::common::{
+    self,
+    test_case::{response::RemoteChain as _, TestCase},
+    ADMIN, USER,
+};
+
+use super::{LeaseCoin, LpnCoin, PaymentCurrency, DOWNPAYMENT};
+
+#[test]
+fn change_close_policy() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let policy = ClosePolicy {
+        stop_loss: Some(
+            price::total_of(LeaseCoin::new(2))
+                .is(LpnCoin::new(1))
+                .into(),
+        ),
+        take_profit: Some(
+            price::total_of(LeaseCoin::new(1))
+                .is(LpnCoin::new(2))
+                .into(),
+        ),
+    };
+    () = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_address.clone(),
+            &ExecuteMsg::ChangeClosePolicy(policy.clone()),
+            &[],
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+
+    let StateResponse::Opened { close_policy, .. } =
+        super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+    assert_eq!(policy, close_policy);
+}
+
+#[test]
+fn change_close_policy_trigger_now() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let err = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_address,
+            &ExecuteMsg::ChangeClosePolicy(ClosePolicy {
+                stop_loss: None,
+                take_profit: Some(super::price_lpn_of::<super::LeaseCurrency>().into()),
+            }),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        Some(&ContractError::ClosePolicyTriggerNow()),
+        err.downcast_ref::<ContractError>()
+    );
+}
+
+#[test]
+fn stop_loss() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    () = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_address.clone(),
+            &ExecuteMsg::ChangeClosePolicy(ClosePolicy {
+                stop_loss: Some(
+                    price::total_of(LeaseCoin::new(100))
+                        .is(LpnCoin::new(99))
+                        .into(),
+                ),
+                take_profit: None,
+            }),
+            &[],
+        )
+        .unwrap()
+        .ignore_response()
+        .unwrap_response();
+
+    common::oracle::feed_price(
+        &mut test_case,
+        Addr::unchecked(ADMIN),
+        LeaseCoin::new(100),
+        LpnCoin::new(98),
+    );
+
+    let mut response = test_case
+        .app
+        .execute(
+            test_case.address_book.oracle().clone(),
+            lease_address.clone(),
+            &ExecuteMsg::PriceAlarm(),
+            &[],
+        )
+        .unwrap();
+    response.expect_submit_tx(TestCase::LEASER_CONNECTION_ID, "0", 1);
+    let response = response.unwrap_response();
+
+    let event = response
+        .events
+        .iter()
+        .find(|event| event.ty == "wasm-ls-close-policy-trigger")
+        .expect("No close policy trigger emitted!");
+    assert!(event
+        .attributes
+        .iter()
+        .any(|attribute| attribute.key == "cause" && attribute.value == "stop-loss"));
+
+    let StateResponse::Opened { in_progress, .. } =
+        super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+    assert!(in_progress.is_some());
+}
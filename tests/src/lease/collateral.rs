@@ -0,0 +1,175 @@
+use finance::{coin::Amount, price};
+use osmosis_std::types::osmosis::gamm::v1beta1::{
+    MsgSwapExactAmountIn, MsgSwapExactAmountInResponse,
+};
+
+use ::lease::api::{ExecuteMsg, StateResponse};
+use sdk::{
+    cosmwasm_std::{Addr, Binary, Coin as CwCoin},
+    cw_multi_test::AppResponse,
+};
+
+use crate::common::{
+    cwcoin,
+    test_case::{
+        response::{RemoteChain as _, ResponseWithInterChainMsgs},
+        TestCase,
+    },
+    ADMIN, USER,
+};
+
+use super::{LeaseCoin, LeaseCurrency, PaymentCoin, PaymentCurrency, DOWNPAYMENT};
+
+#[test]
+fn add_collateral() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let StateResponse::Opened { amount: amount_before, .. } =
+        super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+
+    let collateral = super::create_payment_coin(DOWNPAYMENT / 2);
+    add(&mut test_case, lease_address.clone(), collateral);
+
+    let StateResponse::Opened { amount, in_progress, .. } =
+        super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+
+    let exp_collateral: LeaseCoin = price::total(
+        price::total(collateral, super::price_lpn_of::<PaymentCurrency>()),
+        super::price_lpn_of::<LeaseCurrency>().inv(),
+    );
+    assert_eq!(
+        LeaseCoin::try_from(amount_before).unwrap() + exp_collateral,
+        LeaseCoin::try_from(amount).unwrap()
+    );
+    assert!(in_progress.is_none());
+}
+
+#[test]
+fn add_collateral_in_lease_currency() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let StateResponse::Opened { amount: amount_before, .. } =
+        super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+
+    let collateral = LeaseCoin::new(1_000);
+    test_case.send_funds_from_admin(Addr::unchecked(USER), &[cwcoin(collateral)]);
+
+    // no swap is needed, the collateral is added once transferred to the DEX account
+    () = send_collateral_and_transfer(&mut test_case, lease_address.clone(), cwcoin(collateral))
+        .unwrap_response();
+
+    let StateResponse::Opened { amount, in_progress, .. } =
+        super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+    assert_eq!(
+        LeaseCoin::try_from(amount_before).unwrap() + collateral,
+        LeaseCoin::try_from(amount).unwrap()
+    );
+    assert!(in_progress.is_none());
+}
+
+fn add<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle>(
+    test_case: &mut TestCase<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, Addr>,
+    lease_addr: Addr,
+    collateral: PaymentCoin,
+) -> AppResponse {
+    let cw_collateral: CwCoin = cwcoin(collateral);
+
+    let mut response: ResponseWithInterChainMsgs<'_, ()> =
+        send_collateral_and_transfer(test_case, lease_addr.clone(), cw_collateral.clone());
+    response.expect_submit_tx(TestCase::LEASER_CONNECTION_ID, "0", 1);
+    () = response.unwrap_response();
+
+    let swap_out: LeaseCoin = price::total(
+        price::total(collateral, super::price_lpn_of::<PaymentCurrency>()),
+        super::price_lpn_of::<LeaseCurrency>().inv(),
+    );
+    do_swap(test_case, lease_addr, &cw_collateral, swap_out)
+}
+
+fn send_collateral_and_transfer<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle>(
+    test_case: &mut TestCase<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, Addr>,
+    lease_addr: Addr,
+    cw_collateral: CwCoin,
+) -> ResponseWithInterChainMsgs<'_, ()> {
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_addr.clone(),
+            &ExecuteMsg::AddCollateral(),
+            std::slice::from_ref(&cw_collateral),
+        )
+        .unwrap()
+        .ignore_response();
+
+    response.expect_ibc_transfer(
+        "channel-0",
+        cw_collateral.clone(),
+        lease_addr.as_str(),
+        "ica0",
+    );
+
+    () = response.unwrap_response();
+
+    test_case
+        .app
+        .send_tokens(
+            lease_addr.clone(),
+            Addr::unchecked("ica0"),
+            &[cw_collateral],
+        )
+        .unwrap();
+
+    test_case
+        .app
+        .sudo(lease_addr, &super::construct_response(Binary::default()))
+        .unwrap()
+        .ignore_response()
+}
+
+fn do_swap<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle>(
+    test_case: &mut TestCase<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, Addr>,
+    lease_addr: Addr,
+    cw_collateral: &CwCoin,
+    swap_out: LeaseCoin,
+) -> AppResponse {
+    test_case
+        .app
+        .send_tokens(
+            Addr::unchecked("ica0"),
+            Addr::unchecked(ADMIN),
+            std::slice::from_ref(cw_collateral),
+        )
+        .unwrap();
+
+    test_case.send_funds_from_admin(Addr::unchecked("ica0"), &[cwcoin(swap_out)]);
+
+    test_case
+        .app
+        .sudo(
+            lease_addr,
+            &super::construct_response(Binary(platform::trx::encode_msg_responses(
+                [platform::trx::encode_msg_response(
+                    MsgSwapExactAmountInResponse {
+                        token_out_amount: Amount::from(swap_out).to_string(),
+                    },
+                    MsgSwapExactAmountIn::TYPE_URL,
+                )]
+                .into_iter(),
+            ))),
+        )
+        .unwrap()
+        .unwrap_response()
+}
@@ -33,7 +33,10 @@ fn manual_calculation() {
         previous_interest_due: LpnCoin::new(32_054_794_520).into(),
         current_margin_due: LpnCoin::new(13_737_769_080).into(),
         current_interest_due: LpnCoin::new(32_054_794_520).into(),
+        // the lease price is not fed after the time shift
+        liability: None,
         validity: super::block_time(&test_case),
+        close_policy: Default::default(),
         in_progress: None,
     };
 
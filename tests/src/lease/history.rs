@@ -0,0 +1,75 @@
+use finance::zero::Zero;
+use sdk::cosmwasm_std::Addr;
+
+use ::lease::api::{HistoryEntry, HistoryQuery, HistoryResponse, QueryMsg};
+
+use crate::common::test_case::TestCase;
+
+use super::{LpnCoin, PaymentCurrency, DOWNPAYMENT};
+
+#[test]
+fn history_of_repayments() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    assert!(history_query(&test_case, lease_address.clone(), None, None)
+        .records
+        .is_empty());
+
+    let borrowed: LpnCoin = super::quote_borrow(&test_case, downpayment);
+    let payment = super::create_payment_coin(u128::from(borrowed) / 4);
+    super::repay::repay(&mut test_case, lease_address.clone(), payment);
+    super::repay::repay(&mut test_case, lease_address.clone(), payment);
+
+    let HistoryResponse { records } = history_query(&test_case, lease_address.clone(), None, None);
+    assert_eq!(2, records.len());
+    records.iter().zip(1..).for_each(|(record, id)| {
+        assert_eq!(id, record.id);
+        assert_eq!(test_case.app.block_info().time, record.at);
+
+        let HistoryEntry::Repayment { payment: paid } = &record.entry else {
+            unreachable!()
+        };
+        let total = LpnCoin::try_from(paid.total.clone()).unwrap();
+        assert_eq!(u128::from(payment), u128::from(total));
+        assert!(!paid.loan_close);
+        assert_eq!(
+            total,
+            [
+                &paid.previous_margin_interest,
+                &paid.previous_loan_interest,
+                &paid.current_margin_interest,
+                &paid.current_loan_interest,
+                &paid.principal,
+                &paid.change,
+            ]
+            .into_iter()
+            .map(|part| LpnCoin::try_from(part.clone()).unwrap())
+            .fold(LpnCoin::ZERO, |sum, part| sum + part)
+        );
+    });
+
+    let HistoryResponse { records: page } =
+        history_query(&test_case, lease_address, Some(1), Some(1));
+    assert_eq!(1, page.len());
+    assert_eq!(records[1], page[0]);
+}
+
+fn history_query<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, TimeAlarms>(
+    test_case: &TestCase<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, TimeAlarms>,
+    lease_address: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> HistoryResponse {
+    test_case
+        .app
+        .query()
+        .query_wasm_smart(
+            lease_address,
+            &QueryMsg::History {
+                history: HistoryQuery { start_after, limit },
+            },
+        )
+        .unwrap()
+}
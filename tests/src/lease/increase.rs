@@ -0,0 +1,187 @@
+use finance::{coin::Amount, percent::Percent, price};
+use osmosis_std::types::osmosis::gamm::v1beta1::{
+    MsgSwapExactAmountIn, MsgSwapExactAmountInResponse,
+};
+
+use ::lease::{
+    api::{ExecuteMsg, StateResponse},
+    error::ContractError,
+};
+use sdk::{
+    cosmwasm_std::{Addr, Binary, Coin as CwCoin},
+    cw_multi_test::AppResponse,
+};
+
+use crate::common::{
+    cwcoin,
+    test_case::{
+        response::{RemoteChain as _, ResponseWithInterChainMsgs},
+        TestCase,
+    },
+    ADMIN, USER,
+};
+
+use super::{LeaseCoin, LeaseCurrency, LpnCoin, PaymentCurrency, DOWNPAYMENT};
+
+const MAX_LTD: Percent = Percent::HUNDRED;
+
+#[test]
+fn increase_loan() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, Some(MAX_LTD));
+
+    let StateResponse::Opened {
+        amount: amount_before,
+        principal_due: principal_before,
+        ..
+    } = super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+
+    let increase = LpnCoin::new(DOWNPAYMENT / 2);
+    increase_and_swap(&mut test_case, lease_address.clone(), increase);
+
+    let StateResponse::Opened {
+        amount,
+        principal_due,
+        in_progress,
+        ..
+    } = super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+
+    let exp_asset: LeaseCoin = price::total(increase, super::price_lpn_of::<LeaseCurrency>().inv());
+    assert_eq!(
+        LeaseCoin::try_from(amount_before).unwrap() + exp_asset,
+        LeaseCoin::try_from(amount).unwrap()
+    );
+    assert_eq!(
+        LpnCoin::try_from(principal_before).unwrap() + increase,
+        LpnCoin::try_from(principal_due).unwrap()
+    );
+    assert!(in_progress.is_none());
+}
+
+#[test]
+fn increase_loan_above_initial_ltv() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, Some(MAX_LTD));
+
+    let err = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_address,
+            &ExecuteMsg::IncreaseLoan {
+                amount: LpnCoin::new(2 * DOWNPAYMENT).into(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(&ContractError::LoanIncreaseAboveInitialLtv(_))
+    ));
+}
+
+#[test]
+fn increase_loan_unauthorized() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, Some(MAX_LTD));
+
+    let err = test_case
+        .app
+        .execute(
+            Addr::unchecked(ADMIN),
+            lease_address,
+            &ExecuteMsg::IncreaseLoan {
+                amount: LpnCoin::new(DOWNPAYMENT / 2).into(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(&ContractError::Unauthorized(_))
+    ));
+}
+
+fn increase_and_swap<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle>(
+    test_case: &mut TestCase<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, Addr>,
+    lease_addr: Addr,
+    increase: LpnCoin,
+) -> AppResponse {
+    let cw_proceeds: CwCoin = cwcoin(increase);
+
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_addr.clone(),
+            &ExecuteMsg::IncreaseLoan {
+                amount: increase.into(),
+            },
+            &[],
+        )
+        .unwrap()
+        .ignore_response();
+
+    response.expect_ibc_transfer(
+        "channel-0",
+        cw_proceeds.clone(),
+        lease_addr.as_str(),
+        "ica0",
+    );
+    () = response.unwrap_response();
+
+    test_case
+        .app
+        .send_tokens(
+            lease_addr.clone(),
+            Addr::unchecked("ica0"),
+            std::slice::from_ref(&cw_proceeds),
+        )
+        .unwrap();
+
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = test_case
+        .app
+        .sudo(
+            lease_addr.clone(),
+            &super::construct_response(Binary::default()),
+        )
+        .unwrap()
+        .ignore_response();
+    response.expect_submit_tx(TestCase::LEASER_CONNECTION_ID, "0", 1);
+    () = response.unwrap_response();
+
+    let swap_out: LeaseCoin = price::total(increase, super::price_lpn_of::<LeaseCurrency>().inv());
+    test_case
+        .app
+        .send_tokens(
+            Addr::unchecked("ica0"),
+            Addr::unchecked(ADMIN),
+            std::slice::from_ref(&cw_proceeds),
+        )
+        .unwrap();
+    test_case.send_funds_from_admin(Addr::unchecked("ica0"), &[cwcoin(swap_out)]);
+
+    test_case
+        .app
+        .sudo(
+            lease_addr,
+            &super::construct_response(Binary(platform::trx::encode_msg_responses(
+                [platform::trx::encode_msg_response(
+                    MsgSwapExactAmountInResponse {
+                        token_out_amount: Amount::from(swap_out).to_string(),
+                    },
+                    MsgSwapExactAmountIn::TYPE_URL,
+                )]
+                .into_iter(),
+            ))),
+        )
+        .unwrap()
+        .unwrap_response()
+}
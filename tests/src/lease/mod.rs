@@ -6,12 +6,16 @@ use currency::{
 use finance::{
     coin::Coin,
     duration::Duration,
+    fraction::Fraction,
     interest::InterestPeriod,
+    liability::{self, Status},
     percent::Percent,
     period::Period,
     price::{self, Price},
 };
-use lease::api::{StateQuery, StateResponse};
+use lease::api::{
+    LiabilityState, LiquidationPrices, OneOrMany, RateType, StateQuery, StateResponse,
+};
 use leaser::msg::{QueryMsg, QuoteResponse};
 use sdk::{
     cosmwasm_std::{coin, Addr, Binary, Timestamp},
@@ -21,16 +25,25 @@ use std::collections::HashSet;
 
 use crate::common::{
     self, cwcoin,
+    leaser::Instantiator as LeaserInstantiator,
     test_case::{builder::Builder as TestCaseBuilder, response::RemoteChain, TestCase},
     ADDON_OPTIMAL_INTEREST_RATE, ADMIN, BASE_INTEREST_RATE, USER, UTILIZATION_OPTIMAL,
 };
 
+mod auto_pay;
 mod close;
+mod close_policy;
+mod collateral;
 mod compare_with_lpp;
 mod heal;
+mod history;
+mod increase;
 mod liquidation;
 mod open;
+mod ownership;
+mod position_close;
 mod repay;
+mod simulate;
 
 type Lpn = Usdc;
 type LpnCoin = Coin<Lpn>;
@@ -168,6 +181,7 @@ pub(super) fn try_init_lease<Dispatcher, Treasury, Profit, Lpp, Oracle, TimeAlar
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: LeaseCurrency::TICKER.into(),
                 max_ltd,
+                rate_type: RateType::Fixed,
             },
             downpayment.as_ref().map_or(&[], std::slice::from_ref),
         )
@@ -231,9 +245,10 @@ where
         .query_wasm_smart(
             test_case.address_book.leaser().clone(),
             &QueryMsg::Quote {
-                downpayment: downpayment.into(),
+                downpayment: OneOrMany::One(downpayment.into()),
                 lease_asset: LeaseCurrency::TICKER.into(),
                 max_ltd: None,
+                rate_type: RateType::Fixed,
             },
         )
         .unwrap()
@@ -283,36 +298,78 @@ where
             .saturating_sub(last_paid.nanos()),
         now.nanos().saturating_sub(current_period_start.nanos()),
     );
+    let previous_margin_due =
+        calculate_interest(expected, quote_result.annual_interest_rate_margin, overdue);
+    let previous_interest_due =
+        calculate_interest(expected, quote_result.annual_interest_rate, overdue);
+    let current_margin_due =
+        calculate_interest(expected, quote_result.annual_interest_rate_margin, due);
+    let current_interest_due = calculate_interest(expected, quote_result.annual_interest_rate, due);
+    let past_grace_due = if current_period_start + LeaserInstantiator::GRACE_PERIOD <= now {
+        previous_margin_due + previous_interest_due
+    } else {
+        LpnCoin::ZERO
+    };
+    let liability = expected_liability(
+        total,
+        expected
+            + previous_margin_due
+            + previous_interest_due
+            + current_margin_due
+            + current_interest_due,
+        past_grace_due,
+    );
     StateResponse::Opened {
         amount: total.into(),
         loan_interest_rate: quote_result.annual_interest_rate,
         margin_interest_rate: quote_result.annual_interest_rate_margin,
         principal_due: expected.into(),
-        previous_margin_due: calculate_interest(
-            expected,
-            quote_result.annual_interest_rate_margin,
-            overdue,
-        )
-        .into(),
-        previous_interest_due: calculate_interest(
-            expected,
-            quote_result.annual_interest_rate,
-            overdue,
-        )
-        .into(),
-        current_margin_due: calculate_interest(
-            expected,
-            quote_result.annual_interest_rate_margin,
-            due,
-        )
-        .into(),
-        current_interest_due: calculate_interest(expected, quote_result.annual_interest_rate, due)
-            .into(),
+        previous_margin_due: previous_margin_due.into(),
+        previous_interest_due: previous_interest_due.into(),
+        current_margin_due: current_margin_due.into(),
+        current_interest_due: current_interest_due.into(),
+        liability: Some(liability),
         validity: block_time(test_case),
+        close_policy: Default::default(),
         in_progress: None,
     }
 }
 
+pub(super) fn expected_liability(
+    amount: LeaseCoin,
+    total_due: LpnCoin,
+    overdue: LpnCoin,
+) -> LiabilityState {
+    let liability = LeaserInstantiator::liability();
+    let in_asset =
+        |coin: LpnCoin| -> LeaseCoin { price::total(coin, price_lpn_of::<LeaseCurrency>().inv()) };
+    let total_due_in_asset = in_asset(total_due);
+
+    let zone = match liability::check_liability(
+        &liability,
+        amount,
+        total_due_in_asset.min(amount),
+        in_asset(overdue).min(total_due_in_asset),
+        in_asset(LpnCoin::new(LeaserInstantiator::MIN_LIQUIDATION)),
+        in_asset(LpnCoin::new(LeaserInstantiator::MIN_ASSET)),
+    ) {
+        Status::No(zone) => Some(zone.into()),
+        Status::NoDebt | Status::Liquidation(_) => None,
+    };
+    let price_at = |ltv: Percent| price::total_of(ltv.of(amount)).is(total_due);
+
+    LiabilityState {
+        ltv: Percent::from_ratio(total_due_in_asset, amount),
+        zone,
+        liquidation_prices: LiquidationPrices {
+            first_liq_warn: price_at(liability.first_liq_warn()).into(),
+            second_liq_warn: price_at(liability.second_liq_warn()).into(),
+            third_liq_warn: price_at(liability.third_liq_warn()).into(),
+            max: price_at(liability.max()).into(),
+        },
+    }
+}
+
 pub(super) fn expected_newly_opened_state<
     Dispatcher,
     Treasury,
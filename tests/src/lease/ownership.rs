@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use ::lease::{api::ExecuteMsg, error::ContractError};
+use leaser::msg::QueryMsg;
+use sdk::cosmwasm_std::Addr;
+
+use crate::common::{ADMIN, USER};
+
+use super::{PaymentCurrency, DOWNPAYMENT};
+
+const NEW_CUSTOMER: &str = "new_customer";
+
+#[test]
+fn transfer_ownership() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let response = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_address.clone(),
+            &ExecuteMsg::TransferOwnership {
+                new_customer: Addr::unchecked(NEW_CUSTOMER),
+            },
+            &[],
+        )
+        .unwrap()
+        .unwrap_response();
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-ls-ownership-transfer"));
+
+    let leases = |owner: &str| -> HashSet<Addr> {
+        test_case
+            .app
+            .query()
+            .query_wasm_smart(
+                test_case.address_book.leaser().clone(),
+                &QueryMsg::Leases {
+                    owner: Addr::unchecked(owner),
+                },
+            )
+            .unwrap()
+    };
+    assert!(leases(USER).is_empty());
+    assert_eq!(HashSet::from([lease_address.clone()]), leases(NEW_CUSTOMER));
+
+    let err = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_address,
+            &ExecuteMsg::TransferOwnership {
+                new_customer: Addr::unchecked(USER),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::Unauthorized(_))
+    ));
+}
+
+#[test]
+fn transfer_ownership_unauthorized() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let err = test_case
+        .app
+        .execute(
+            Addr::unchecked(ADMIN),
+            lease_address,
+            &ExecuteMsg::TransferOwnership {
+                new_customer: Addr::unchecked(ADMIN),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::Unauthorized(_))
+    ));
+}
@@ -0,0 +1,173 @@
+use finance::{coin::Amount, price};
+use osmosis_std::types::osmosis::gamm::v1beta1::{
+    MsgSwapExactAmountIn, MsgSwapExactAmountInResponse,
+};
+
+use ::lease::{
+    api::{ExecuteMsg, StateResponse},
+    error::ContractError,
+};
+use sdk::{
+    cosmwasm_std::{Addr, Binary},
+    cw_multi_test::AppResponse,
+};
+
+use crate::common::{
+    cwcoin,
+    test_case::{
+        response::{RemoteChain as _, ResponseWithInterChainMsgs},
+        TestCase,
+    },
+    ADMIN, USER,
+};
+
+use super::{LeaseCoin, LpnCoin, PaymentCurrency, DOWNPAYMENT};
+
+#[test]
+fn close_position_partial() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let StateResponse::Opened { amount: amount_before, .. } =
+        super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+    let amount_before: LeaseCoin = amount_before.try_into().unwrap();
+
+    let close_amount: LeaseCoin = (Amount::from(amount_before) / 4).into();
+    let response = close(&mut test_case, lease_address.clone(), close_amount);
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-ls-close-position"));
+
+    let StateResponse::Opened { amount, in_progress, .. } =
+        super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+    assert_eq!(
+        amount_before - close_amount,
+        LeaseCoin::try_from(amount).unwrap()
+    );
+    assert!(in_progress.is_none());
+}
+
+#[test]
+fn close_position_unauthorized() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let err = test_case
+        .app
+        .execute(
+            Addr::unchecked(ADMIN),
+            lease_address,
+            &ExecuteMsg::ClosePosition { amount: None },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::Unauthorized(_))
+    ));
+}
+
+#[test]
+fn close_position_too_big() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let StateResponse::Opened { amount, .. } =
+        super::state_query(&test_case, lease_address.as_str()) else {
+        unreachable!()
+    };
+    let close_amount: LeaseCoin = LeaseCoin::try_from(amount).unwrap() + LeaseCoin::new(1);
+
+    let err = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_address,
+            &ExecuteMsg::ClosePosition {
+                amount: Some(close_amount.into()),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        Some(&ContractError::InvalidPositionCloseAmount(
+            close_amount.into()
+        )),
+        err.downcast_ref::<ContractError>()
+    );
+}
+
+fn close<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle>(
+    test_case: &mut TestCase<Dispatcher, Treasury, Profit, Leaser, Lpp, Oracle, Addr>,
+    lease_addr: Addr,
+    close_amount: LeaseCoin,
+) -> AppResponse {
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = test_case
+        .app
+        .execute(
+            Addr::unchecked(USER),
+            lease_addr.clone(),
+            &ExecuteMsg::ClosePosition {
+                amount: Some(close_amount.into()),
+            },
+            &[],
+        )
+        .unwrap()
+        .ignore_response();
+    response.expect_submit_tx(TestCase::LEASER_CONNECTION_ID, "0", 1);
+    () = response.unwrap_response();
+
+    test_case
+        .app
+        .send_tokens(
+            Addr::unchecked("ica0"),
+            Addr::unchecked(ADMIN),
+            &[cwcoin(close_amount)],
+        )
+        .unwrap();
+
+    let close_lpn: LpnCoin = price::total(close_amount, super::price_lpn_of());
+    test_case.send_funds_from_admin(Addr::unchecked("ica0"), &[cwcoin(close_lpn)]);
+
+    let mut response: ResponseWithInterChainMsgs<'_, ()> = test_case
+        .app
+        .sudo(
+            lease_addr.clone(),
+            &super::construct_response(Binary(platform::trx::encode_msg_responses(
+                [platform::trx::encode_msg_response(
+                    MsgSwapExactAmountInResponse {
+                        token_out_amount: Amount::from(close_lpn).to_string(),
+                    },
+                    MsgSwapExactAmountIn::TYPE_URL,
+                )]
+                .into_iter(),
+            ))),
+        )
+        .unwrap()
+        .ignore_response();
+    response.expect_submit_tx(TestCase::LEASER_CONNECTION_ID, "0", 1);
+    () = response.unwrap_response();
+
+    test_case
+        .app
+        .send_tokens(
+            Addr::unchecked("ica0"),
+            lease_addr.clone(),
+            &[cwcoin(close_lpn)],
+        )
+        .unwrap();
+
+    test_case
+        .app
+        .sudo(lease_addr, &super::construct_response(Binary::default()))
+        .unwrap()
+        .unwrap_response()
+}
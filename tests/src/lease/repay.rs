@@ -152,21 +152,24 @@ fn full_repay_with_max_ltd() {
     let borrowed = Coin::new(percent.of(DOWNPAYMENT));
     let lease_address = super::open_lease(&mut test_case, downpayment, Some(percent));
 
+    let amount: LeaseCoin = (Percent::HUNDRED + percent).of(price::total(
+        downpayment,
+        Price::<PaymentCurrency, LeaseCurrency>::identity(),
+    ));
+    let principal_due: LpnCoin = price::total(percent.of(downpayment), super::price_lpn_of());
+    let liability = super::expected_liability(amount, principal_due, LpnCoin::ZERO);
     let expected_result = StateResponse::Opened {
-        amount: (Percent::HUNDRED + percent)
-            .of(price::total(
-                downpayment,
-                Price::<PaymentCurrency, LeaseCurrency>::identity(),
-            ))
-            .into(),
+        amount: amount.into(),
         loan_interest_rate: Percent::from_permille(70),
         margin_interest_rate: Percent::from_permille(30),
-        principal_due: price::total(percent.of(downpayment), super::price_lpn_of()).into(),
+        principal_due: principal_due.into(),
         previous_margin_due: LpnCoin::ZERO.into(),
         previous_interest_due: LpnCoin::ZERO.into(),
         current_margin_due: LpnCoin::ZERO.into(),
         current_interest_due: LpnCoin::ZERO.into(),
+        liability: Some(liability),
         validity: Timestamp::from_nanos(1537237454879305533),
+        close_policy: Default::default(),
         in_progress: None,
     };
     let query_result = super::state_query(&test_case, lease_address.as_str());
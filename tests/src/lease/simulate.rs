@@ -0,0 +1,66 @@
+use finance::percent::Percent;
+use sdk::cosmwasm_std::StdError;
+
+use ::lease::api::{QueryMsg, SimulateQuery, SimulateResponse, StateResponse};
+
+use super::{PaymentCurrency, DOWNPAYMENT};
+
+#[test]
+fn simulate() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let StateResponse::Opened { amount, .. } =
+        super::state_query(&test_case, lease_address.as_str())
+    else {
+        unreachable!()
+    };
+
+    let SimulateResponse { at, scenarios } = test_case
+        .app
+        .query()
+        .query_wasm_smart(
+            lease_address,
+            &QueryMsg::Simulate {
+                simulate: SimulateQuery {
+                    price_change: vec![Percent::HUNDRED, Percent::from_percent(10)],
+                    at: None,
+                },
+            },
+        )
+        .unwrap();
+
+    assert_eq!(test_case.app.block_info().time, at);
+    assert_eq!(2, scenarios.len());
+
+    assert_eq!(Percent::HUNDRED, scenarios[0].price_change);
+    assert_eq!(0, scenarios[0].zone.as_ref().unwrap().level);
+    assert!(scenarios[0].liquidation.is_none());
+
+    assert!(scenarios[1].ltv > Percent::HUNDRED);
+    assert!(scenarios[1].zone.is_none());
+    assert_eq!(Some(amount), scenarios[1].liquidation);
+}
+
+#[test]
+fn simulate_zero_price_change() {
+    let mut test_case = super::create_test_case::<PaymentCurrency>();
+    let downpayment = super::create_payment_coin(DOWNPAYMENT);
+    let lease_address = super::open_lease(&mut test_case, downpayment, None);
+
+    let err = test_case
+        .app
+        .query()
+        .query_wasm_smart::<SimulateResponse>(
+            lease_address,
+            &QueryMsg::Simulate {
+                simulate: SimulateQuery {
+                    price_change: vec![Percent::ZERO],
+                    at: None,
+                },
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(err, StdError::GenericErr { .. }));
+}
@@ -11,6 +11,7 @@ use finance::{
     percent::Percent,
     price::{total, total_of, Price},
 };
+use lease::api::RateType;
 use leaser::msg::QueryMsg;
 use sdk::{
     cosmwasm_ext::Response,
@@ -95,6 +96,7 @@ fn open_lease_not_in_lease_currency() {
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: lease_currency.into(),
                 max_ltd: None,
+                rate_type: RateType::Fixed,
             },
             &[downpayment],
         )
@@ -163,6 +165,7 @@ fn open_multiple_loans() {
                 &leaser::msg::ExecuteMsg::OpenLease {
                     currency: LeaseCurrency::TICKER.into(),
                     max_ltd: None,
+                    rate_type: RateType::Fixed,
                 },
                 &[cwcoin::<Lpn, _>(3)],
             )
@@ -188,6 +191,7 @@ fn open_multiple_loans() {
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: LeaseCurrency::TICKER.into(),
                 max_ltd: None,
+                rate_type: RateType::Fixed,
             },
             &[cwcoin::<Lpn, _>(30)],
         )
@@ -280,6 +284,22 @@ fn test_quote() {
 
     assert_eq!(resp.annual_interest_rate_margin, Percent::from_permille(30),);
 
+    let leaser = test_case.address_book.leaser().clone();
+    let resp_multi = leaser_mod::query_quote_multi::<LeaseCurrency>(
+        &mut test_case.app,
+        leaser,
+        vec![
+            Coin::<Downpayment>::new(50).into(),
+            Coin::<LeaseCurrency>::new(100).into(),
+        ],
+        None,
+    );
+    assert_eq!(resp_multi.borrow.try_into(), Ok(borrow));
+    assert_eq!(
+        resp_multi.total.try_into(),
+        Ok(total(downpayment + borrow, price_lease_lpn.inv()))
+    );
+
     let leaser = test_case.address_book.leaser().clone();
     let resp = leaser_mod::query_quote::<Downpayment, LeaseCurrency>(
         &mut test_case.app,
@@ -525,6 +545,7 @@ fn open_loans_lpp_fails() {
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: LeaseCurrency::TICKER.into(),
                 max_ltd: None,
+                rate_type: RateType::Fixed,
             },
             &[downpayment],
         )
@@ -609,6 +630,7 @@ where
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: LeaseC::TICKER.into(),
                 max_ltd: None,
+                rate_type: RateType::Fixed,
             },
             &[cwcoin(downpayment)],
         )
@@ -14,6 +14,7 @@ use finance::{
     percent::Percent,
     price::{self, dto::PriceDTO},
 };
+use lease::api::RateType;
 use leaser::msg::QueryMsg;
 use marketprice::{config::Config as PriceConfig, SpotPrice};
 use oracle::{
@@ -275,6 +276,7 @@ fn open_lease<Dispatcher, Treasury, Profit, Lpp, Oracle, TimeAlarms>(
             &leaser::msg::ExecuteMsg::OpenLease {
                 currency: LeaseCurrency::TICKER.into(),
                 max_ltd: None,
+                rate_type: RateType::Fixed,
             },
             &[cw_coin(downpayment)],
         )